@@ -5,13 +5,25 @@ pub fn main() {
         use serde::Serialize;
         use underworld_core::{
             actions::{Action, AttackNpc, ExitRoom, InspectNpc},
-            components::{games::GameState, PlayerCharacter},
+            components::{
+                games::{Difficulty, GameState},
+                PlayerCharacter,
+            },
+            content::ContentRegistry,
             game::Game,
             generators::{game::game_generator, generator::Generator, players::player_generator},
+            ruleset::Ruleset,
         };
 
         let player = player_generator(None, None, None).generate();
-        let state = game_generator().generate();
+        let ruleset = Ruleset::default();
+        let content = ContentRegistry::default();
+        let state = game_generator(
+            Difficulty::Normal,
+            ruleset.clone(),
+            content.room_templates.clone(),
+        )
+        .generate();
         let mut game = Game { state, player };
 
         let first_exit_id = match game.state.current_room_exits().get(0) {
@@ -23,7 +35,8 @@ pub fn main() {
             exit_id: first_exit_id.to_string(),
         };
 
-        game.handle_action(&Action::ExitRoom(exit_room)).unwrap();
+        game.handle_action(&Action::ExitRoom(exit_room), &ruleset, &content)
+            .unwrap();
         let npc_id = match game.state.current_room().npc_positions.get(0) {
             Some(it) => it.npc.id,
             None => return,
@@ -34,11 +47,13 @@ pub fn main() {
             discover_health: true,
             discover_packed_items: true,
         };
-        game.handle_action(&Action::InspectNpc(inspect)).unwrap();
+        game.handle_action(&Action::InspectNpc(inspect), &ruleset, &content)
+            .unwrap();
         let attack = AttackNpc {
             npc_id: npc_id.to_string(),
         };
-        game.handle_action(&Action::AttackNpc(attack)).unwrap();
+        game.handle_action(&Action::AttackNpc(attack), &ruleset, &content)
+            .unwrap();
 
         #[derive(Serialize)]
         struct SerializedGame {
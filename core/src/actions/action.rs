@@ -15,8 +15,12 @@ pub enum Action {
     CastSpellOnNpc(super::CastSpellOnNpc),
     CastSpellOnPlayer(super::CastSpellOnPlayer),
     ExitRoom(super::ExitRoom),
+    ExtinguishItem(super::ExtinguishItem),
+    GiveItemToCompanion(super::GiveItemToCompanion),
     InspectFixture(super::InspectFixture),
+    InspectItem(super::InspectItem),
     InspectNpc(super::InspectNpc),
+    LightItem(super::LightItem),
     LookAtFixture(super::LookAtFixture),
     LookAtNpc(super::LookAtNpc),
     LootFixture(super::LootFixture),
@@ -24,8 +28,46 @@ pub enum Action {
     MovePlayerItem(super::MovePlayerItem),
     OpenFixture(super::OpenFixture),
     OpenFixtureHiddenCompartment(super::OpenFixtureHiddenCompartment),
+    ParleyWithNpc(super::ParleyWithNpc),
     PickUpItem(super::PickUpItem),
+    RecruitNpc(super::RecruitNpc),
     SellPlayerItem(super::SellPlayerItem),
+    TakeItemFromCompanion(super::TakeItemFromCompanion),
     UseItemOnPlayer(super::UseItemOnPlayer),
     ThrowItemAtNpc(super::ThrowItemAtNpc),
+    TravelToRoom(super::TravelToRoom),
+}
+
+impl Action {
+    /// How many turns of in-game time the action takes up.
+    pub fn turn_cost(&self) -> u32 {
+        match self {
+            // Each exit along the way is its own action with its own cost.
+            Action::TravelToRoom(_) => 0,
+            Action::ExitRoom(_) => 3,
+            Action::InspectFixture(_)
+            | Action::InspectItem(_)
+            | Action::InspectNpc(_)
+            | Action::LootFixture(_)
+            | Action::LootNpc(_)
+            | Action::OpenFixture(_)
+            | Action::OpenFixtureHiddenCompartment(_) => 2,
+            Action::AttackNpc(_)
+            | Action::CastSpellOnNpc(_)
+            | Action::CastSpellOnPlayer(_)
+            | Action::ExtinguishItem(_)
+            | Action::GiveItemToCompanion(_)
+            | Action::LightItem(_)
+            | Action::LookAtFixture(_)
+            | Action::LookAtNpc(_)
+            | Action::MovePlayerItem(_)
+            | Action::ParleyWithNpc(_)
+            | Action::PickUpItem(_)
+            | Action::RecruitNpc(_)
+            | Action::SellPlayerItem(_)
+            | Action::TakeItemFromCompanion(_)
+            | Action::ThrowItemAtNpc(_)
+            | Action::UseItemOnPlayer(_) => 1,
+        }
+    }
 }
@@ -3,8 +3,12 @@ pub mod attack_npc;
 pub mod cast_spell_on_npc;
 pub mod cast_spell_on_player;
 pub mod exit_room;
+pub mod extinguish_item;
+pub mod give_item_to_companion;
 pub mod inspect_fixture;
+pub mod inspect_item;
 pub mod inspect_npc;
+pub mod light_item;
 pub mod look_at_fixture;
 pub mod look_at_npc;
 pub mod loot_fixture;
@@ -12,17 +16,40 @@ pub mod loot_npc;
 pub mod move_player_item;
 pub mod open_fixture;
 pub mod open_fixture_hidden_compartment;
+pub mod parley_with_npc;
 mod pick_up_item;
+pub mod recruit_npc;
 pub mod sell_player_item;
+pub mod take_item_from_companion;
 mod throw_item_at_npc;
+pub mod travel_to_room;
 pub mod use_item_on_player;
 
 pub use {
-    action::Action, attack_npc::AttackNpc, cast_spell_on_npc::CastSpellOnNpc,
-    cast_spell_on_player::CastSpellOnPlayer, exit_room::ExitRoom, inspect_fixture::InspectFixture,
-    inspect_npc::InspectNpc, look_at_fixture::LookAtFixture, look_at_npc::LookAtNpc,
-    loot_fixture::LootFixture, loot_npc::LootNpc, move_player_item::MovePlayerItem,
-    open_fixture::OpenFixture, open_fixture_hidden_compartment::OpenFixtureHiddenCompartment,
-    pick_up_item::PickUpItem, sell_player_item::SellPlayerItem, throw_item_at_npc::ThrowItemAtNpc,
+    action::Action,
+    attack_npc::AttackNpc,
+    cast_spell_on_npc::CastSpellOnNpc,
+    cast_spell_on_player::CastSpellOnPlayer,
+    exit_room::ExitRoom,
+    extinguish_item::ExtinguishItem,
+    give_item_to_companion::GiveItemToCompanion,
+    inspect_fixture::InspectFixture,
+    inspect_item::InspectItem,
+    inspect_npc::InspectNpc,
+    light_item::LightItem,
+    look_at_fixture::LookAtFixture,
+    look_at_npc::LookAtNpc,
+    loot_fixture::LootFixture,
+    loot_npc::LootNpc,
+    move_player_item::MovePlayerItem,
+    open_fixture::OpenFixture,
+    open_fixture_hidden_compartment::OpenFixtureHiddenCompartment,
+    parley_with_npc::{ParleyApproach, ParleyWithNpc},
+    pick_up_item::PickUpItem,
+    recruit_npc::RecruitNpc,
+    sell_player_item::SellPlayerItem,
+    take_item_from_companion::TakeItemFromCompanion,
+    throw_item_at_npc::ThrowItemAtNpc,
+    travel_to_room::TravelToRoom,
     use_item_on_player::UseItemOnPlayer,
 };
@@ -0,0 +1,51 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Achievement {
+    ClearFiftyRooms,
+    FirstKill,
+    KillAVampire,
+    LearnEverySpell,
+    SurvivePoisonAtOneHealth,
+}
+
+impl Achievement {
+    pub fn description(&self) -> &str {
+        match *self {
+            Achievement::ClearFiftyRooms => "Leave nothing standing in fifty rooms.",
+            Achievement::FirstKill => "Kill your first NPC.",
+            Achievement::KillAVampire => "Kill a vampire.",
+            Achievement::LearnEverySpell => "Learn every spell there is to learn.",
+            Achievement::SurvivePoisonAtOneHealth => {
+                "Live through poison with a single hit point left."
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Achievement"))]
+pub struct AchievementView {
+    pub achievement: Achievement,
+    pub description: String,
+    pub unlocked: bool,
+}
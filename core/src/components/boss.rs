@@ -0,0 +1,15 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Marks an NPC as one of the elites guarding the deeper parts of the dungeon.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Boss {
+    pub title: String,
+    /// Beating the final boss ends the run in victory.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_final: bool,
+}
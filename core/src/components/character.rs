@@ -16,12 +16,19 @@ use super::{
     {Stats, StatsView},
 };
 
+const MAX_CARRY_WEIGHT_MULTIPLIER: i32 = 150;
+/// Enough for the heaviest starter kit, with a little room left to pick things up.
+const MIN_CARRY_CAPACITY: i32 = 15;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
 pub struct Character {
     pub stats: Stats,
     pub species: Species,
+    /// Name of the content pack species this character was spawned as, if any.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub custom_species: Option<String>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub life_modifier: Option<LifeModifier>,
     #[cfg_attr(feature = "serialization", serde(default))]
@@ -103,6 +110,33 @@ impl Character {
         self.stats.health.current = 0;
     }
 
+    /// The weight the character can carry before being over-encumbered.
+    pub fn carry_capacity(&self) -> i32 {
+        (self.species.carry_capacity() + self.stats.height.carry_capacity_modifier())
+            .max(MIN_CARRY_CAPACITY)
+    }
+
+    /// The most the character can carry at all, no matter how slow it makes them.
+    pub fn max_carry_weight(&self) -> i32 {
+        self.carry_capacity() * MAX_CARRY_WEIGHT_MULTIPLIER / 100
+    }
+
+    pub fn is_over_encumbered(&self) -> bool {
+        self.inventory.total_weight() > self.carry_capacity()
+    }
+
+    pub fn can_carry(&self, extra_weight: i32) -> bool {
+        self.inventory.total_weight() + extra_weight <= self.max_carry_weight()
+    }
+
+    pub fn is_undead(&self) -> bool {
+        self.life_modifier.is_some()
+    }
+
+    pub fn is_blinded(&self) -> bool {
+        self.current_effects.blindness.is_some()
+    }
+
     pub fn has_weapons_readied(&self) -> bool {
         !self.inventory.readied_weapons().is_empty()
     }
@@ -165,6 +199,8 @@ pub struct CharacterView {
     pub stats: StatsView,
     pub species: Species,
     #[cfg_attr(feature = "serialization", serde(default))]
+    pub custom_species: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
     pub life_modifier: Option<LifeModifier>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub inventory: Option<InventoryView>,
@@ -182,6 +218,8 @@ pub struct CharacterViewArgs {
     pub knows_health: bool,
     pub knows_inventory: bool,
     pub knows_packed_in_inventory: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_spell_memory: bool,
 }
 
 impl CharacterViewArgs {
@@ -190,6 +228,7 @@ impl CharacterViewArgs {
             knows_health: true,
             knows_inventory: true,
             knows_packed_in_inventory: true,
+            knows_spell_memory: true,
         }
     }
 }
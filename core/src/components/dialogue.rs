@@ -0,0 +1,26 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// What an NPC is talking about, so clients can style the conversation.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum DialogueTopic {
+    BribeAccepted,
+    Greeting,
+    Intimidated,
+    NotIntimidated,
+    Refusal,
+    RoomInformation,
+    Threat,
+}
@@ -0,0 +1,32 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// How an NPC feels about the player.
+#[derive(Clone, Copy, Debug, Default, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Disposition {
+    /// Will talk, and might even help.
+    Friendly,
+    /// Attacks on sight.
+    #[default]
+    Hostile,
+    /// Keeps to themselves unless provoked.
+    Wary,
+}
+
+impl Disposition {
+    pub fn is_hostile(&self) -> bool {
+        matches!(*self, Disposition::Hostile)
+    }
+}
@@ -21,6 +21,8 @@ pub struct Effects {
     pub resurrection_aura: bool,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub covered_in_oil: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub blindness: Option<Blindness>,
 }
 
 #[derive(Clone, Debug)]
@@ -39,6 +41,7 @@ pub struct EffectsView {
     pub knows_has_resurrection_aura: bool,
     pub poison: Option<Poison>,
     pub covered_in_oil: bool,
+    pub blindness: Option<Blindness>,
 }
 
 #[derive(Clone, Debug)]
@@ -48,3 +51,10 @@ pub struct Poison {
     pub damage: i32,
     pub duration: i32,
 }
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct Blindness {
+    pub duration: i32,
+}
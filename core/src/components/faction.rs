@@ -0,0 +1,73 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
+
+use super::Species;
+
+/// The larger groups the denizens of the underworld side with.
+#[derive(Clone, Copy, Debug, Display, EnumIter, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Faction {
+    Goblinkin,
+    Kobolds,
+    Orcs,
+    Swampfolk,
+    Undead,
+}
+
+impl Faction {
+    /// Whether members of the two factions will fight each other when they meet.
+    pub fn is_hostile_to(&self, other: &Faction) -> bool {
+        match (*self, *other) {
+            (Faction::Undead, Faction::Undead) => false,
+            (Faction::Undead, _) | (_, Faction::Undead) => true,
+            (Faction::Goblinkin, Faction::Kobolds) | (Faction::Kobolds, Faction::Goblinkin) => true,
+            (Faction::Orcs, Faction::Swampfolk) | (Faction::Swampfolk, Faction::Orcs) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Species {
+    pub fn faction(&self) -> Faction {
+        match *self {
+            Species::Bugbear | Species::Goblin | Species::Hobgoblin | Species::Moblin => {
+                Faction::Goblinkin
+            }
+            Species::Dragonkin | Species::Kobold => Faction::Kobolds,
+            Species::Ogre | Species::Orc => Faction::Orcs,
+            Species::Frogkin | Species::Lizardkin | Species::Rockoblin | Species::Turtlekin => {
+                Faction::Swampfolk
+            }
+            Species::Phantom | Species::Shadow => Faction::Undead,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::Faction;
+
+    #[test]
+    fn hostility_goes_both_ways() {
+        for faction in Faction::iter() {
+            assert!(!faction.is_hostile_to(&faction));
+            for other in Faction::iter() {
+                assert_eq!(faction.is_hostile_to(&other), other.is_hostile_to(&faction));
+            }
+        }
+    }
+}
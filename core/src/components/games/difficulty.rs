@@ -0,0 +1,107 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// Chosen when a game is generated, and fixed for the rest of the run.
+#[derive(Clone, Copy, Debug, Default, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Difficulty {
+    /// Fewer, weaker NPCs and generous loot and healing.
+    Story,
+    #[default]
+    Normal,
+    /// More dangerous in every way, and the player character is lost for good on death.
+    Hardcore,
+}
+
+impl Difficulty {
+    /// Adjusts the ruleset's d6 dodge roll by one either way.
+    pub fn player_dodge_chance(&self, base_chance: i32) -> i32 {
+        match *self {
+            Difficulty::Story => base_chance + 1,
+            Difficulty::Normal => base_chance,
+            Difficulty::Hardcore => (base_chance - 1).max(0),
+        }
+    }
+
+    pub fn acid_destroys_item_chance(&self, base_chance: i32) -> i32 {
+        match *self {
+            Difficulty::Story => base_chance / 2,
+            Difficulty::Normal => base_chance,
+            Difficulty::Hardcore => base_chance * 3 / 2,
+        }
+    }
+
+    /// Acid the player throws works in their favour, so it bites more often on easier runs.
+    pub fn thrown_acid_destroys_item_chance(&self, base_chance: i32) -> i32 {
+        match *self {
+            Difficulty::Story => (base_chance * 3 / 2).min(100),
+            Difficulty::Normal => base_chance,
+            Difficulty::Hardcore => base_chance / 2,
+        }
+    }
+
+    pub fn spawn_from_ghost_chance(&self, base_chance: i32) -> i32 {
+        match *self {
+            Difficulty::Story => base_chance / 2,
+            Difficulty::Normal => base_chance,
+            Difficulty::Hardcore => base_chance * 2,
+        }
+    }
+
+    /// How much the danger level goes up for each new room seen or NPC killed.
+    pub fn danger_level_increase(&self) -> u32 {
+        match *self {
+            Difficulty::Story | Difficulty::Normal => 1,
+            Difficulty::Hardcore => 2,
+        }
+    }
+
+    pub fn has_permadeath(&self) -> bool {
+        matches!(*self, Difficulty::Hardcore)
+    }
+
+    /// Groups of NPCs lose or gain a member, but never drop below one.
+    pub fn scale_group_size(&self, group_size: usize) -> usize {
+        match *self {
+            Difficulty::Story => group_size.saturating_sub(1).max(1),
+            Difficulty::Normal => group_size,
+            Difficulty::Hardcore => group_size + 1,
+        }
+    }
+
+    pub fn scale_npc_damage(&self, damage: i32) -> i32 {
+        match *self {
+            Difficulty::Story => damage * 3 / 4,
+            Difficulty::Normal => damage,
+            Difficulty::Hardcore => damage * 5 / 4,
+        }
+    }
+
+    pub fn scale_healing(&self, healing: i32) -> i32 {
+        match *self {
+            Difficulty::Story => healing * 3 / 2,
+            Difficulty::Normal => healing,
+            Difficulty::Hardcore => healing * 3 / 4,
+        }
+    }
+
+    /// Loot is generated as if the danger level were higher or lower than it is.
+    pub fn loot_danger_level(&self, danger_level: u32) -> u32 {
+        match *self {
+            Difficulty::Story => danger_level + 5,
+            Difficulty::Normal => danger_level,
+            Difficulty::Hardcore => danger_level.saturating_sub(5).max(1),
+        }
+    }
+}
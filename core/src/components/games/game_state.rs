@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
@@ -11,14 +11,23 @@ use uuid::Uuid;
 use crate::{
     components::{
         fixtures::FixtureViewArgs,
-        rooms::{Room, RoomView},
+        items::ItemType,
+        quests::Quest,
+        rooms::{LightLevel, NpcPosition, Room, RoomView},
+        spells::SpellName,
         worlds::{World, WorldView},
-        CharacterViewArgs, Ghost, NonPlayerViewArgs,
+        CharacterViewArgs, Faction, Ghost, NonPlayer, NonPlayerView, NonPlayerViewArgs,
+        PlayerCharacter,
     },
-    systems::view::room::view,
+    systems::view::{non_player, room::view},
 };
 
-use super::{CharacterKnowledge, FixtureKnowledge, Statistics};
+use super::{
+    CharacterKnowledge, Difficulty, FixtureKnowledge, ItemKnowledge, RunSummary, Statistics,
+};
+
+/// How many companions can travel with the player at once.
+pub const MAX_PARTY_SIZE: usize = 2;
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -37,11 +46,32 @@ pub struct GameState {
     #[cfg_attr(feature = "serialization", serde(default))]
     pub player_fixture_knowledge: HashMap<Uuid, FixtureKnowledge>,
     #[cfg_attr(feature = "serialization", serde(default))]
+    pub player_item_knowledge: HashMap<Uuid, ItemKnowledge>,
+    #[cfg_attr(feature = "serialization", serde(default))]
     pub player_statistics: HashMap<Uuid, Statistics>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub danger_level: u32,
+    /// The last danger level milestone a boss was spawned for.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub last_boss_milestone: u32,
+    /// The depth each room template was last forced at, by template name.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub template_depths_used: HashMap<String, u32>,
     #[cfg_attr(feature = "serialization", serde(default, skip))]
     pub ghosts: Vec<Ghost>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub quests: Vec<Quest>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub run_summary: Option<RunSummary>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub reputation: HashMap<Faction, i32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub party: Vec<NonPlayer>,
+    /// How many turns have passed since the game started.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub turn: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub difficulty: Difficulty,
 }
 
 impl GameState {
@@ -63,6 +93,13 @@ impl GameState {
             .unwrap_or_default()
     }
 
+    pub fn item_knowledge(&self, item_id: &Uuid) -> ItemKnowledge {
+        self.player_item_knowledge
+            .get(item_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     pub fn set_npc_knowledge(&mut self, npc_id: Uuid, knowledge: CharacterKnowledge) {
         self.player_npc_knowledge.insert(npc_id, knowledge);
     }
@@ -82,6 +119,32 @@ impl GameState {
         statistics.total_damage_done += damage;
     }
 
+    /// How well the faction thinks of the player. Everyone starts out neutral at 0.
+    pub fn reputation_with(&self, faction: &Faction) -> i32 {
+        self.reputation.get(faction).cloned().unwrap_or_default()
+    }
+
+    pub fn change_reputation(&mut self, faction: &Faction, change: i32) {
+        let reputation = self.reputation.entry(*faction).or_default();
+        *reputation += change;
+    }
+
+    pub fn add_spell_learned_to_stats(&mut self, pc_id: &Uuid, spell_name: SpellName) {
+        let statistics = self.player_statistics.entry(*pc_id).or_default();
+        if !statistics.spells_learned.contains(&spell_name) {
+            statistics.spells_learned.push(spell_name);
+        }
+    }
+
+    pub fn add_item_looted_to_stats(&mut self, pc_id: &Uuid, item_type: ItemType) {
+        let statistics = self.player_statistics.entry(*pc_id).or_default();
+        *statistics.items_looted.entry(item_type).or_default() += 1;
+    }
+
+    pub fn player_stats_mut(&mut self, pc_id: &Uuid) -> &mut Statistics {
+        self.player_statistics.entry(*pc_id).or_default()
+    }
+
     pub fn player_stats(&self, pc_id: &Uuid) -> Statistics {
         self.player_statistics
             .get(pc_id)
@@ -89,10 +152,62 @@ impl GameState {
             .unwrap_or_default()
     }
 
+    pub fn find_companion(&self, companion_id: &Uuid) -> Option<&NonPlayer> {
+        self.party
+            .iter()
+            .find(|companion| companion.id.eq(companion_id))
+    }
+
+    pub fn find_companion_mut(&mut self, companion_id: &Uuid) -> Option<&mut NonPlayer> {
+        self.party
+            .iter_mut()
+            .find(|companion| companion.id.eq(companion_id))
+    }
+
+    pub fn party_is_full(&self) -> bool {
+        self.party.len() >= MAX_PARTY_SIZE
+    }
+
+    /// The run is over once the final boss has been beaten.
+    pub fn is_completed(&self) -> bool {
+        self.run_summary.is_some()
+    }
+
+    pub fn build_run_summary(&self, player: &PlayerCharacter) -> RunSummary {
+        let bosses_defeated = self
+            .world
+            .rooms
+            .iter()
+            .flat_map(|room| room.npc_positions.iter())
+            .filter(|npc_position| {
+                npc_position.npc.is_boss() && npc_position.npc.character.is_dead()
+            })
+            .count();
+
+        RunSummary {
+            player_name: player.name.clone(),
+            statistics: self.player_stats(&player.id),
+            danger_level: self.danger_level,
+            rooms_seen: self.rooms_seen.len(),
+            bosses_defeated,
+            quests_completed: self.quests.iter().filter(|quest| quest.completed).count(),
+            turns: self.turn,
+            gold: player.gold,
+        }
+    }
+
+    pub fn find_quest_mut(&mut self, quest_id: &Uuid) -> Option<&mut Quest> {
+        self.quests.iter_mut().find(|quest| quest.id.eq(quest_id))
+    }
+
     pub fn set_fixture_knowledge(&mut self, fixture_id: Uuid, knowledge: FixtureKnowledge) {
         self.player_fixture_knowledge.insert(fixture_id, knowledge);
     }
 
+    pub fn set_item_knowledge(&mut self, item_id: Uuid, knowledge: ItemKnowledge) {
+        self.player_item_knowledge.insert(item_id, knowledge);
+    }
+
     pub fn current_room_exits(&self) -> Vec<Uuid> {
         self.current_room()
             .exits
@@ -117,7 +232,85 @@ impl GameState {
             .unwrap()
     }
 
+    /// Look for an NPC in any room, not just the one the player is in.
+    pub fn find_npc(&self, npc_id: &Uuid) -> Option<&NonPlayer> {
+        self.world
+            .rooms
+            .iter()
+            .find_map(|room| room.find_npc(npc_id))
+    }
+
+    pub fn find_npc_mut(&mut self, npc_id: &Uuid) -> Option<&mut NpcPosition> {
+        self.world
+            .rooms
+            .iter_mut()
+            .find_map(|room| room.find_npc_mut(npc_id))
+    }
+
+    pub fn find_room(&self, room_id: &Uuid) -> Option<&Room> {
+        self.world.rooms.iter().find(|room| room.id.eq(room_id))
+    }
+
+    pub fn find_room_mut(&mut self, room_id: &Uuid) -> Option<&mut Room> {
+        self.world.rooms.iter_mut().find(|room| room.id.eq(room_id))
+    }
+
+    /// Rooms reachable through an exit of the given room, along with the exit leading there.
+    /// Exits nobody has been through yet don't lead anywhere.
+    pub fn adjacent_rooms(&self, room: &Room) -> Vec<(Uuid, Uuid)> {
+        room.exits
+            .iter()
+            .filter_map(|exit| {
+                self.world
+                    .exit_graph
+                    .iter()
+                    .find(|exit_map| exit_map.exit_id.eq(&exit.id))
+                    .and_then(|exit_map| exit_map.other_room_id(room.id))
+                    .map(|room_id| (exit.id, room_id))
+            })
+            .collect()
+    }
+
+    /// The exits to take, in order, for the shortest route to a room through rooms already seen.
+    pub fn path_to_room(&self, room_id: &Uuid) -> Option<Vec<Uuid>> {
+        let mut came_from: HashMap<Uuid, (Uuid, Uuid)> = HashMap::new();
+        let mut queue = VecDeque::from([self.current_room_id]);
+        while let Some(current_id) = queue.pop_front() {
+            if current_id.eq(room_id) {
+                let mut exit_ids: Vec<Uuid> = Vec::new();
+                let mut step = current_id;
+                while let Some((previous_id, exit_id)) = came_from.get(&step) {
+                    exit_ids.push(*exit_id);
+                    step = *previous_id;
+                }
+                exit_ids.reverse();
+                return Some(exit_ids);
+            }
+
+            let room = match self.find_room(&current_id) {
+                Some(it) => it,
+                None => continue,
+            };
+            for (exit_id, next_id) in self.adjacent_rooms(room) {
+                if next_id.ne(&self.current_room_id)
+                    && self.rooms_seen.contains(&next_id)
+                    && !came_from.contains_key(&next_id)
+                {
+                    came_from.insert(next_id, (current_id, exit_id));
+                    queue.push_back(next_id);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Sees the room by its own light. Use `view_current_room` for what the player sees.
     pub fn view_room(&self, room: &Room) -> RoomView {
+        self.view_room_in_light(room, room.light_level())
+    }
+
+    fn view_room_in_light(&self, room: &Room, light_level: LightLevel) -> RoomView {
         let mut fixture_args: HashMap<Uuid, FixtureViewArgs> = HashMap::new();
 
         for fixture_id in room
@@ -151,6 +344,7 @@ impl GameState {
                         knows_health: knowledge.knows_health,
                         knows_inventory: knowledge.knows_inventory,
                         knows_packed_in_inventory: knowledge.knows_packed_in_inventory,
+                        knows_spell_memory: false,
                     },
                 },
             );
@@ -179,12 +373,44 @@ impl GameState {
             npc_args,
             fixture_args,
             exit_visitations,
+            &self.player_item_knowledge,
+            light_level,
             self.all_knowledge_unlocked,
         )
     }
 
-    pub fn view_current_room(&self) -> RoomView {
-        self.view_room(self.current_room())
+    pub fn view_current_room(&self, player: &PlayerCharacter) -> RoomView {
+        self.view_room_in_light(self.current_room(), self.current_light_level(player))
+    }
+
+    /// The current room's light, brightened by any light source the player holds up.
+    pub fn current_light_level(&self, player: &PlayerCharacter) -> LightLevel {
+        let light_level = self.current_room().light_level();
+        if player.character.inventory.carries_light() {
+            light_level.brightened()
+        } else {
+            light_level
+        }
+    }
+
+    /// Nothing in the current room can be made out in the dark.
+    pub fn can_see_current_room(&self, player: &PlayerCharacter) -> bool {
+        self.all_knowledge_unlocked || self.current_light_level(player) != LightLevel::Dark
+    }
+
+    /// In dim light everything in the current room is only a vague shape.
+    pub fn sees_current_room_clearly(&self, player: &PlayerCharacter) -> bool {
+        self.all_knowledge_unlocked || self.current_light_level(player) == LightLevel::Bright
+    }
+
+    /// Companions hide nothing from the player, so they are always fully known.
+    pub fn view_party(&self) -> Vec<NonPlayerView> {
+        self.party
+            .iter()
+            .map(|companion| {
+                non_player::view(companion, &CharacterViewArgs::knows_all_args(), None, true)
+            })
+            .collect()
     }
 }
 
@@ -205,19 +431,34 @@ pub struct GameStateView {
     pub all_knowledge_unlocked: bool,
     pub player_npc_knowledge: HashMap<String, CharacterKnowledge>,
     pub player_fixture_knowledge: HashMap<String, FixtureKnowledge>,
+    pub player_item_knowledge: HashMap<String, ItemKnowledge>,
     pub player_statistics: HashMap<String, Statistics>,
     pub danger_level: u32,
+    pub run_summary: Option<RunSummary>,
+    pub reputation: HashMap<String, i32>,
+    pub party: Vec<NonPlayerView>,
+    pub turn: u32,
+    pub difficulty: Difficulty,
 }
 
 #[cfg(test)]
 mod tests {
     use uuid::Uuid;
 
-    use crate::generators::{game::game_generator, generator::Generator};
+    use crate::{
+        components::games::Difficulty,
+        generators::{
+            floors::FloorGeneratorBuilder,
+            game::{floor_game_generator, game_generator},
+            generator::Generator,
+        },
+        ruleset::Ruleset,
+    };
 
     #[test]
     fn stats_can_be_incremented() {
-        let mut state = game_generator().generate();
+        let mut state =
+            game_generator(Difficulty::Normal, Ruleset::default(), Vec::new()).generate();
         let pc_id = Uuid::new_v4();
         state.add_player_kill_to_stats(&pc_id);
         let stats = state.player_stats(&pc_id);
@@ -260,6 +501,7 @@ mod tests {
                     base_damage_resistance: None,
                 },
                 species: crate::components::Species::Bugbear,
+                custom_species: None,
                 life_modifier: None,
                 inventory: Inventory::default(),
                 current_effects: Effects::default(),
@@ -272,4 +514,235 @@ mod tests {
 
         assert!(!serialized.contains("\"ghosts\":"));
     }
+
+    #[test]
+    fn paths_only_go_through_seen_rooms() {
+        let mut state = floor_game_generator(
+            Difficulty::Normal,
+            FloorGeneratorBuilder::new().num_rooms(6..=6).to_owned(),
+        )
+        .generate();
+        let far_room_id = state.world.rooms.last().unwrap().id;
+        assert_eq!(None, state.path_to_room(&far_room_id));
+
+        state.rooms_seen = state.world.rooms.iter().map(|room| room.id).collect();
+        let exit_ids = state.path_to_room(&far_room_id).unwrap();
+        assert!(!exit_ids.is_empty());
+
+        let mut room_id = state.current_room_id;
+        for exit_id in exit_ids {
+            let exit_map = state
+                .world
+                .exit_graph
+                .iter()
+                .find(|exit_map| exit_map.exit_id.eq(&exit_id))
+                .unwrap();
+            room_id = exit_map.other_room_id(room_id).unwrap();
+        }
+        assert_eq!(far_room_id, room_id);
+    }
+
+    #[test]
+    fn floor_views_only_show_seen_rooms() {
+        use crate::systems::view::game_state;
+
+        let state = floor_game_generator(
+            Difficulty::Normal,
+            FloorGeneratorBuilder::new().num_rooms(6..=6).to_owned(),
+        )
+        .generate();
+
+        let player = crate::generators::players::player_generator(None, None, None).generate();
+        let view = game_state::view(&state, &player);
+        assert_eq!(1, view.world.rooms.len());
+        assert_eq!(state.current_room_id.to_string(), view.world.rooms[0].id);
+    }
+
+    #[test]
+    fn darkness_hides_the_room_until_a_light_is_held_up() {
+        use crate::{
+            components::{
+                items::ItemType,
+                rooms::{Descriptor, LightLevel},
+            },
+            generators::players::player_generator,
+        };
+
+        let mut state =
+            game_generator(Difficulty::Normal, Ruleset::default(), Vec::new()).generate();
+        state.current_room_mut().descriptors = vec![Descriptor::Dark];
+        let mut player = player_generator(None, None, None).generate();
+
+        let view = state.view_current_room(&player);
+        assert_eq!(LightLevel::Dark, view.light_level);
+        assert!(view.npc_positions.is_empty());
+        assert!(view.fixture_positions.is_empty());
+        assert_eq!(state.current_room().exits.len(), view.exits.len());
+
+        let torch = player
+            .character
+            .inventory
+            .equipment
+            .iter_mut()
+            .find(|character_item| character_item.item.item_type == ItemType::Torch)
+            .unwrap();
+        torch.at_the_ready = true;
+        torch.item.light_source.as_mut().unwrap().is_lit = true;
+
+        let view = state.view_current_room(&player);
+        assert_eq!(LightLevel::Dim, view.light_level);
+        assert_eq!(
+            state.current_room().fixture_positions.len(),
+            view.fixture_positions.len()
+        );
+        assert!(view
+            .fixture_positions
+            .iter()
+            .all(|fixture_position| fixture_position.is_obscured));
+    }
+
+    #[test]
+    fn darkness_hides_the_actions_on_what_is_in_the_room() {
+        use crate::{
+            actions::{Action, LookAtNpc},
+            components::rooms::{Descriptor, NpcPosition},
+            content::ContentRegistry,
+            game::Game,
+            generators::{non_players::NonPlayerGeneratorBuilder, players::player_generator},
+        };
+
+        let mut game = Game {
+            state: game_generator(Difficulty::Normal, Ruleset::default(), Vec::new()).generate(),
+            player: player_generator(None, None, None).generate(),
+        };
+        let npc = NonPlayerGeneratorBuilder::new().build().generate();
+        let npc_id = npc.id.to_string();
+        game.state.current_room_mut().npc_positions = vec![NpcPosition {
+            npc,
+            position_descriptor: None,
+        }];
+        game.state.current_room_mut().descriptors = vec![Descriptor::Dark];
+
+        let mentions_npc = |actions: Vec<Action>| {
+            actions
+                .iter()
+                .any(|action| format!("{:?}", action).contains(&npc_id))
+        };
+        assert!(!mentions_npc(game.current_actions()));
+        assert!(game
+            .handle_action(
+                &Action::LookAtNpc(LookAtNpc {
+                    npc_id: npc_id.clone(),
+                }),
+                &Ruleset::default(),
+                &ContentRegistry::default(),
+            )
+            .is_err());
+
+        game.state.current_room_mut().descriptors = vec![Descriptor::Dim];
+        let actions = game.current_actions();
+        assert!(mentions_npc(actions.clone()));
+        assert!(!actions
+            .iter()
+            .any(|action| matches!(action, Action::LookAtNpc(_) | Action::InspectNpc(_))));
+    }
+
+    #[test]
+    fn using_an_unknown_scroll_identifies_everything_else() {
+        use crate::{
+            actions::{Action, UseItemOnPlayer},
+            components::items::{
+                CharacterItem, Consumable, ConsumableEffect, ConsumableEffectName, Item, ItemType,
+                LocationTag,
+            },
+            components::tag::Tagged,
+            content::ContentRegistry,
+            game::Game,
+            generators::players::player_generator,
+            ruleset::Ruleset,
+            systems::view::player,
+        };
+
+        let mut game = Game {
+            state: game_generator(Difficulty::Normal, Ruleset::default(), Vec::new()).generate(),
+            player: player_generator(None, None, None).generate(),
+        };
+        let scroll_id = Uuid::new_v4();
+        game.player.character.add_item(CharacterItem {
+            item: Item {
+                id: scroll_id,
+                name: None,
+                item_type: ItemType::Scroll,
+                tags: ItemType::Scroll.tags(),
+                descriptors: Vec::new(),
+                material: None,
+                attack: None,
+                defense: None,
+                consumable: Some(Consumable {
+                    effect: ConsumableEffect {
+                        name: ConsumableEffectName::Identify,
+                        learn_spell_effect: None,
+                        healing_effect: None,
+                    },
+                    uses: 2,
+                }),
+                throwable: None,
+                light_source: None,
+            },
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        });
+
+        let view = player::check_in_game(&game.player, &game.state);
+        assert!(view
+            .character
+            .inventory
+            .unwrap()
+            .equipment
+            .iter()
+            .all(|character_item| !character_item.item.knows_consumable));
+
+        // A quest finishing off the back of this turn could hand over an unknown reward.
+        game.state.quests.clear();
+        let ruleset = Ruleset {
+            unidentified_fizzle_chance: 0,
+            ..Ruleset::default()
+        };
+        let action = Action::UseItemOnPlayer(UseItemOnPlayer {
+            item_id: scroll_id.to_string(),
+        });
+        game.handle_action(&action, &ruleset, &ContentRegistry::default())
+            .unwrap();
+
+        assert!(game
+            .player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .all(|character_item| game
+                .state
+                .item_knowledge(&character_item.item.id)
+                .reveals_all_of(&character_item.item)));
+
+        let view = player::check_in_game(&game.player, &game.state);
+        let equipment = view.character.inventory.unwrap().equipment;
+        let scroll = equipment
+            .iter()
+            .find(|character_item| character_item.item.id == scroll_id.to_string())
+            .unwrap();
+        assert!(scroll.item.consumable.is_some());
+        assert!(game
+            .player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .filter(|character_item| character_item.item.attack.is_some())
+            .all(
+                |character_item| equipment.iter().any(|viewed| viewed.item.id
+                    == character_item.item.id.to_string()
+                    && viewed.item.attack.is_some())
+            ));
+    }
 }
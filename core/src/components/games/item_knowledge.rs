@@ -0,0 +1,44 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::components::items::Item;
+
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct ItemKnowledge {
+    pub knows_attack: bool,
+    pub knows_defense: bool,
+    pub knows_consumable: bool,
+}
+
+impl ItemKnowledge {
+    /// Everything there is to know about an item.
+    pub fn identified() -> Self {
+        Self {
+            knows_attack: true,
+            knows_defense: true,
+            knows_consumable: true,
+        }
+    }
+
+    /// Whether there is nothing left to learn about the item.
+    pub fn reveals_all_of(&self, item: &Item) -> bool {
+        (self.knows_attack || item.attack.is_none())
+            && (self.knows_defense || item.defense.is_none())
+            && (self.knows_consumable || item.consumable.is_none())
+    }
+
+    pub fn merge(&self, other: &ItemKnowledge) -> Self {
+        Self {
+            knows_attack: self.knows_attack || other.knows_attack,
+            knows_defense: self.knows_defense || other.knows_defense,
+            knows_consumable: self.knows_consumable || other.knows_consumable,
+        }
+    }
+}
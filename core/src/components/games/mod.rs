@@ -1,9 +1,15 @@
 pub mod character_knowledge;
+pub mod difficulty;
 pub mod fixture_knowledge;
 pub mod game_state;
+pub mod item_knowledge;
+pub mod run_summary;
 pub mod statistics;
 
 pub use character_knowledge::CharacterKnowledge;
+pub use difficulty::Difficulty;
 pub use fixture_knowledge::FixtureKnowledge;
 pub use game_state::GameState;
-pub use statistics::Statistics;
+pub use item_knowledge::ItemKnowledge;
+pub use run_summary::RunSummary;
+pub use statistics::{CauseOfDeath, Statistics};
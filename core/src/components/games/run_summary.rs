@@ -0,0 +1,29 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use super::Statistics;
+
+/// A look back at how a finished run went.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct RunSummary {
+    pub player_name: Option<String>,
+    pub statistics: Statistics,
+    pub danger_level: u32,
+    pub rooms_seen: usize,
+    pub bosses_defeated: usize,
+    pub quests_completed: usize,
+    pub gold: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub turns: u32,
+}
@@ -1,10 +1,14 @@
+use std::collections::HashMap;
+
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
 #[cfg(feature = "openapi")]
-use poem_openapi::Object;
+use poem_openapi::{Enum, Object};
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
 
+use crate::components::{items::ItemType, spells::SpellName, LifeModifier, Species};
+
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
@@ -13,4 +17,49 @@ pub struct Statistics {
     pub num_killed: i64,
     pub total_damage_taken: i32,
     pub total_damage_done: i32,
+    /// Every spell ever learned, even those since forgotten.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub spells_learned: Vec<SpellName>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub kills_by_species: HashMap<Species, i64>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub kills_by_life_modifier: HashMap<LifeModifier, i64>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub spells_cast: HashMap<SpellName, i64>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub items_looted: HashMap<ItemType, i64>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub rooms_visited: i64,
+    /// Rooms left with nothing alive in them by one of the player's kills.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub rooms_cleared: i64,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub gold_earned: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub gold_spent: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub potions_used: i64,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub deepest_danger_level: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub cause_of_death: Option<CauseOfDeath>,
+    /// The species of the NPC that landed the killing blow, if one did.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub killed_by: Option<Species>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub turns_survived: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum CauseOfDeath {
+    Attacked,
+    Poisoned,
+    Cold,
 }
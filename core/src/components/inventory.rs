@@ -7,10 +7,12 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::{
-    items::{CharacterItem, CharacterItemView, Item},
+    items::{CharacterItem, CharacterItemView, EquipmentSlot, EquipmentSlotView, Item, ItemType},
     Attack, Defense,
 };
 
+const OFF_HAND_ATTACK_MODIFIER: i32 = -2;
+
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
@@ -40,6 +42,19 @@ impl Inventory {
             .cloned()
     }
 
+    pub fn find_item_mut(&mut self, item_id: &Uuid) -> Option<&mut CharacterItem> {
+        self.equipment
+            .iter_mut()
+            .find(|character_item| character_item.item.id.eq(item_id))
+    }
+
+    /// Only a lit light source held at the ready helps the player see.
+    pub fn carries_light(&self) -> bool {
+        self.equipment
+            .iter()
+            .any(|character_item| character_item.is_at_the_ready() && character_item.item.is_lit())
+    }
+
     pub fn add_item(&mut self, character_item: CharacterItem) {
         self.equipment.push(character_item)
     }
@@ -66,6 +81,12 @@ impl Inventory {
             .collect()
     }
 
+    pub fn is_wearing(&self, item_type: ItemType) -> bool {
+        self.equipment.iter().any(|character_item| {
+            character_item.is_at_the_ready() && character_item.item.item_type == item_type
+        })
+    }
+
     pub fn readied_weapons(&self) -> Vec<CharacterItem> {
         self.equipment
             .iter()
@@ -74,6 +95,86 @@ impl Inventory {
             .collect()
     }
 
+    /// The types of all readied items that take up a hand, like weapons and shields.
+    pub fn held_item_types(&self) -> Vec<ItemType> {
+        self.equipment
+            .iter()
+            .filter(|item| item.is_at_the_ready() && item.item.item_type.handedness().is_some())
+            .map(|item| item.item.item_type)
+            .collect()
+    }
+
+    /// When dual wielding, the weakest weapon that can be used in the off hand.
+    pub fn off_hand_weapon(&self) -> Option<&CharacterItem> {
+        let readied_weapons: Vec<&CharacterItem> = self
+            .equipment
+            .iter()
+            .filter(|item| item.is_weapon() && item.is_at_the_ready())
+            .collect();
+
+        if readied_weapons.len() < 2 {
+            return None;
+        }
+
+        readied_weapons
+            .into_iter()
+            .filter(|item| item.item.item_type.is_off_hand_capable())
+            .min_by_key(|item| item.item.num_attack_rolls())
+    }
+
+    /// Which slot a readied item is taking up, if any.
+    pub fn slot_for_item(&self, character_item: &CharacterItem) -> Option<EquipmentSlot> {
+        if !character_item.is_at_the_ready() {
+            return None;
+        }
+
+        if character_item.item.item_type.handedness().is_some() {
+            let is_shield = matches!(
+                character_item.item.item_type,
+                ItemType::Buckler | ItemType::Shield
+            );
+            let is_off_hand = self
+                .off_hand_weapon()
+                .map(|off_hand| off_hand.item.id.eq(&character_item.item.id))
+                .unwrap_or(false);
+
+            return if is_shield || is_off_hand {
+                Some(EquipmentSlot::OffHand)
+            } else {
+                Some(EquipmentSlot::MainHand)
+            };
+        }
+
+        EquipmentSlot::for_worn_item(
+            &character_item.item.item_type,
+            &character_item.equipped_location,
+        )
+    }
+
+    pub fn item_in_slot(&self, slot: &EquipmentSlot) -> Option<&CharacterItem> {
+        self.equipment
+            .iter()
+            .find(|character_item| self.slot_for_item(character_item).as_ref() == Some(slot))
+    }
+
+    /// Every occupied slot and the item filling it.
+    pub fn paper_doll(&self) -> Vec<(EquipmentSlot, &CharacterItem)> {
+        self.equipment
+            .iter()
+            .filter_map(|character_item| {
+                self.slot_for_item(character_item)
+                    .map(|slot| (slot, character_item))
+            })
+            .collect()
+    }
+
+    pub fn total_weight(&self) -> i32 {
+        self.equipment
+            .iter()
+            .map(|character_item| character_item.item.weight())
+            .sum()
+    }
+
     pub fn non_readied_weapons(&self) -> Vec<&CharacterItem> {
         self.equipment
             .iter()
@@ -88,14 +189,25 @@ impl Inventory {
     }
 
     pub fn full_attack(&self) -> Option<Attack> {
+        let off_hand_id = self.off_hand_weapon().map(|item| item.item.id);
+
         self.equipment
             .iter()
             .filter_map(|character_item| {
-                if character_item.at_the_ready {
-                    character_item.item.attack.clone()
-                } else {
-                    None
+                if !character_item.at_the_ready {
+                    return None;
                 }
+
+                character_item.item.attack.clone().map(|attack| {
+                    if off_hand_id.eq(&Some(character_item.item.id)) {
+                        Attack {
+                            modifier: attack.modifier + OFF_HAND_ATTACK_MODIFIER,
+                            ..attack
+                        }
+                    } else {
+                        attack
+                    }
+                })
             })
             .reduce(|accum, item| Attack {
                 num_rolls: accum.num_rolls + item.num_rolls,
@@ -132,6 +244,14 @@ impl Inventory {
 #[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Inventory"))]
 pub struct InventoryView {
     pub equipment: Vec<CharacterItemView>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub paper_doll: Vec<EquipmentSlotView>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub total_weight: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub carry_capacity: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_carry_weight: i32,
 }
 
 #[cfg(test)]
@@ -140,8 +260,8 @@ mod tests {
 
     use crate::components::{
         damage::AttackEffect,
-        items::{CharacterItem, Item, ItemType, LocationTag},
-        Attack, Defense,
+        items::{CharacterItem, Descriptor, Item, ItemType, LocationTag},
+        Attack, Defense, Material, Tagged,
     };
 
     use super::Inventory;
@@ -166,6 +286,7 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        light_source: None,
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -186,6 +307,7 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        light_source: None,
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -219,6 +341,7 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        light_source: None,
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -239,6 +362,7 @@ mod tests {
                         defense: None,
                         consumable: None,
                         throwable: None,
+                        light_source: None,
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -257,6 +381,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn full_attack_with_off_hand_weapon() {
+        let inventory = Inventory {
+            equipment: vec![
+                CharacterItem {
+                    item: Item {
+                        id: Uuid::new_v4(),
+                        name: None,
+                        item_type: ItemType::LongSword,
+                        tags: ItemType::LongSword.tags(),
+                        descriptors: Vec::new(),
+                        material: None,
+                        attack: Some(Attack {
+                            num_rolls: 2,
+                            modifier: 1,
+                            effects: Vec::new(),
+                        }),
+                        defense: None,
+                        consumable: None,
+                        throwable: None,
+                        light_source: None,
+                    },
+                    equipped_location: LocationTag::Hand,
+                    at_the_ready: true,
+                },
+                CharacterItem {
+                    item: Item {
+                        id: Uuid::new_v4(),
+                        name: None,
+                        item_type: ItemType::Dagger,
+                        tags: ItemType::Dagger.tags(),
+                        descriptors: Vec::new(),
+                        material: None,
+                        attack: Some(Attack {
+                            num_rolls: 1,
+                            modifier: 0,
+                            effects: Vec::new(),
+                        }),
+                        defense: None,
+                        consumable: None,
+                        throwable: None,
+                        light_source: None,
+                    },
+                    equipped_location: LocationTag::Hand,
+                    at_the_ready: true,
+                },
+            ],
+        };
+
+        let off_hand = inventory.off_hand_weapon();
+        assert!(off_hand.is_some());
+        assert_eq!(off_hand.unwrap().item.item_type, ItemType::Dagger);
+
+        let attack = inventory.full_attack().unwrap();
+        assert_eq!(attack.num_rolls, 3);
+        assert_eq!(attack.modifier, -1);
+    }
+
     #[test]
     fn full_defense() {
         let inventory = Inventory {
@@ -275,6 +457,7 @@ mod tests {
                         }),
                         consumable: None,
                         throwable: None,
+                        light_source: None,
                     },
                     equipped_location: LocationTag::Feet,
                     at_the_ready: true,
@@ -293,6 +476,7 @@ mod tests {
                         }),
                         consumable: None,
                         throwable: None,
+                        light_source: None,
                     },
                     equipped_location: LocationTag::Hand,
                     at_the_ready: true,
@@ -305,4 +489,48 @@ mod tests {
         let attack = merged.unwrap();
         assert_eq!(attack.damage_resistance, 8);
     }
+
+    #[test]
+    fn total_weight() {
+        let inventory = Inventory {
+            equipment: vec![
+                CharacterItem {
+                    item: Item {
+                        id: Uuid::new_v4(),
+                        name: None,
+                        item_type: ItemType::Breastplate,
+                        tags: Vec::new(),
+                        descriptors: vec![Descriptor::Heavy],
+                        material: Some(Material::Steel),
+                        attack: None,
+                        defense: None,
+                        consumable: None,
+                        throwable: None,
+                        light_source: None,
+                    },
+                    equipped_location: LocationTag::Body,
+                    at_the_ready: true,
+                },
+                CharacterItem {
+                    item: Item {
+                        id: Uuid::new_v4(),
+                        name: None,
+                        item_type: ItemType::Scroll,
+                        tags: Vec::new(),
+                        descriptors: Vec::new(),
+                        material: Some(Material::Paper),
+                        attack: None,
+                        defense: None,
+                        consumable: None,
+                        throwable: None,
+                        light_source: None,
+                    },
+                    equipped_location: LocationTag::Pockets,
+                    at_the_ready: false,
+                },
+            ],
+        };
+
+        assert_eq!(23, inventory.total_weight());
+    }
 }
@@ -41,6 +41,8 @@ pub struct ConsumableEffect {
 pub enum ConsumableEffectName {
     LearnSpell,
     HealingGrog,
+    /// Reveals everything about the rest of the player's items.
+    Identify,
 }
 
 #[derive(Clone, Debug)]
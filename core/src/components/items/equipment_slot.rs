@@ -0,0 +1,83 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use super::{ItemType, LocationTag};
+
+/// A place on the body that can only hold one item at a time.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum EquipmentSlot {
+    Ankles,
+    Arms,
+    Body,
+    Chest,
+    Face,
+    Feet,
+    Hands,
+    Head,
+    Legs,
+    MainHand,
+    Neck,
+    OffHand,
+    Shoulders,
+    Waist,
+    Wrists,
+}
+
+impl EquipmentSlot {
+    /// The slot a worn item takes up when it is at the given location.
+    /// Items that are held, packed away or hanging from a belt don't take up a slot.
+    pub fn for_worn_item(item_type: &ItemType, location: &LocationTag) -> Option<EquipmentSlot> {
+        if item_type.handedness().is_some() {
+            return None;
+        }
+
+        match *location {
+            LocationTag::Ankle => Some(EquipmentSlot::Ankles),
+            LocationTag::Arm => Some(EquipmentSlot::Arms),
+            // Armour is strapped on over top of whatever else is on the body.
+            LocationTag::Body => match *item_type {
+                ItemType::Breastplate => Some(EquipmentSlot::Chest),
+                _ => Some(EquipmentSlot::Body),
+            },
+            LocationTag::Face => Some(EquipmentSlot::Face),
+            LocationTag::Feet => Some(EquipmentSlot::Feet),
+            LocationTag::Hand => Some(EquipmentSlot::Hands),
+            LocationTag::Head => Some(EquipmentSlot::Head),
+            LocationTag::Leg => Some(EquipmentSlot::Legs),
+            LocationTag::Neck => Some(EquipmentSlot::Neck),
+            LocationTag::Shoulder => Some(EquipmentSlot::Shoulders),
+            LocationTag::Waist => Some(EquipmentSlot::Waist),
+            LocationTag::Wrist => Some(EquipmentSlot::Wrists),
+            LocationTag::Back
+            | LocationTag::Hip
+            | LocationTag::HipSheath
+            | LocationTag::Packed
+            | LocationTag::Pockets => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "EquipmentSlot"))]
+pub struct EquipmentSlotView {
+    pub slot: EquipmentSlot,
+    pub item_id: String,
+}
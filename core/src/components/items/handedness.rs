@@ -0,0 +1,34 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// How an item that is held in the hands gets wielded.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Handedness {
+    /// Held in one hand, but too unwieldy to be used in the off hand.
+    OneHanded,
+    /// Light enough to be held in the off hand next to another weapon.
+    OffHand,
+    /// Needs both hands, nothing else can be held alongside it.
+    TwoHanded,
+}
+
+impl Handedness {
+    pub fn hands(&self) -> usize {
+        match *self {
+            Handedness::OneHanded | Handedness::OffHand => 1,
+            Handedness::TwoHanded => 2,
+        }
+    }
+}
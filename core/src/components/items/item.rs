@@ -11,7 +11,8 @@ use crate::components::{
 };
 
 use super::{
-    Descriptor, ItemType, Throwable, ThrowableView, {Consumable, ConsumableView},
+    Descriptor, ItemType, LightSource, LightSourceView, Throwable, ThrowableView,
+    {Consumable, ConsumableView},
 };
 
 #[derive(Clone, Debug)]
@@ -39,9 +40,29 @@ pub struct Item {
     pub consumable: Option<Consumable>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub throwable: Option<Throwable>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub light_source: Option<LightSource>,
 }
 
+const HEAVY_WEIGHT_MODIFIER: i32 = 50;
+
 impl Item {
+    /// Weight of the item from its type and material, never less than 1.
+    pub fn weight(&self) -> i32 {
+        let material_modifier = self
+            .material
+            .as_ref()
+            .map(|material| material.weight_modifier())
+            .unwrap_or(100);
+        let heavy_modifier = if self.descriptors.contains(&Descriptor::Heavy) {
+            HEAVY_WEIGHT_MODIFIER
+        } else {
+            0
+        };
+
+        (self.item_type.weight() * (material_modifier + heavy_modifier) / 100).max(1)
+    }
+
     pub fn num_attack_rolls(&self) -> usize {
         self.attack
             .as_ref()
@@ -65,6 +86,13 @@ impl Item {
         self.tags.iter().any(|tag| tag.is_consumable())
     }
 
+    pub fn is_lit(&self) -> bool {
+        self.light_source
+            .as_ref()
+            .map(|light_source| light_source.is_lit)
+            .unwrap_or_default()
+    }
+
     pub fn decrease_uses(&mut self) {
         if let Some(consumable) = self.consumable.as_mut() {
             consumable.uses -= 1;
@@ -102,6 +130,8 @@ pub struct ItemView {
     pub consumable: Option<ConsumableView>,
     pub knows_consumable: bool,
     pub throwable: Option<ThrowableView>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub light_source: Option<LightSourceView>,
     pub is_equippable: bool,
 }
 
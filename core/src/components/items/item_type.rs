@@ -4,11 +4,16 @@ use bevy_ecs::prelude::Component;
 use poem_openapi::Enum;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
-use strum_macros::EnumIter;
+use strum_macros::{Display, EnumIter, EnumString};
 
 use crate::components::tag::{Tag, Tagged};
 
-#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+use super::Handedness;
+
+const MAX_HANDS: usize = 2;
+
+#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "snake_case")]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -21,6 +26,7 @@ pub enum ItemType {
     Boots,
     BowlerHat,
     Buckler,
+    Caltrops,
     Cloak,
     Club,
     Crown,
@@ -33,6 +39,7 @@ pub enum ItemType {
     Halberd,
     Hammer,
     Helm,
+    Lantern,
     LoinCloth,
     LongSword,
     Mace,
@@ -50,12 +57,55 @@ pub enum ItemType {
     Shackles,
     Spear,
     TopHat,
+    Torch,
     Trousers,
     Vest,
     Whip,
 }
 
 impl ItemType {
+    /// Weight of the item before its material is taken into account.
+    pub fn weight(&self) -> i32 {
+        match *self {
+            ItemType::Breastplate => 15,
+            ItemType::Halberd => 14,
+            ItemType::GreatSword | ItemType::Pike => 12,
+            ItemType::PlateBoots | ItemType::Shield => 8,
+            ItemType::Lantern => 3,
+            ItemType::Morningstar => 7,
+            ItemType::Hammer
+            | ItemType::LongSword
+            | ItemType::Mace
+            | ItemType::PlateHelmet
+            | ItemType::Spear => 6,
+            ItemType::PlateGauntlets => 5,
+            ItemType::Buckler
+            | ItemType::Club
+            | ItemType::Helm
+            | ItemType::Shackles
+            | ItemType::ShortSword => 4,
+            ItemType::Boots | ItemType::Cloak => 3,
+            ItemType::Caltrops
+            | ItemType::Crown
+            | ItemType::Pot
+            | ItemType::Torch
+            | ItemType::Trousers
+            | ItemType::Whip => 2,
+            ItemType::BowlerHat
+            | ItemType::Dagger
+            | ItemType::Dirk
+            | ItemType::Fedora
+            | ItemType::Flask
+            | ItemType::Gloves
+            | ItemType::LoinCloth
+            | ItemType::Mask
+            | ItemType::Scroll
+            | ItemType::Shirt
+            | ItemType::TopHat
+            | ItemType::Vest => 1,
+        }
+    }
+
     pub fn is_lower_body(&self) -> bool {
         matches!(*self, ItemType::LoinCloth | ItemType::Trousers)
     }
@@ -78,6 +128,103 @@ impl ItemType {
         matches!(*self, ItemType::Boots | ItemType::PlateBoots)
     }
 
+    pub fn handedness(&self) -> Option<Handedness> {
+        match *self {
+            ItemType::GreatSword | ItemType::Halberd | ItemType::Pike => {
+                Some(Handedness::TwoHanded)
+            }
+            ItemType::Buckler
+            | ItemType::Dagger
+            | ItemType::Dirk
+            | ItemType::Lantern
+            | ItemType::Shield
+            | ItemType::Torch => Some(Handedness::OffHand),
+            ItemType::Club
+            | ItemType::Hammer
+            | ItemType::LongSword
+            | ItemType::Mace
+            | ItemType::Morningstar
+            | ItemType::ShortSword
+            | ItemType::Spear
+            | ItemType::Whip => Some(Handedness::OneHanded),
+            ItemType::Breastplate
+            | ItemType::Boots
+            | ItemType::BowlerHat
+            | ItemType::Caltrops
+            | ItemType::Cloak
+            | ItemType::Crown
+            | ItemType::Fedora
+            | ItemType::Flask
+            | ItemType::Gloves
+            | ItemType::Helm
+            | ItemType::LoinCloth
+            | ItemType::Mask
+            | ItemType::PlateBoots
+            | ItemType::PlateGauntlets
+            | ItemType::PlateHelmet
+            | ItemType::Pot
+            | ItemType::Scroll
+            | ItemType::Shirt
+            | ItemType::Shackles
+            | ItemType::TopHat
+            | ItemType::Trousers
+            | ItemType::Vest => None,
+        }
+    }
+
+    pub fn is_two_handed(&self) -> bool {
+        matches!(self.handedness(), Some(Handedness::TwoHanded))
+    }
+
+    pub fn is_off_hand_capable(&self) -> bool {
+        matches!(self.handedness(), Some(Handedness::OffHand))
+    }
+
+    /// Check if there are enough free hands to hold this item next to the items already held.
+    pub fn has_free_hands(&self, held: &[ItemType]) -> bool {
+        let hands_needed = match self.handedness() {
+            Some(handedness) => handedness.hands(),
+            None => return true,
+        };
+
+        let hands_in_use: usize = held
+            .iter()
+            .filter_map(|item_type| item_type.handedness())
+            .map(|handedness| handedness.hands())
+            .sum();
+
+        hands_in_use + hands_needed <= MAX_HANDS
+    }
+
+    /// When dual wielding, at least one of the two weapons needs to be light enough
+    /// to be used in the off hand.
+    pub fn can_dual_wield_with(&self, held: &[ItemType]) -> bool {
+        if !self.is_weapon() {
+            return true;
+        }
+
+        held.iter()
+            .filter(|item_type| item_type.is_weapon())
+            .all(|item_type| self.is_off_hand_capable() || item_type.is_off_hand_capable())
+    }
+
+    pub fn fits_in_hands_with(&self, held: &[ItemType]) -> bool {
+        self.has_free_hands(held) && self.can_dual_wield_with(held)
+    }
+
+    pub fn is_weapon(&self) -> bool {
+        self.tags().iter().any(|tag| tag.is_weapon())
+    }
+
+    /// How many turns a fresh light source of this type burns for.
+    pub fn burn_time(&self) -> Option<i32> {
+        match *self {
+            ItemType::Torch => Some(40),
+            ItemType::Lantern => Some(100),
+            _ => None,
+        }
+    }
+
     pub fn is_for_hands(&self) -> bool {
         matches!(
             *self,
@@ -137,6 +284,9 @@ impl Tagged for ItemType {
             ItemType::Scroll => vec![Tag::Consumable, Tag::Teachable],
             ItemType::Pot => vec![Tag::Consumable, Tag::Throwable],
             ItemType::Flask => vec![Tag::Consumable],
+            ItemType::Caltrops => vec![Tag::Consumable, Tag::Throwable],
+            ItemType::Lantern => vec![Tag::Light, Tag::Equippable],
+            ItemType::Torch => vec![Tag::Light, Tag::Wood, Tag::Equippable],
         }
     }
 }
@@ -0,0 +1,39 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Something that can be lit to see by, which burns down one turn at a time while lit.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct LightSource {
+    pub burn_time: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_lit: bool,
+}
+
+impl LightSource {
+    pub fn is_burned_out(&self) -> bool {
+        self.burn_time <= 0
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "LightSource"))]
+pub struct LightSourceView {
+    pub burn_time: i32,
+    pub is_lit: bool,
+}
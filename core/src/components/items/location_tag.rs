@@ -90,8 +90,8 @@ pub fn ready_tag_for_item_type(item_type: &ItemType) -> LocationTag {
         | ItemType::Pike
         | ItemType::Spear => LocationTag::Hand,
         ItemType::Breastplate | ItemType::Shirt | ItemType::Vest => LocationTag::Body,
-        ItemType::Mask
-        | ItemType::Crown
+        ItemType::Mask => LocationTag::Face,
+        ItemType::Crown
         | ItemType::BowlerHat
         | ItemType::Fedora
         | ItemType::TopHat
@@ -103,8 +103,9 @@ pub fn ready_tag_for_item_type(item_type: &ItemType) -> LocationTag {
         ItemType::LoinCloth => LocationTag::Waist,
         ItemType::Shackles => LocationTag::Wrist,
         ItemType::Scroll => LocationTag::Pockets,
-        ItemType::Pot => LocationTag::Packed,
+        ItemType::Pot | ItemType::Caltrops => LocationTag::Packed,
         ItemType::Flask => LocationTag::Packed,
+        ItemType::Lantern | ItemType::Torch => LocationTag::Hand,
     }
 }
 
@@ -162,8 +163,9 @@ pub fn packed_tags_for_item_type(item_type: &ItemType) -> Vec<LocationTag> {
         ItemType::Pike => vec![LocationTag::Packed, LocationTag::Back],
         ItemType::Spear => vec![LocationTag::Packed, LocationTag::Back],
         ItemType::Scroll => vec![LocationTag::Packed, LocationTag::Pockets],
-        ItemType::Pot => vec![LocationTag::Packed],
+        ItemType::Pot | ItemType::Caltrops => vec![LocationTag::Packed],
         ItemType::Flask => vec![LocationTag::Packed],
+        ItemType::Lantern | ItemType::Torch => vec![LocationTag::Packed, LocationTag::Hip],
     }
 }
 
@@ -189,7 +191,7 @@ pub fn location_tags_for_item_type(item_type: &ItemType) -> Vec<LocationTag> {
         }
         ItemType::Whip => vec![LocationTag::Hand, LocationTag::Hip],
         ItemType::Breastplate => vec![LocationTag::Body],
-        ItemType::Mask => vec![LocationTag::Head],
+        ItemType::Mask => vec![LocationTag::Face],
         ItemType::Cloak => vec![LocationTag::Shoulder],
         ItemType::Shirt => vec![LocationTag::Body],
         ItemType::Trousers => vec![LocationTag::Leg],
@@ -209,7 +211,8 @@ pub fn location_tags_for_item_type(item_type: &ItemType) -> Vec<LocationTag> {
         ItemType::Pike => vec![LocationTag::Hand, LocationTag::Back],
         ItemType::Spear => vec![LocationTag::Hand, LocationTag::Back],
         ItemType::Scroll => vec![LocationTag::Packed, LocationTag::Pockets],
-        ItemType::Pot => vec![LocationTag::Packed],
+        ItemType::Pot | ItemType::Caltrops => vec![LocationTag::Packed],
         ItemType::Flask => vec![LocationTag::Packed],
+        ItemType::Lantern | ItemType::Torch => vec![LocationTag::Hand, LocationTag::Hip],
     }
 }
@@ -2,9 +2,12 @@ mod character_item;
 mod consumable;
 mod consumable_effect;
 mod descriptor;
+mod equipment_slot;
 mod fixture_item;
+mod handedness;
 mod item;
 mod item_type;
+mod light_source;
 mod location_descriptor;
 mod location_tag;
 mod throwable;
@@ -17,13 +20,19 @@ pub use consumable_effect::{
     LearnSpellEffect,
 };
 pub use descriptor::{Descriptor, DescriptorIter};
+pub use equipment_slot::{EquipmentSlot, EquipmentSlotIter, EquipmentSlotView};
 pub use fixture_item::{FixtureItem, FixtureItemView};
+pub use handedness::{Handedness, HandednessIter};
 pub use item::{Item, ItemView};
 pub use item_type::{ItemType, ItemTypeIter};
+pub use light_source::{LightSource, LightSourceView};
 pub use location_descriptor::{LocationDescriptor, LocationDescriptorIter};
 pub use location_tag::{
     location_tags_for_item_type, packed_tags_for_item_type, ready_tag_for_item_type, LocationTag,
     LocationTagIter,
 };
 pub use throwable::{Throwable, ThrowableView};
-pub use throwable_effect::{OilSplashEffect, ThrowableEffect, ThrowableEffectName};
+pub use throwable_effect::{
+    AcidFlaskEffect, CaltropsEffect, FirebombEffect, HolyWaterEffect, OilSplashEffect,
+    SmokeBombEffect, ThrowableEffect, ThrowableEffectName, ThrowableEffectNameIter,
+};
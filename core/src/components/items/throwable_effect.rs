@@ -6,6 +6,8 @@ use poem_openapi::{Enum, Object};
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
+use crate::components::Attack;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
@@ -18,6 +20,16 @@ pub struct ThrowableEffect {
     pub name: ThrowableEffectName,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub oil_splash_effect: Option<OilSplashEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub smoke_bomb_effect: Option<SmokeBombEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub caltrops_effect: Option<CaltropsEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub holy_water_effect: Option<HolyWaterEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub acid_flask_effect: Option<AcidFlaskEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub firebomb_effect: Option<FirebombEffect>,
 }
 
 #[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, Hash)]
@@ -33,7 +45,12 @@ pub struct ThrowableEffect {
     oai(rename_all = "snake_case", rename = "ThrowableEffectName")
 )]
 pub enum ThrowableEffectName {
+    AcidFlask,
+    Caltrops,
+    Firebomb,
+    HolyWater,
     OilSplash,
+    SmokeBomb,
 }
 
 #[derive(Clone, Debug)]
@@ -47,3 +64,71 @@ pub enum ThrowableEffectName {
 pub struct OilSplashEffect {
     pub covers_all_enemies: bool,
 }
+
+/// Fills the room with smoke, blinding every NPC inside it for a few turns
+/// so that the player can slip away without being hit.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct SmokeBombEffect {
+    pub duration: i32,
+}
+
+/// Scatters spikes across the floor which hurt every NPC in the room.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct CaltropsEffect {
+    pub damage: Attack,
+}
+
+/// Burns the undead, but does nothing to the living.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct HolyWaterEffect {
+    pub damage: Attack,
+    pub covers_all_enemies: bool,
+}
+
+/// Eats away at the weapons the NPCs are holding.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct AcidFlaskEffect {
+    pub covers_all_enemies: bool,
+}
+
+/// Burns NPCs, and ignites any NPC that is covered in oil for double the damage.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct FirebombEffect {
+    pub damage: Attack,
+    pub covers_all_enemies: bool,
+}
@@ -36,6 +36,23 @@ pub enum Material {
     Wool,
 }
 
+impl Material {
+    /// Percentage of an item type's base weight when it is made from this material.
+    pub fn weight_modifier(&self) -> i32 {
+        match *self {
+            Material::Gold | Material::Stone => 150,
+            Material::Iron => 110,
+            Material::Steel => 100,
+            Material::Ceramic | Material::Hide => 90,
+            Material::Glass | Material::Leather => 80,
+            Material::Bone | Material::Fur => 70,
+            Material::Wooden => 60,
+            Material::Cotton | Material::Linen | Material::Silk | Material::Wool => 50,
+            Material::Paper | Material::Papyrus => 20,
+        }
+    }
+}
+
 pub trait BuiltWithMaterial {
     fn possible_materials(&self) -> Vec<Material>;
 }
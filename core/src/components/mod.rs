@@ -1,6 +1,11 @@
+mod achievement;
+mod boss;
 mod character;
 pub mod damage;
+mod dialogue;
+mod disposition;
 mod effects;
+mod faction;
 pub mod fixtures;
 pub mod games;
 mod ghost;
@@ -10,6 +15,7 @@ mod life_modifier;
 mod material;
 mod non_player;
 mod player;
+pub mod quests;
 pub mod rooms;
 mod size;
 mod species;
@@ -18,9 +24,14 @@ mod stats;
 mod tag;
 pub mod worlds;
 
+pub use achievement::{Achievement, AchievementIter, AchievementView};
+pub use boss::Boss;
 pub use character::{Character, CharacterView, CharacterViewArgs};
 pub use damage::{Attack, Defense, Health};
-pub use effects::{Effects, EffectsView, Poison};
+pub use dialogue::{DialogueTopic, DialogueTopicIter};
+pub use disposition::{Disposition, DispositionIter};
+pub use effects::{Blindness, Effects, EffectsView, Poison};
+pub use faction::{Faction, FactionIter};
 pub use ghost::Ghost;
 pub use inventory::{Inventory, InventoryView};
 pub use life_modifier::LifeModifier;
@@ -6,7 +6,7 @@ use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{Character, CharacterView, CharacterViewArgs};
+use super::{Boss, Character, CharacterView, CharacterViewArgs, Disposition, Faction};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -15,9 +15,39 @@ pub struct NonPlayer {
     pub character: Character,
     pub id: Uuid,
     pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub boss: Option<Boss>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub disposition: Disposition,
+    /// Ran from a fight, and is looking for allies to regroup with.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub has_fled: bool,
+    /// Locked up in a prison cell, waiting for someone to deal with their captors.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_prisoner: bool,
 }
 
 impl NonPlayer {
+    pub fn is_boss(&self) -> bool {
+        self.boss.is_some()
+    }
+
+    pub fn is_final_boss(&self) -> bool {
+        self.boss
+            .as_ref()
+            .map(|boss| boss.is_final)
+            .unwrap_or(false)
+    }
+
+    /// Anything brought back from the dead sides with the undead, whatever it was in life.
+    pub fn faction(&self) -> Faction {
+        if self.character.life_modifier.is_some() {
+            Faction::Undead
+        } else {
+            self.character.species.faction()
+        }
+    }
+
     pub fn set_name(&mut self, name: &str) {
         self.name = Some(name.to_string());
     }
@@ -36,6 +66,10 @@ pub struct NonPlayerView {
     pub can_be_looted: bool,
     pub id: String,
     pub name: Option<String>,
+    pub is_boss: bool,
+    pub title: Option<String>,
+    pub disposition: Disposition,
+    pub faction: Faction,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -0,0 +1,7 @@
+pub mod objective;
+pub mod quest;
+
+pub use {
+    objective::{Objective, ObjectiveType, ObjectiveTypeIter},
+    quest::{Quest, QuestReward, QuestView},
+};
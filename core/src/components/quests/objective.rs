@@ -0,0 +1,46 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::components::{items::ItemType, Species};
+
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum ObjectiveType {
+    /// Get a prisoner out of a prison cell, by cutting down the last of their guards or by
+    /// talking to or recruiting them once the guards are gone.
+    FreePrisoner,
+    /// Make it down to a certain danger level.
+    ReachDepth,
+    /// Get a certain type of item into the player's inventory.
+    RetrieveItem,
+    /// Kill a number of NPCs of one species.
+    SlaySpecies,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Objective"))]
+pub struct Objective {
+    pub objective_type: ObjectiveType,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub item_type: Option<ItemType>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub species: Option<Species>,
+    pub required: u32,
+}
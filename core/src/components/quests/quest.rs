@@ -0,0 +1,60 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::components::items::{Item, ItemView};
+
+use super::Objective;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Quest {
+    pub id: Uuid,
+    pub name: String,
+    pub objective: Objective,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub progress: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub completed: bool,
+    pub reward: QuestReward,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct QuestReward {
+    pub gold: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub item: Option<Item>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Quest"))]
+pub struct QuestView {
+    pub id: String,
+    pub name: String,
+    pub objective: Objective,
+    pub progress: u32,
+    pub completed: bool,
+    pub reward_gold: u32,
+    pub reward_item: Option<ItemView>,
+}
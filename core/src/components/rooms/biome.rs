@@ -0,0 +1,34 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// The theme shared by a region of rooms, which keeps what is found in them consistent.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Biome {
+    CryptComplex,
+    GoblinWarren,
+    FloodedCaverns,
+    VampireManor,
+}
+
+impl Biome {
+    pub fn area_name(&self) -> &'static str {
+        match *self {
+            Biome::CryptComplex => "The Crypt Complex",
+            Biome::GoblinWarren => "The Goblin Warren",
+            Biome::FloodedCaverns => "The Flooded Caverns",
+            Biome::VampireManor => "The Vampire Manor",
+        }
+    }
+}
@@ -0,0 +1,48 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use super::Descriptor;
+
+/// An environmental effect a room has on whoever is in it, coming from its descriptors.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(
+    feature = "openapi",
+    derive(Enum),
+    oai(rename_all = "snake_case", rename = "RoomCondition")
+)]
+pub enum Condition {
+    /// Saps the player's health every turn unless they wear a cloak.
+    Freezing,
+    /// Blows traded between the player and NPCs are more likely to miss,
+    /// unless the player holds up a lit light.
+    Dark,
+    /// The steam soaks up fire, weakening fire spells.
+    Steamy,
+    /// The damp grounds out electric spells, weakening them.
+    Moist,
+}
+
+impl Descriptor {
+    pub fn condition(&self) -> Option<Condition> {
+        match *self {
+            Descriptor::Freezing => Some(Condition::Freezing),
+            Descriptor::Dark => Some(Condition::Dark),
+            Descriptor::Steamy => Some(Condition::Steamy),
+            Descriptor::Moist => Some(Condition::Moist),
+            Descriptor::Chill | Descriptor::Dim | Descriptor::Grimy | Descriptor::Sweltering => {
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Where a room sits on its floor's grid. Rooms generated one at a time don't have any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct Coordinates {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coordinates {
+    pub fn neighbours(&self) -> Vec<Coordinates> {
+        vec![
+            Coordinates {
+                x: self.x,
+                y: self.y - 1,
+            },
+            Coordinates {
+                x: self.x + 1,
+                y: self.y,
+            },
+            Coordinates {
+                x: self.x,
+                y: self.y + 1,
+            },
+            Coordinates {
+                x: self.x - 1,
+                y: self.y,
+            },
+        ]
+    }
+}
@@ -24,4 +24,7 @@ pub struct FixturePosition {
 pub struct FixturePositionView {
     pub fixture: FixtureView,
     pub position_descriptor: Option<FixturePositionDescriptor>,
+    /// Too dim to make out anything but the shape of it.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_obscured: bool,
 }
@@ -0,0 +1,34 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// How well the player can see in a room, which decides how much of it shows up in its view.
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum LightLevel {
+    /// Nothing in the room can be made out, only the way out.
+    Dark,
+    /// Shapes can be made out, but none of the details.
+    Dim,
+    Bright,
+}
+
+impl LightLevel {
+    /// A carried light pushes the darkness back by one step.
+    pub fn brightened(&self) -> LightLevel {
+        match *self {
+            LightLevel::Dark => LightLevel::Dim,
+            LightLevel::Dim | LightLevel::Bright => LightLevel::Bright,
+        }
+    }
+}
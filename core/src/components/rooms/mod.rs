@@ -1,3 +1,6 @@
+pub mod biome;
+pub mod condition;
+pub mod coordinates;
 pub mod descriptor;
 pub mod dimensions;
 pub mod exit;
@@ -6,12 +9,16 @@ pub mod exit_type;
 pub mod fixture_position;
 pub mod fixture_position_descriptor;
 pub mod flavour;
+pub mod light_level;
 pub mod npc_position;
 pub mod npc_position_descriptor;
 pub mod room;
 pub mod room_type;
 pub mod room_view;
 
+pub use biome::{Biome, BiomeIter};
+pub use condition::{Condition, ConditionIter};
+pub use coordinates::Coordinates;
 pub use descriptor::{Descriptor, DescriptorIter};
 pub use dimensions::Dimensions;
 pub use exit::{Exit, ExitView};
@@ -20,6 +27,7 @@ pub use exit_type::{ExitType, ExitTypeIter};
 pub use fixture_position::{FixturePosition, FixturePositionView};
 pub use fixture_position_descriptor::{FixturePositionDescriptor, FixturePositionDescriptorIter};
 pub use flavour::{Flavour, FlavourIter};
+pub use light_level::{LightLevel, LightLevelIter};
 pub use npc_position::{NpcPosition, NpcPositionView};
 pub use npc_position_descriptor::NpcPositionDescriptor;
 pub use room::Room;
@@ -24,4 +24,7 @@ pub struct NpcPosition {
 pub struct NpcPositionView {
     pub npc: NonPlayerView,
     pub position_descriptor: Option<NpcPositionDescriptor>,
+    /// Too dim to make out anything but the shape of them.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_obscured: bool,
 }
@@ -6,7 +6,10 @@ use uuid::Uuid;
 
 use crate::components::{items::Item, NonPlayer};
 
-use super::{Descriptor, Dimensions, Exit, FixturePosition, Flavour, NpcPosition, RoomType};
+use super::{
+    Biome, Condition, Coordinates, Descriptor, Dimensions, Exit, FixturePosition, Flavour,
+    LightLevel, NpcPosition, RoomType,
+};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -25,13 +28,47 @@ pub struct Room {
     pub npc_positions: Vec<NpcPosition>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub flavour: Option<Flavour>,
+    /// Hand written flavour for rooms built from a template.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub flavour_text: Option<String>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub exits: Vec<Exit>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub loose_items: Vec<Item>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub coordinates: Option<Coordinates>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub biome: Option<Biome>,
 }
 
 impl Room {
+    pub fn conditions(&self) -> Vec<Condition> {
+        let mut conditions: Vec<Condition> = Vec::new();
+        for condition in self.descriptors.iter().filter_map(Descriptor::condition) {
+            if !conditions.contains(&condition) {
+                conditions.push(condition);
+            }
+        }
+        conditions
+    }
+
+    /// The room's own light, before anything the player carries in with them.
+    pub fn light_level(&self) -> LightLevel {
+        if self.descriptors.contains(&Descriptor::Dark) {
+            LightLevel::Dark
+        } else if self.descriptors.contains(&Descriptor::Dim) {
+            LightLevel::Dim
+        } else {
+            LightLevel::Bright
+        }
+    }
+
+    pub fn has_condition(&self, condition: Condition) -> bool {
+        self.descriptors
+            .iter()
+            .any(|descriptor| descriptor.condition() == Some(condition))
+    }
+
     pub fn find_npc(&self, npc_id: &Uuid) -> Option<&NonPlayer> {
         self.npc_positions
             .iter()
@@ -53,6 +90,25 @@ impl Room {
             .find(|_| true) // First one
     }
 
+    pub fn first_alive_hostile_npc(&self) -> Option<&NonPlayer> {
+        self.npc_positions
+            .iter()
+            .map(|npc_position| &npc_position.npc)
+            .find(|npc| !npc.character.is_dead() && npc.disposition.is_hostile())
+    }
+
+    /// The prisoner, still alive, once nobody hostile is left standing guard over them.
+    pub fn freed_prisoner(&self) -> Option<&NonPlayer> {
+        if self.first_alive_hostile_npc().is_some() {
+            return None;
+        }
+
+        self.npc_positions
+            .iter()
+            .map(|npc_position| &npc_position.npc)
+            .find(|npc| npc.is_prisoner && !npc.character.is_dead())
+    }
+
     pub fn find_npc_mut(&mut self, target_id: &Uuid) -> Option<&mut NpcPosition> {
         self.npc_positions
             .iter_mut()
@@ -65,6 +121,14 @@ impl Room {
             .find(|fixture_position| fixture_position.fixture.id.eq(fixture_id))
     }
 
+    pub fn remove_npc(&mut self, npc_id: &Uuid) -> Option<NonPlayer> {
+        let index = self
+            .npc_positions
+            .iter()
+            .position(|npc_position| npc_position.npc.id.eq(npc_id));
+        index.map(|i| self.npc_positions.remove(i).npc)
+    }
+
     pub fn remove_loose_item(&mut self, item_id: &Uuid) -> Option<Item> {
         let index = self
             .loose_items
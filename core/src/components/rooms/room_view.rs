@@ -8,7 +8,8 @@ use serde::{Deserialize, Serialize};
 use crate::components::items::ItemView;
 
 use super::{
-    Descriptor, Dimensions, ExitView, FixturePositionView, Flavour, NpcPositionView, RoomType,
+    Biome, Condition, Coordinates, Descriptor, Dimensions, ExitView, FixturePositionView, Flavour,
+    LightLevel, NpcPositionView, RoomType,
 };
 
 #[derive(Clone, Debug)]
@@ -19,13 +20,19 @@ pub struct RoomView {
     pub id: String,
     pub name: Option<String>,
     pub descriptors: Vec<Descriptor>,
+    pub conditions: Vec<Condition>,
+    pub light_level: LightLevel,
     pub room_type: RoomType,
     pub fixture_positions: Vec<FixturePositionView>,
     pub dimensions: Dimensions,
     pub npc_positions: Vec<NpcPositionView>,
     pub flavour: Option<Flavour>,
+    pub flavour_text: Option<String>,
     pub exits: Vec<ExitView>,
     pub loose_items: Vec<ItemView>,
+    pub coordinates: Option<Coordinates>,
+    pub biome: Option<Biome>,
+    pub area_name: Option<String>,
 }
 
 #[derive(Clone, Debug, Default)]
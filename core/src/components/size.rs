@@ -33,4 +33,30 @@ impl Size {
     pub fn is_average(&self) -> bool {
         matches!(*self, Size::Medium | Size::Average)
     }
+
+    /// How imposing something of this size is, from smallest to largest.
+    pub fn scale(&self) -> i32 {
+        match *self {
+            Size::Tiny => 0,
+            Size::Small => 1,
+            Size::Narrow | Size::Short | Size::Squat => 2,
+            Size::Average | Size::Long | Size::Medium => 3,
+            Size::Tall | Size::Wide => 4,
+            Size::Large => 5,
+            Size::Huge => 6,
+            Size::Massive => 7,
+        }
+    }
+
+    pub fn carry_capacity_modifier(&self) -> i32 {
+        match *self {
+            Size::Massive => 20,
+            Size::Huge => 15,
+            Size::Large | Size::Tall | Size::Wide => 10,
+            Size::Average | Size::Long | Size::Medium => 0,
+            Size::Narrow | Size::Short | Size::Squat => -5,
+            Size::Small => -10,
+            Size::Tiny => -15,
+        }
+    }
 }
@@ -4,9 +4,10 @@ use bevy_ecs::prelude::Component;
 use poem_openapi::Enum;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
-use strum_macros::EnumIter;
+use strum_macros::{Display, EnumIter, EnumString};
 
-#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "snake_case")]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -30,3 +31,19 @@ pub enum Species {
     Shadow,
     Turtlekin,
 }
+
+impl Species {
+    /// How much weight an average sized member of the species can carry before slowing down.
+    pub fn carry_capacity(&self) -> i32 {
+        match *self {
+            Species::Bugbear | Species::Ogre | Species::Rockoblin | Species::Turtlekin => 60,
+            Species::Dragonkin
+            | Species::Hobgoblin
+            | Species::Lizardkin
+            | Species::Moblin
+            | Species::Orc => 50,
+            Species::Frogkin | Species::Goblin | Species::Kobold => 35,
+            Species::Phantom | Species::Shadow => 20,
+        }
+    }
+}
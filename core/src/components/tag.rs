@@ -35,6 +35,7 @@ pub enum Tag {
     Fixture,
     Instrument,
     Leather,
+    Light,
     Metal,
     Paper,
     Rope,
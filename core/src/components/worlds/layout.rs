@@ -0,0 +1,25 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// How the rooms of a new game are laid out.
+#[derive(Clone, Copy, Debug, Default, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Layout {
+    /// Each room is generated the first time one of its exits is taken.
+    #[default]
+    Lazy,
+    /// A whole floor of rooms is generated up front on a grid, with loops, dead ends and a
+    /// staircase down.
+    Floor,
+}
@@ -0,0 +1,63 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::components::rooms::{Coordinates, ExitType, RoomType};
+
+/// What the player has explored so far. Only rooms they've seen are on it, and exits that
+/// haven't been taken lead off into the fog.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct Map {
+    pub current_room_id: String,
+    pub rooms: Vec<MapRoom>,
+    pub connections: Vec<MapConnection>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct MapRoom {
+    pub id: String,
+    pub name: Option<String>,
+    pub room_type: RoomType,
+    /// The room's own coordinates when it has them, otherwise a spot worked out from how it
+    /// connects to the rooms around it.
+    pub position: Coordinates,
+    pub is_current: bool,
+    pub num_unexplored_exits: usize,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct MapConnection {
+    pub exit_id: String,
+    pub exit_type: ExitType,
+    pub from_room_id: String,
+    /// Missing while the far side is still unexplored.
+    pub to_room_id: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum MapFormat {
+    #[default]
+    Ascii,
+    Svg,
+    Dot,
+}
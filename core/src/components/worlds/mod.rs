@@ -1,5 +1,9 @@
+pub mod layout;
+pub mod map;
 pub mod world;
 pub mod world_view;
 
+pub use layout::{Layout, LayoutIter};
+pub use map::{Map, MapConnection, MapFormat, MapFormatIter, MapRoom};
 pub use world::{ExitMap, World};
 pub use world_view::{ExitMapView, WorldView};
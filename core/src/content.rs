@@ -0,0 +1,370 @@
+mod room_templates;
+
+use std::ops::RangeInclusive;
+
+use rand::{prelude::ThreadRng, Rng};
+
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    components::{
+        fixtures::{Fixture, FixtureType},
+        items::{Item, ItemType},
+        rooms::Room,
+        Attack, Character, Defense, Material, NonPlayer, Species, Tag,
+    },
+    errors::Error,
+};
+
+pub use room_templates::{RoomTemplate, TemplateExit, TemplateFixture, TemplateNpc};
+
+/// Every built in species, item type and fixture type competes with its custom variants
+/// as if it had this spawn weight.
+pub const BUILT_IN_SPAWN_WEIGHT: u32 = 100;
+
+/// Extra species, items and fixtures loaded from content packs. Each definition is a themed
+/// variant of one of the built in types, and stands in for it at generation time based on its
+/// spawn weight.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(default)
+)]
+pub struct ContentRegistry {
+    pub species: Vec<SpeciesDefinition>,
+    pub items: Vec<ItemDefinition>,
+    pub fixtures: Vec<FixtureDefinition>,
+    pub room_templates: Vec<RoomTemplate>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct SpeciesDefinition {
+    pub name: String,
+    pub base: Species,
+    #[cfg_attr(feature = "serialization", serde(default = "default_spawn_weight"))]
+    pub spawn_weight: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub health_range: Option<RangeInclusive<i32>>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub attack_rolls_range: Option<RangeInclusive<usize>>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub damage_resistance_range: Option<RangeInclusive<i32>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct ItemDefinition {
+    pub name: String,
+    pub base: ItemType,
+    #[cfg_attr(feature = "serialization", serde(default = "default_spawn_weight"))]
+    pub spawn_weight: u32,
+    /// Replaces the base item's tags when not empty.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub tags: Vec<Tag>,
+    /// Replaces the base item's material with one of these when not empty.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub materials: Vec<Material>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub attack_rolls_range: Option<RangeInclusive<usize>>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub damage_resistance_range: Option<RangeInclusive<i32>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct FixtureDefinition {
+    pub name: String,
+    pub base: FixtureType,
+    #[cfg_attr(feature = "serialization", serde(default = "default_spawn_weight"))]
+    pub spawn_weight: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub materials: Vec<Material>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub can_be_opened: Option<bool>,
+}
+
+#[cfg(feature = "serialization")]
+fn default_spawn_weight() -> u32 {
+    BUILT_IN_SPAWN_WEIGHT
+}
+
+impl ContentRegistry {
+    pub fn is_empty(&self) -> bool {
+        self.species.is_empty()
+            && self.items.is_empty()
+            && self.fixtures.is_empty()
+            && self.room_templates.is_empty()
+    }
+
+    /// Add everything from another content pack to this one.
+    pub fn merge(&mut self, other: ContentRegistry) {
+        self.species.extend(other.species);
+        self.items.extend(other.items);
+        self.fixtures.extend(other.fixtures);
+        self.room_templates.extend(other.room_templates);
+    }
+
+    /// Check the loaded definitions can all be rolled, so a bad content pack is rejected when
+    /// it is loaded rather than when a room is generated from it.
+    pub fn validate(&self) -> Result<(), Error> {
+        for definition in &self.species {
+            check_range(&definition.name, "health_range", &definition.health_range)?;
+            check_range(
+                &definition.name,
+                "attack_rolls_range",
+                &definition.attack_rolls_range,
+            )?;
+            check_range(
+                &definition.name,
+                "damage_resistance_range",
+                &definition.damage_resistance_range,
+            )?;
+        }
+
+        for definition in &self.items {
+            check_range(
+                &definition.name,
+                "attack_rolls_range",
+                &definition.attack_rolls_range,
+            )?;
+            check_range(
+                &definition.name,
+                "damage_resistance_range",
+                &definition.damage_resistance_range,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Swap in custom variants for the NPCs, fixtures and items of a freshly generated room.
+    /// Room templates are picked by the room generator instead.
+    pub fn apply_to_room(&self, room: &mut Room) {
+        if self.is_empty() {
+            return;
+        }
+
+        for npc_position in room.npc_positions.iter_mut() {
+            self.apply_to_npc(&mut npc_position.npc);
+        }
+
+        let mut rng = rand::thread_rng();
+        for fixture_position in room.fixture_positions.iter_mut() {
+            self.apply_to_fixture(&mut rng, &mut fixture_position.fixture);
+            for fixture_item in fixture_position.fixture.items.iter_mut() {
+                self.apply_to_item(&mut rng, &mut fixture_item.item);
+            }
+        }
+
+        for item in room.loose_items.iter_mut() {
+            self.apply_to_item(&mut rng, item);
+        }
+    }
+
+    pub fn apply_to_npc(&self, npc: &mut NonPlayer) {
+        let mut rng = rand::thread_rng();
+        let species = npc.character.species;
+        let candidates = self
+            .species
+            .iter()
+            .filter(|definition| definition.base == species)
+            .map(|definition| (definition, definition.spawn_weight));
+        if let Some(definition) = choose(&mut rng, candidates, BUILT_IN_SPAWN_WEIGHT) {
+            apply_species(&mut rng, definition, &mut npc.character);
+        }
+
+        for character_item in npc.character.inventory.equipment.iter_mut() {
+            self.apply_to_item(&mut rng, &mut character_item.item);
+        }
+    }
+
+    fn apply_to_item(&self, rng: &mut ThreadRng, item: &mut Item) {
+        let candidates = self
+            .items
+            .iter()
+            .filter(|definition| definition.base == item.item_type)
+            .map(|definition| (definition, definition.spawn_weight));
+        let definition = match choose(rng, candidates, BUILT_IN_SPAWN_WEIGHT) {
+            Some(it) => it,
+            None => return,
+        };
+
+        item.name = Some(definition.name.clone());
+        if !definition.tags.is_empty() {
+            item.tags = definition.tags.clone();
+        }
+        if let Some(material) = choose_material(rng, &definition.materials) {
+            item.material = Some(material);
+        }
+        if let Some(range) = &definition.attack_rolls_range {
+            let num_rolls = rng.gen_range(range.clone());
+            match item.attack.as_mut() {
+                Some(attack) => attack.num_rolls = num_rolls,
+                None => {
+                    item.attack = Some(Attack {
+                        num_rolls,
+                        modifier: 0,
+                        effects: Vec::new(),
+                    })
+                }
+            }
+        }
+        if let Some(range) = &definition.damage_resistance_range {
+            item.defense = Some(Defense {
+                damage_resistance: rng.gen_range(range.clone()),
+            });
+        }
+    }
+
+    fn apply_to_fixture(&self, rng: &mut ThreadRng, fixture: &mut Fixture) {
+        let candidates = self
+            .fixtures
+            .iter()
+            .filter(|definition| definition.base == fixture.fixture_type)
+            .map(|definition| (definition, definition.spawn_weight));
+        let definition = match choose(rng, candidates, BUILT_IN_SPAWN_WEIGHT) {
+            Some(it) => it,
+            None => return,
+        };
+
+        fixture.name = Some(definition.name.clone());
+        if let Some(material) = choose_material(rng, &definition.materials) {
+            fixture.material = Some(material);
+        }
+        if let Some(can_be_opened) = definition.can_be_opened {
+            fixture.can_be_opened = can_be_opened;
+        }
+    }
+}
+
+fn apply_species(rng: &mut ThreadRng, definition: &SpeciesDefinition, character: &mut Character) {
+    character.custom_species = Some(definition.name.clone());
+    if let Some(range) = &definition.health_range {
+        let health = rng.gen_range(range.clone());
+        character.stats.health.max = health;
+        if !character.is_dead() {
+            character.stats.health.current = health;
+        }
+    }
+    if let Some(range) = &definition.attack_rolls_range {
+        character.stats.base_attack = Some(Attack {
+            num_rolls: rng.gen_range(range.clone()),
+            modifier: 0,
+            effects: Vec::new(),
+        });
+    }
+    if let Some(range) = &definition.damage_resistance_range {
+        character.stats.base_damage_resistance = Some(Defense {
+            damage_resistance: rng.gen_range(range.clone()),
+        });
+    }
+}
+
+fn check_range<T: PartialOrd>(
+    name: &str,
+    field: &str,
+    range: &Option<RangeInclusive<T>>,
+) -> Result<(), Error> {
+    match range {
+        Some(range) if range.is_empty() => Err(Error::InvalidContentError(format!(
+            "{} has an empty {}",
+            name, field
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn choose_material(rng: &mut ThreadRng, materials: &[Material]) -> Option<Material> {
+    if materials.is_empty() {
+        return None;
+    }
+
+    materials.get(rng.gen_range(0..materials.len())).cloned()
+}
+
+/// Pick one of the weighted candidates, or `None` when the built in option wins the roll.
+pub(crate) fn choose<'a, T>(
+    rng: &mut ThreadRng,
+    candidates: impl Iterator<Item = (&'a T, u32)>,
+    built_in_weight: u32,
+) -> Option<&'a T> {
+    let candidates: Vec<(&T, u32)> = candidates.collect();
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let total: u64 = candidates
+        .iter()
+        .map(|(_, weight)| u64::from(*weight))
+        .sum::<u64>()
+        + u64::from(built_in_weight);
+    let mut roll = rng.gen_range(0..total);
+    for (definition, weight) in candidates {
+        if roll < u64::from(weight) {
+            return Some(definition);
+        }
+        roll -= u64::from(weight);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeInclusive;
+
+    use crate::{
+        components::{items::ItemType, Material, Species, Tag},
+        generators::{generator::Generator, items::item_generator_for_level},
+    };
+
+    use super::{ContentRegistry, ItemDefinition, SpeciesDefinition};
+
+    #[test]
+    fn heavily_weighted_items_replace_the_built_in_type() {
+        let registry = ContentRegistry {
+            items: vec![ItemDefinition {
+                name: "Gnoll Cleaver".to_string(),
+                base: ItemType::LongSword,
+                spawn_weight: u32::MAX,
+                tags: vec![Tag::Blade],
+                materials: vec![Material::Bone],
+                attack_rolls_range: Some(3..=3),
+                damage_resistance_range: None,
+            }],
+            ..Default::default()
+        };
+
+        let mut item = item_generator_for_level(&ItemType::LongSword, false, 1).generate();
+        let mut rng = rand::thread_rng();
+        registry.apply_to_item(&mut rng, &mut item);
+
+        assert_eq!(item.name, Some("Gnoll Cleaver".to_string()));
+        assert_eq!(item.material, Some(Material::Bone));
+        assert_eq!(item.attack.map(|attack| attack.num_rolls), Some(3));
+    }
+
+    #[test]
+    fn reversed_ranges_fail_validation() {
+        let registry = ContentRegistry {
+            species: vec![SpeciesDefinition {
+                name: "Frost Goblin".to_string(),
+                base: Species::Goblin,
+                spawn_weight: 100,
+                health_range: Some(RangeInclusive::new(12, 4)),
+                attack_rolls_range: None,
+                damage_resistance_range: None,
+            }],
+            ..Default::default()
+        };
+
+        assert!(registry.validate().is_err());
+        assert!(ContentRegistry::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::components::{
+    fixtures::FixtureType,
+    items::ItemType,
+    rooms::{
+        Descriptor, Dimensions, ExitDescriptor, ExitType, FixturePositionDescriptor, Flavour,
+        NpcPositionDescriptor, RoomType,
+    },
+    Disposition, LifeModifier, Material, Size, Species,
+};
+
+/// A handcrafted set piece, like a throne room or a goblin kitchen, that the room generator can
+/// pick instead of rolling a random room.
+///
+/// A template with no spawn weight only shows up at the depths listed in `depths`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct RoomTemplate {
+    pub name: String,
+    pub room_type: RoomType,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub spawn_weight: u32,
+    /// Danger levels where this room is always generated, or the first room past them when the
+    /// danger level jumps over one.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub depths: Vec<u32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub min_danger_level: Option<u32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub max_danger_level: Option<u32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub descriptors: Vec<Descriptor>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub flavour: Option<Flavour>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub flavour_text: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub dimensions: Option<Dimensions>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub fixtures: Vec<TemplateFixture>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub npcs: Vec<TemplateNpc>,
+    /// The first exit is the one the player walks in through. Rooms without any get random
+    /// exits for their room type.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub exits: Vec<TemplateExit>,
+}
+
+impl RoomTemplate {
+    /// The deepest of `depths` that `danger_level` has reached and the template hasn't already
+    /// been forced at, given the depth it was last forced at.
+    pub fn forced_depth(&self, danger_level: u32, last_forced_depth: Option<u32>) -> Option<u32> {
+        self.depths
+            .iter()
+            .copied()
+            .filter(|depth| *depth <= danger_level && *depth > last_forced_depth.unwrap_or(0))
+            .max()
+    }
+
+    pub fn can_spawn_at(&self, danger_level: u32) -> bool {
+        self.spawn_weight > 0
+            && self.min_danger_level.unwrap_or(0) <= danger_level
+            && self.max_danger_level.unwrap_or(u32::MAX) >= danger_level
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct TemplateFixture {
+    pub fixture_type: FixtureType,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub material: Option<Material>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub position_descriptor: Option<FixturePositionDescriptor>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub items: Vec<ItemType>,
+    /// Any items here give the fixture a hidden compartment to keep them in.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub hidden_items: Vec<ItemType>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct TemplateNpc {
+    pub species: Species,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub life_modifier: Option<LifeModifier>,
+    /// Falls back to how the species usually feels about the player.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub disposition: Option<Disposition>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub position_descriptor: Option<NpcPositionDescriptor>,
+    /// Equipped where the item is normally carried at the ready.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub items: Vec<ItemType>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub packed_items: Vec<ItemType>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct TemplateExit {
+    pub exit_type: ExitType,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub material: Option<Material>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub size: Option<Size>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub descriptors: Vec<ExitDescriptor>,
+}
@@ -1,17 +1,33 @@
 #[derive(Clone, Debug, thiserror::Error, strum_macros::Display)]
 pub enum Error {
+    CarryCapacityExceededError(String),
+    CompanionNotFoundError(String),
     ExitNotFoundError(String),
     FixtureNotFoundError(String),
+    GameCompletedError,
     FixtureCannotBeFound(String),
     FixtureCannotBeOpened(String),
     FixtureHasNoHiddenCompartment(String),
     FixtureHasHiddenCompartmentUnknown(String),
+    InvalidContentError(String),
     InvalidIdError(String),
+    InvalidItemLocationError(String),
+    LightSourceBurnedOutError(String),
     ItemNotDirectlyUsableError(String),
     ItemNotFoundError(String),
+    ItemNotLightSourceError(String),
     ItemNotThrowableError(String),
+    NotEnoughFreeHandsError(String),
+    NotEnoughGoldError,
+    NpcCannotBeRecruitedError(String),
+    NpcCannotParleyError(String),
+    NpcIsDeadError(String),
     NpcNotFoundError(String),
+    OffHandWeaponRequiredError(String),
+    PartyFullError,
+    NoPathToRoomError(String),
     PlayerIsDeadError,
+    RoomNotFoundError(String),
     SpellNotFoundError(String),
     TooManyWeaponsEquippedError,
     TooManyWearablesEquippedError,
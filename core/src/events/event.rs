@@ -6,9 +6,10 @@ use uuid::Uuid;
 
 use crate::components::{
     games::GameState,
-    items::{CharacterItem, LocationTag},
+    items::{CharacterItem, ConsumableEffectName, LightSource, LocationTag},
+    rooms::NpcPosition,
     spells::LearnedSpell,
-    PlayerCharacter, Poison,
+    Blindness, Disposition, PlayerCharacter, Poison,
 };
 
 use super::NpcDamagedByPoison;
@@ -21,6 +22,10 @@ use super::NpcDamagedByPoison;
     serde(rename_all = "snake_case", tag = "event_type")
 )]
 pub enum Event {
+    CompanionHit(super::CompanionHit),
+    CompanionKilled(super::CompanionKilled),
+    CompanionReceivedItem(super::CompanionReceivedItem),
+    FactionReputationChanged(super::FactionReputationChanged),
     GameDangerLevelIncreased(u32),
     GhostEscapesToTheVoid(super::GhostEscapesToTheVoid),
     DeadNpcBeaten(super::DeadNpcBeaten),
@@ -28,21 +33,39 @@ pub enum Event {
     FixtureHiddenCompartmentOpened(super::FixtureHiddenCompartmentOpened),
     FixtureOpened(super::FixtureOpened),
     FixtureViewed(super::FixtureViewed),
+    ItemIdentified(super::ItemIdentified),
+    ItemTakenFromCompanion(super::ItemTakenFromCompanion),
     ItemTakenFromFixture(super::ItemTakenFromFixture),
     ItemTakenFromNpc(super::ItemTakenFromNpc),
+    NpcBlinded(super::NpcBlinded),
+    NpcBlindnessDissipated(super::NpcBlindnessDissipated),
+    NpcBlindnessDurationChanged(super::NpcBlindnessDurationChanged),
+    NpcBribed(super::NpcBribed),
     NpcCoveredInOil(Uuid),
     NpcDamagedByPoison(NpcDamagedByPoison),
+    NpcDispositionChanged(super::NpcDispositionChanged),
     NpcHealthDiscovered(super::NpcHealthDiscovered),
+    NpcHitNpc(super::NpcHitNpc),
     NpcHitWithAcid(Uuid),
     NpcItemDestroyed(super::NpcItemDestroyed),
+    NpcKilledNpc(super::NpcKilledNpc),
     NpcMissed(super::NpcMissed),
+    NpcMissedNpc(super::NpcMissedNpc),
+    NpcMoved(super::NpcMoved),
+    NpcMovementHeard(super::NpcMovementHeard),
+    NpcOilIgnited(Uuid),
     NpcPackedDiscovered(super::NpcPackedDiscovered),
     NpcPoisonDurationChanged(super::NpcPoisonEffectDurationChanged),
     NpcPoisonEffectDissipated(super::NpcPoisonEffectDissipated),
     NpcPoisonLevelChanged(super::NpcPoisonLevelChanged),
     NpcPoisoned(super::NpcPoisoned),
+    NpcRecruited(super::NpcRecruited),
+    NpcRegrouped(super::NpcRegrouped),
+    NpcSpoke(super::NpcSpoke),
     NpcViewed(super::NpcViewed),
     NpcWeaponReadied(super::NpcWeaponReadied),
+    PlayerBlockedAttack(super::PlayerBlockedAttack),
+    PlayerDamagedByCold(i32),
     PlayerDamagedByPoison(i32),
     PlayerDropsAllItems,
     PlayerGainedGold(u32),
@@ -55,6 +78,10 @@ pub enum Event {
     PlayerHitNpc(super::PlayerHitNpc),
     PlayerItemMoved(super::PlayerItemMoved),
     PlayerHitWithAcid,
+    PlayerItemBurnTimeChanged(super::PlayerItemBurnTimeChanged),
+    PlayerItemExtinguished(super::PlayerItemExtinguished),
+    PlayerItemFizzled(super::PlayerItemFizzled),
+    PlayerItemLit(super::PlayerItemLit),
     PlayerItemDestroyed(Uuid),
     PlayerItemRemoved(super::PlayerItemRemoved),
     PlayerItemUsed(super::PlayerItemUsed),
@@ -74,9 +101,29 @@ pub enum Event {
     PlayerSpellForgotten(super::PlayerSpellForgotten),
     PlayerSpellLearned(super::PlayerSpellLearned),
     PlayerSpellUsed(super::PlayerSpellUsed),
+    PlayerVictorious(super::PlayerVictorious),
+    QuestCompleted(super::QuestCompleted),
+    QuestProgressed(super::QuestProgressed),
     RoomExited(super::RoomExited),
     RoomGenerated(super::RoomGenerated),
     RoomFirstSeen(super::RoomFirstSeen),
+    RoomRepopulated(super::RoomRepopulated),
+    TurnsPassed(u32),
+}
+
+/// Pairs each event with the game turn it happened on, counting on from the turn the action
+/// started on. Every `TurnsPassed` moves the clock on for the events after it.
+pub fn stamp_turns(start_turn: u32, events: Vec<Event>) -> Vec<(u32, Event)> {
+    let mut turn = start_turn;
+    events
+        .into_iter()
+        .map(|event| {
+            if let Event::TurnsPassed(turns) = &event {
+                turn += turns;
+            }
+            (turn, event)
+        })
+        .collect()
 }
 
 pub fn apply_events(
@@ -91,35 +138,90 @@ pub fn apply_events(
         match event {
             Event::RoomExited(room_exited) => {
                 new_game.current_room_id = room_exited.new_room_id;
+                new_game.player_stats_mut(&player.id).rooms_visited += 1;
+            }
+            Event::RoomGenerated(room_generated) => {
+                if let Some(milestone) = room_generated.boss_milestone {
+                    new_game.last_boss_milestone = milestone;
+                }
+                if let (Some(depth), Some(name)) = (
+                    room_generated.forced_template_depth,
+                    &room_generated.room.name,
+                ) {
+                    new_game.template_depths_used.insert(name.clone(), depth);
+                }
+                new_game
+                    .world
+                    .add_room(room_generated.entrance_id, room_generated.room.clone());
             }
-            Event::RoomGenerated(room_generated) => new_game
-                .world
-                .add_room(room_generated.entrance_id, room_generated.room.clone()),
             Event::PlayerHitNpc(npc_hit) => {
-                if let Some(position) = new_game.current_room_mut().find_npc_mut(&npc_hit.npc_id) {
+                if let Some(position) = new_game.find_npc_mut(&npc_hit.npc_id) {
                     position.npc.character.damage(npc_hit.damage);
                 }
                 new_game.add_player_damage_done_to_stats(&npc_hit.attacker_id, npc_hit.damage);
             }
             Event::PlayerKilledNpc(npc_killed) => {
-                let room = new_game.current_room_mut();
-                if let Some(position) = room.find_npc_mut(&npc_killed.npc_id) {
+                let killed = new_game
+                    .find_npc(&npc_killed.npc_id)
+                    .map(|npc| (npc.character.species, npc.character.life_modifier));
+                if let Some(position) = new_game.find_npc_mut(&npc_killed.npc_id) {
                     position.npc.character.kill();
                     position.position_descriptor = None;
                 }
+                let cleared_room = new_game.world.rooms.iter().any(|room| {
+                    room.find_npc(&npc_killed.npc_id).is_some()
+                        && room
+                            .npc_positions
+                            .iter()
+                            .all(|npc_position| npc_position.npc.character.is_dead())
+                });
+                if cleared_room {
+                    new_game
+                        .player_stats_mut(&npc_killed.killer_id)
+                        .rooms_cleared += 1;
+                }
                 new_game.add_player_kill_to_stats(&npc_killed.killer_id);
+                if let Some((species, life_modifier)) = killed {
+                    let statistics = new_game.player_stats_mut(&npc_killed.killer_id);
+                    *statistics.kills_by_species.entry(species).or_default() += 1;
+                    if let Some(life_modifier) = life_modifier {
+                        *statistics
+                            .kills_by_life_modifier
+                            .entry(life_modifier)
+                            .or_default() += 1;
+                    }
+                }
             }
             Event::PlayerHit(player_hit) => {
                 new_player.character.damage(player_hit.damage);
                 new_game.add_player_damage_taken_to_stats(&player.id, player_hit.damage);
             }
-            Event::PlayerKilled(_) => new_player.character.kill(),
-            Event::ItemTakenFromNpc(item_taken_from_npc) => take_item_from_npc(
-                &mut new_game,
-                &mut new_player,
-                &item_taken_from_npc.npc_id,
-                &item_taken_from_npc.item_id,
-            ),
+            Event::PlayerKilled(player_killed) => {
+                new_player.character.kill();
+                let killed_by = player_killed
+                    .killer_id
+                    .and_then(|killer_id| new_game.find_npc(&killer_id))
+                    .map(|npc| npc.character.species);
+                let statistics = new_game.player_stats_mut(&player.id);
+                statistics.cause_of_death = Some(player_killed.cause_of_death);
+                statistics.killed_by = killed_by;
+            }
+            Event::ItemTakenFromNpc(item_taken_from_npc) => {
+                let item_type = new_game
+                    .current_room()
+                    .find_npc(&item_taken_from_npc.npc_id)
+                    .and_then(|npc| npc.character.find_item(&item_taken_from_npc.item_id))
+                    .map(|character_item| character_item.item.item_type);
+                take_item_from_npc(
+                    &mut new_game,
+                    &mut new_player,
+                    &item_taken_from_npc.npc_id,
+                    &item_taken_from_npc.item_id,
+                );
+                if let Some(item_type) = item_type {
+                    new_game.add_item_looted_to_stats(&player.id, item_type);
+                }
+            }
             Event::NpcWeaponReadied(weapon_readied) => ready_npc_weapon(
                 &mut new_game,
                 &weapon_readied.npc_id,
@@ -134,6 +236,26 @@ pub fn apply_events(
                 character_item.equipped_location = item_moved.location;
                 new_player.character.add_item(character_item);
             }
+            Event::PlayerItemLit(item_lit) => {
+                if let Some(light_source) = light_source_mut(&mut new_player, &item_lit.item_id) {
+                    light_source.is_lit = true;
+                }
+            }
+            Event::PlayerItemExtinguished(item_extinguished) => {
+                if let Some(light_source) =
+                    light_source_mut(&mut new_player, &item_extinguished.item_id)
+                {
+                    light_source.is_lit = false;
+                }
+            }
+            Event::PlayerItemBurnTimeChanged(burn_time_changed) => {
+                if let Some(light_source) =
+                    light_source_mut(&mut new_player, &burn_time_changed.item_id)
+                {
+                    light_source.burn_time =
+                        (light_source.burn_time + burn_time_changed.burn_time).max(0);
+                }
+            }
             Event::NpcHealthDiscovered(health_discovered) => {
                 let mut knowledge = new_game.npc_knowledge(&health_discovered.npc_id);
                 knowledge.knows_health = true;
@@ -149,6 +271,12 @@ pub fn apply_events(
                 knowledge.knows_has_hidden_compartment = true;
                 new_game.set_fixture_knowledge(has_hidden.fixture_id, knowledge);
             }
+            Event::ItemIdentified(identified) => {
+                let knowledge = new_game
+                    .item_knowledge(&identified.item_id)
+                    .merge(&identified.knowledge);
+                new_game.set_item_knowledge(identified.item_id, knowledge);
+            }
             Event::RoomFirstSeen(first_seen) => {
                 new_game.rooms_seen.push(first_seen.room_id);
             }
@@ -191,6 +319,11 @@ pub fn apply_events(
                     .find_spell_mut(&player_spell_used.spell_id)
                 {
                     learned_spell.spell.uses -= 1;
+                    *new_game
+                        .player_stats_mut(&player.id)
+                        .spells_cast
+                        .entry(learned_spell.spell.name)
+                        .or_default() += 1;
                 }
             }
             Event::PlayerItemRemoved(player_item_removed) => {
@@ -202,6 +335,7 @@ pub fn apply_events(
                 new_player.character.remove_item(item_id);
             }
             Event::PlayerSpellLearned(player_spell_learned) => {
+                new_game.add_spell_learned_to_stats(&player.id, player_spell_learned.spell.name);
                 new_player.character.spell_memory.add_spell(LearnedSpell {
                     id: player_spell_learned.spell_id,
                     spell: player_spell_learned.spell.clone(),
@@ -214,6 +348,14 @@ pub fn apply_events(
                         Some(it) => it,
                         None => continue,
                     };
+                if let Some(ConsumableEffectName::HealingGrog) = character_item
+                    .item
+                    .consumable
+                    .as_ref()
+                    .map(|consumable| consumable.effect.name)
+                {
+                    new_game.player_stats_mut(&player.id).potions_used += 1;
+                }
                 character_item.decrease_uses();
                 new_player.character.add_item(character_item);
             }
@@ -224,15 +366,26 @@ pub fn apply_events(
             Event::PlayerMaxHealthChanged(change) => {
                 new_player.character.increase_max_health(*change);
             }
-            Event::GameDangerLevelIncreased(level) => new_game.danger_level += level,
+            Event::GameDangerLevelIncreased(level) => {
+                new_game.danger_level += level;
+                let danger_level = new_game.danger_level;
+                let statistics = new_game.player_stats_mut(&player.id);
+                statistics.deepest_danger_level = statistics.deepest_danger_level.max(danger_level);
+            }
+            Event::TurnsPassed(turns) => {
+                new_game.turn += turns;
+                if !new_player.character.is_dead() {
+                    new_game.player_stats_mut(&player.id).turns_survived += turns;
+                }
+            }
             Event::NpcDamagedByPoison(poison_damage) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&poison_damage.npc_id)
-                {
+                if let Some(position) = new_game.find_npc_mut(&poison_damage.npc_id) {
                     position.npc.character.damage(poison_damage.damage);
                 }
             }
+            Event::PlayerDamagedByCold(damage) => {
+                new_player.character.damage(*damage);
+            }
             Event::PlayerDamagedByPoison(damage) => {
                 new_player.character.damage(*damage);
             }
@@ -242,27 +395,21 @@ pub fn apply_events(
                 }
             }
             Event::NpcPoisonDurationChanged(poison_change) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&poison_change.npc_id)
-                {
+                if let Some(position) = new_game.find_npc_mut(&poison_change.npc_id) {
                     if let Some(poison) = position.npc.character.current_effects.poison.as_mut() {
                         poison.duration += poison_change.duration;
                     }
                 }
             }
             Event::NpcPoisonLevelChanged(poison_change) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&poison_change.npc_id)
-                {
+                if let Some(position) = new_game.find_npc_mut(&poison_change.npc_id) {
                     if let Some(poison) = position.npc.character.current_effects.poison.as_mut() {
                         poison.duration += poison_change.damage;
                     }
                 }
             }
             Event::NpcPoisoned(poisoned) => {
-                if let Some(position) = new_game.current_room_mut().find_npc_mut(&poisoned.npc_id) {
+                if let Some(position) = new_game.find_npc_mut(&poisoned.npc_id) {
                     position.npc.character.current_effects.poison = Some(Poison {
                         damage: poisoned.damage,
                         duration: poisoned.duration,
@@ -282,6 +429,7 @@ pub fn apply_events(
             }
             Event::PlayerGainedGold(gold) => {
                 new_player.gold += gold;
+                new_game.player_stats_mut(&player.id).gold_earned += gold;
             }
             Event::PlayerShieldAuraDamaged(damage) => {
                 if let Some(shield_aura) = new_player.character.current_effects.shield_aura.as_mut()
@@ -293,10 +441,7 @@ pub fn apply_events(
                 new_player.character.current_effects.shield_aura = None
             }
             Event::NpcPoisonEffectDissipated(effect_dissipated) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&effect_dissipated.npc_id)
-                {
+                if let Some(position) = new_game.find_npc_mut(&effect_dissipated.npc_id) {
                     position.npc.character.current_effects.poison = None;
                 }
             }
@@ -304,10 +449,7 @@ pub fn apply_events(
                 new_player.character.current_effects.poison = None;
             }
             Event::NpcItemDestroyed(npc_item_destroyed) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&npc_item_destroyed.npc_id)
-                {
+                if let Some(position) = new_game.find_npc_mut(&npc_item_destroyed.npc_id) {
                     position
                         .npc
                         .character
@@ -315,10 +457,129 @@ pub fn apply_events(
                 }
             }
             Event::NpcCoveredInOil(npc_id) => {
-                if let Some(position) = new_game.current_room_mut().find_npc_mut(npc_id) {
+                if let Some(position) = new_game.find_npc_mut(npc_id) {
                     position.npc.character.current_effects.covered_in_oil = true;
                 }
             }
+            Event::NpcOilIgnited(npc_id) => {
+                if let Some(position) = new_game.find_npc_mut(npc_id) {
+                    position.npc.character.current_effects.covered_in_oil = false;
+                }
+            }
+            Event::NpcBribed(bribed) => {
+                new_player.gold = new_player.gold.saturating_sub(bribed.gold);
+                new_game.player_stats_mut(&player.id).gold_spent += bribed.gold;
+            }
+            Event::NpcHitNpc(npc_hit) => {
+                if let Some(position) = new_game.find_npc_mut(&npc_hit.npc_id) {
+                    position.npc.character.damage(npc_hit.damage);
+                }
+            }
+            Event::NpcKilledNpc(npc_killed) => {
+                if let Some(position) = new_game.find_npc_mut(&npc_killed.npc_id) {
+                    position.npc.character.kill();
+                    position.position_descriptor = None;
+                }
+            }
+            Event::FactionReputationChanged(reputation_changed) => {
+                new_game.change_reputation(&reputation_changed.faction, reputation_changed.change);
+            }
+            Event::NpcMoved(npc_moved) => {
+                let npc = new_game
+                    .find_room_mut(&npc_moved.from_room_id)
+                    .and_then(|room| room.remove_npc(&npc_moved.npc_id));
+                if let (Some(mut npc), Some(room)) =
+                    (npc, new_game.find_room_mut(&npc_moved.to_room_id))
+                {
+                    npc.has_fled = npc_moved.fled;
+                    room.npc_positions.push(NpcPosition {
+                        npc,
+                        position_descriptor: None,
+                    });
+                }
+            }
+            Event::NpcRegrouped(regrouped) => {
+                if let Some(position) = new_game
+                    .find_room_mut(&regrouped.room_id)
+                    .and_then(|room| room.find_npc_mut(&regrouped.npc_id))
+                {
+                    position.npc.has_fled = false;
+                }
+            }
+            Event::RoomRepopulated(repopulated) => {
+                if let Some(room) = new_game.find_room_mut(&repopulated.room_id) {
+                    room.npc_positions
+                        .extend(repopulated.npc_positions.iter().cloned());
+                }
+            }
+            Event::NpcRecruited(recruited) => {
+                new_player.gold = new_player.gold.saturating_sub(recruited.gold);
+                new_game.player_stats_mut(&player.id).gold_spent += recruited.gold;
+                if let Some(mut npc) = new_game.current_room_mut().remove_npc(&recruited.npc_id) {
+                    npc.disposition = Disposition::Friendly;
+                    new_game.party.push(npc);
+                }
+            }
+            Event::CompanionReceivedItem(received) => {
+                if let Some(character_item) = new_player.character.remove_item(&received.item_id) {
+                    if let Some(companion) = new_game.find_companion_mut(&received.companion_id) {
+                        companion.character.add_item(packed(character_item));
+                    }
+                }
+            }
+            Event::ItemTakenFromCompanion(taken) => {
+                if let Some(companion) = new_game.find_companion_mut(&taken.companion_id) {
+                    if let Some(character_item) = companion.character.remove_item(&taken.item_id) {
+                        new_player.character.add_item(packed(character_item));
+                    }
+                }
+            }
+            Event::CompanionHit(companion_hit) => {
+                if let Some(companion) = new_game.find_companion_mut(&companion_hit.companion_id) {
+                    companion.character.damage(companion_hit.damage);
+                }
+            }
+            Event::CompanionKilled(companion_killed) => {
+                // The fallen are left behind in the room they died in.
+                if let Some(index) = new_game
+                    .party
+                    .iter()
+                    .position(|companion| companion.id.eq(&companion_killed.companion_id))
+                {
+                    let mut companion = new_game.party.remove(index);
+                    companion.kill();
+                    new_game.current_room_mut().npc_positions.push(NpcPosition {
+                        npc: companion,
+                        position_descriptor: None,
+                    });
+                }
+            }
+            Event::NpcDispositionChanged(disposition_changed) => {
+                if let Some(position) = new_game.find_npc_mut(&disposition_changed.npc_id) {
+                    position.npc.disposition = disposition_changed.disposition;
+                }
+            }
+            Event::NpcBlinded(blinded) => {
+                if let Some(position) = new_game.find_npc_mut(&blinded.npc_id) {
+                    position.npc.character.current_effects.blindness = Some(Blindness {
+                        duration: blinded.duration,
+                    });
+                }
+            }
+            Event::NpcBlindnessDurationChanged(blindness_change) => {
+                if let Some(position) = new_game.find_npc_mut(&blindness_change.npc_id) {
+                    if let Some(blindness) =
+                        position.npc.character.current_effects.blindness.as_mut()
+                    {
+                        blindness.duration += blindness_change.duration;
+                    }
+                }
+            }
+            Event::NpcBlindnessDissipated(dissipated) => {
+                if let Some(position) = new_game.find_npc_mut(&dissipated.npc_id) {
+                    position.npc.character.current_effects.blindness = None;
+                }
+            }
             Event::PlayerDropsAllItems => {
                 new_game
                     .current_room_mut()
@@ -330,6 +591,7 @@ pub fn apply_events(
                     .current_room_mut()
                     .remove_loose_item(item_id)
                     .unwrap();
+                new_game.add_item_looted_to_stats(&player.id, item.item_type);
                 let packed_item = CharacterItem {
                     equipped_location: LocationTag::Packed,
                     item,
@@ -337,9 +599,34 @@ pub fn apply_events(
                 };
                 new_player.character.add_item(packed_item)
             }
+            Event::PlayerVictorious(victorious) => {
+                new_game.run_summary = Some(victorious.summary.clone());
+            }
+            Event::QuestProgressed(quest_progressed) => {
+                if let Some(quest) = new_game.find_quest_mut(&quest_progressed.quest_id) {
+                    quest.progress = quest_progressed.progress;
+                }
+            }
+            Event::QuestCompleted(quest_completed) => {
+                if let Some(quest) = new_game.find_quest_mut(&quest_completed.quest_id) {
+                    quest.completed = true;
+                    if let Some(item) = quest.reward.item.take() {
+                        new_player.character.add_item(CharacterItem {
+                            equipped_location: LocationTag::Packed,
+                            item,
+                            at_the_ready: false,
+                        });
+                    }
+                }
+            }
             Event::NpcMissed(_)
+            | Event::NpcMissedNpc(_)
+            | Event::NpcSpoke(_)
+            | Event::NpcMovementHeard(_)
+            | Event::PlayerBlockedAttack(_)
             | Event::DeadNpcBeaten(_)
             | Event::PlayerMissed(_)
+            | Event::PlayerItemFizzled(_)
             | Event::NpcViewed(_)
             | Event::FixtureViewed(_)
             | Event::NpcHitWithAcid(_)
@@ -378,6 +665,7 @@ fn take_item_from_fixture(
         .find_fixture_mut(fixture_id)
         .unwrap();
     let fixture_item = fixture_position.fixture.remove_item(item_id).unwrap();
+    new_game.add_item_looted_to_stats(&new_player.id, fixture_item.item.item_type);
 
     let packed_item = CharacterItem {
         equipped_location: LocationTag::Packed,
@@ -393,21 +681,96 @@ fn take_item_from_npc(
     npc_id: &Uuid,
     item_id: &Uuid,
 ) {
-    let position = new_game.current_room_mut().find_npc_mut(npc_id).unwrap();
+    let position = new_game.find_npc_mut(npc_id).unwrap();
     let character_item = position.npc.character.remove_item(item_id).unwrap();
 
-    let packed_item = CharacterItem {
+    new_player.character.add_item(packed(character_item));
+}
+
+fn light_source_mut<'a>(
+    player: &'a mut PlayerCharacter,
+    item_id: &Uuid,
+) -> Option<&'a mut LightSource> {
+    player
+        .character
+        .inventory
+        .find_item_mut(item_id)
+        .and_then(|character_item| character_item.item.light_source.as_mut())
+}
+
+fn packed(character_item: CharacterItem) -> CharacterItem {
+    CharacterItem {
         equipped_location: LocationTag::Packed,
         item: character_item.item,
         at_the_ready: false,
-    };
-    new_player.character.add_item(packed_item);
+    }
 }
 
 fn ready_npc_weapon(new_game: &mut GameState, npc_id: &Uuid, item_id: &Uuid) {
-    let position = new_game.current_room_mut().find_npc_mut(npc_id).unwrap();
+    let position = new_game.find_npc_mut(npc_id).unwrap();
     let mut character_item = position.npc.character.remove_item(item_id).unwrap();
     character_item.at_the_ready = true;
     character_item.equipped_location = LocationTag::Hand;
     position.npc.character.add_item(character_item);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::rooms::NpcPosition,
+        events::PlayerKilledNpc,
+        generators::{
+            game::game_generator, generator::Generator, non_players::NonPlayerGeneratorBuilder,
+            players::player_generator,
+        },
+        ruleset::Ruleset,
+    };
+
+    use super::{apply_events, stamp_turns, Event};
+
+    #[test]
+    fn events_are_stamped_with_the_turn_they_happened_on() {
+        let events = vec![
+            Event::PlayerGainedGold(1),
+            Event::TurnsPassed(1),
+            Event::PlayerGainedGold(2),
+            Event::TurnsPassed(1),
+            Event::PlayerGainedGold(3),
+        ];
+
+        let turns: Vec<u32> = stamp_turns(5, events)
+            .into_iter()
+            .map(|(turn, _)| turn)
+            .collect();
+
+        assert_eq!(vec![5, 6, 6, 7, 7], turns);
+    }
+
+    #[test]
+    fn killing_the_last_npc_in_a_room_clears_it() {
+        let mut state =
+            game_generator(Default::default(), Ruleset::default(), Vec::new()).generate();
+        let player = player_generator(None, None, None).generate();
+        let first = NonPlayerGeneratorBuilder::new().build().generate();
+        let second = NonPlayerGeneratorBuilder::new().build().generate();
+        state.current_room_mut().npc_positions = [&first, &second]
+            .into_iter()
+            .map(|npc| NpcPosition {
+                npc: npc.clone(),
+                position_descriptor: None,
+            })
+            .collect();
+        let kill = |npc_id| {
+            vec![Event::PlayerKilledNpc(PlayerKilledNpc {
+                npc_id,
+                killer_id: player.id,
+            })]
+        };
+
+        let (state, player) = apply_events(&kill(first.id), &state, &player);
+        assert_eq!(0, state.player_stats(&player.id).rooms_cleared);
+
+        let (state, player) = apply_events(&kill(second.id), &state, &player);
+        assert_eq!(1, state.player_stats(&player.id).rooms_cleared);
+    }
+}
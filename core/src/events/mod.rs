@@ -1,28 +1,52 @@
+mod companion_hit;
+mod companion_killed;
+mod companion_received_item;
 mod dead_npc_beaten;
 mod event;
+mod faction_reputation_changed;
 mod fixture_has_hidden_compartment_discovered;
 mod fixture_hidden_compartment_opened;
 mod fixture_opened;
 mod fixture_viewed;
 mod ghost_escapes_to_the_void;
+mod item_identified;
+mod item_taken_from_companion;
 mod item_taken_from_fixture;
 mod item_taken_from_npc;
+mod npc_blinded;
+mod npc_blindness_dissipated;
+mod npc_blindness_duration_changed;
+mod npc_bribed;
 mod npc_damaged_by_poison;
+mod npc_disposition_changed;
 mod npc_health_discovered;
+mod npc_hit_npc;
 mod npc_item_destroyed;
+mod npc_killed_npc;
 mod npc_missed;
+mod npc_missed_npc;
+mod npc_moved;
+mod npc_movement_heard;
 mod npc_packed_discovered;
 mod npc_poison_effect_dissipated;
 mod npc_poison_effect_duration_changed;
 mod npc_poison_level_changed;
 mod npc_poisoned;
+mod npc_recruited;
+mod npc_regrouped;
+mod npc_spoke;
 mod npc_viewed;
 mod npc_weapon_readied;
+mod player_blocked_attack;
 mod player_gains_retribution_aura;
 mod player_gains_shield_aura;
 mod player_healed;
 mod player_hit;
 mod player_hit_npc;
+mod player_item_burn_time_changed;
+mod player_item_extinguished;
+mod player_item_fizzled;
+mod player_item_lit;
 mod player_item_moved;
 mod player_item_removed;
 mod player_item_used;
@@ -33,30 +57,49 @@ mod player_poisoned;
 mod player_spell_forgotten;
 mod player_spell_learned;
 mod player_spell_used;
+mod player_victorious;
+mod quest_completed;
+mod quest_progressed;
 mod room_exited;
 mod room_first_seen;
 mod room_generated;
+mod room_repopulated;
 
 pub use {
-    dead_npc_beaten::DeadNpcBeaten, event::apply_events, event::Event,
+    companion_hit::CompanionHit, companion_killed::CompanionKilled,
+    companion_received_item::CompanionReceivedItem, dead_npc_beaten::DeadNpcBeaten,
+    event::apply_events, event::stamp_turns, event::Event,
+    faction_reputation_changed::FactionReputationChanged,
     fixture_has_hidden_compartment_discovered::FixtureHasHiddenCompartmentDiscovered,
     fixture_hidden_compartment_opened::FixtureHiddenCompartmentOpened,
     fixture_opened::FixtureOpened, fixture_viewed::FixtureViewed,
-    ghost_escapes_to_the_void::GhostEscapesToTheVoid,
+    ghost_escapes_to_the_void::GhostEscapesToTheVoid, item_identified::ItemIdentified,
+    item_taken_from_companion::ItemTakenFromCompanion,
     item_taken_from_fixture::ItemTakenFromFixture, item_taken_from_npc::ItemTakenFromNpc,
-    npc_damaged_by_poison::NpcDamagedByPoison, npc_health_discovered::NpcHealthDiscovered,
-    npc_item_destroyed::NpcItemDestroyed, npc_missed::NpcMissed,
+    npc_blinded::NpcBlinded, npc_blindness_dissipated::NpcBlindnessDissipated,
+    npc_blindness_duration_changed::NpcBlindnessDurationChanged, npc_bribed::NpcBribed,
+    npc_damaged_by_poison::NpcDamagedByPoison, npc_disposition_changed::NpcDispositionChanged,
+    npc_health_discovered::NpcHealthDiscovered, npc_hit_npc::NpcHitNpc,
+    npc_item_destroyed::NpcItemDestroyed, npc_killed_npc::NpcKilledNpc, npc_missed::NpcMissed,
+    npc_missed_npc::NpcMissedNpc, npc_moved::NpcMoved, npc_movement_heard::NpcMovementHeard,
     npc_packed_discovered::NpcPackedDiscovered,
     npc_poison_effect_dissipated::NpcPoisonEffectDissipated,
     npc_poison_effect_duration_changed::NpcPoisonEffectDurationChanged,
     npc_poison_level_changed::NpcPoisonLevelChanged, npc_poisoned::NpcPoisoned,
+    npc_recruited::NpcRecruited, npc_regrouped::NpcRegrouped, npc_spoke::NpcSpoke,
     npc_viewed::NpcViewed, npc_weapon_readied::NpcWeaponReadied,
+    player_blocked_attack::PlayerBlockedAttack,
     player_gains_retribution_aura::PlayerGainsRetributionAura,
     player_gains_shield_aura::PlayerGainsShieldAura, player_healed::PlayerHealed,
-    player_hit::PlayerHit, player_hit_npc::PlayerHitNpc, player_item_moved::PlayerItemMoved,
+    player_hit::PlayerHit, player_hit_npc::PlayerHitNpc,
+    player_item_burn_time_changed::PlayerItemBurnTimeChanged,
+    player_item_extinguished::PlayerItemExtinguished, player_item_fizzled::PlayerItemFizzled,
+    player_item_lit::PlayerItemLit, player_item_moved::PlayerItemMoved,
     player_item_removed::PlayerItemRemoved, player_item_used::PlayerItemUsed,
     player_killed::PlayerKilled, player_killed_npc::PlayerKilledNpc, player_missed::PlayerMissed,
     player_poisoned::PlayerPoisoned, player_spell_forgotten::PlayerSpellForgotten,
     player_spell_learned::PlayerSpellLearned, player_spell_used::PlayerSpellUsed,
-    room_exited::RoomExited, room_first_seen::RoomFirstSeen, room_generated::RoomGenerated,
+    player_victorious::PlayerVictorious, quest_completed::QuestCompleted,
+    quest_progressed::QuestProgressed, room_exited::RoomExited, room_first_seen::RoomFirstSeen,
+    room_generated::RoomGenerated, room_repopulated::RoomRepopulated,
 };
@@ -0,0 +1,18 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::components::DialogueTopic;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct NpcSpoke {
+    pub npc_id: Uuid,
+    pub topic: DialogueTopic,
+    pub line: String,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub exit_id: Option<Uuid>,
+}
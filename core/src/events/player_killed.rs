@@ -4,9 +4,12 @@ use bevy_ecs::prelude::Component;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::components::games::CauseOfDeath;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
 pub struct PlayerKilled {
     pub killer_id: Option<Uuid>,
+    pub cause_of_death: CauseOfDeath,
 }
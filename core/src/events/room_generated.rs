@@ -12,4 +12,10 @@ use crate::components::rooms::Room;
 pub struct RoomGenerated {
     pub room: Room,
     pub entrance_id: Uuid,
+    /// Set when the room is a template that was forced at this depth.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub forced_template_depth: Option<u32>,
+    /// Set when a boss was spawned in the room, to the milestone it was spawned for.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub boss_milestone: Option<u32>,
 }
@@ -1,19 +1,24 @@
 use crate::{
     actions::{
-        Action, AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, ExitRoom, InspectFixture, InspectNpc,
-        LookAtFixture, LookAtNpc, LootFixture, LootNpc, MovePlayerItem, OpenFixture,
-        OpenFixtureHiddenCompartment, UseItemOnPlayer,
+        Action, AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, ExitRoom, ExtinguishItem,
+        GiveItemToCompanion, InspectFixture, InspectItem, InspectNpc, LightItem, LookAtFixture,
+        LookAtNpc, LootFixture, LootNpc, MovePlayerItem, OpenFixture, OpenFixtureHiddenCompartment,
+        ParleyApproach, ParleyWithNpc, RecruitNpc, TakeItemFromCompanion, ThrowItemAtNpc,
+        TravelToRoom, UseItemOnPlayer,
     },
     components::{
         games::GameState,
         items::{
             ConsumableEffectName, {packed_tags_for_item_type, ready_tag_for_item_type},
         },
-        PlayerCharacter,
+        rooms::RoomType,
+        NonPlayer, PlayerCharacter,
     },
+    content::ContentRegistry,
     errors::Error,
     events::Event,
     handlers::{handle_action, HandledAction},
+    ruleset::Ruleset,
 };
 
 pub struct Game {
@@ -22,35 +27,60 @@ pub struct Game {
 }
 
 impl Game {
-    pub fn handle_action(&mut self, action: &Action) -> Result<Vec<Event>, Error> {
+    pub fn handle_action(
+        &mut self,
+        action: &Action,
+        ruleset: &Ruleset,
+        content: &ContentRegistry,
+    ) -> Result<Vec<Event>, Error> {
         let HandledAction {
             events,
             new_state,
             new_player,
-        } = handle_action(action, &self.state, &self.player)?;
+        } = handle_action(action, &self.state, &self.player, ruleset, content)?;
         self.state = new_state;
         self.player = new_player;
 
         Ok(events)
     }
 
+    /// Only prisoners and tavern regulars who aren't out for blood will consider joining.
+    fn can_try_recruiting(&self, npc: &NonPlayer) -> bool {
+        let room = self.state.current_room();
+        !self.state.party_is_full()
+            && !npc.disposition.is_hostile()
+            && !npc.is_boss()
+            && (npc.is_prisoner || room.room_type == RoomType::TavernHall)
+    }
+
     pub fn current_actions(&self) -> Vec<Action> {
+        if self.state.is_completed() {
+            return Vec::new();
+        }
+
+        // Nothing in the room can be reached in the dark, and in dim light nothing that would
+        // give away more than its shape.
+        let can_see = self.state.can_see_current_room(&self.player);
+        let sees_clearly = self.state.sees_current_room_clearly(&self.player);
+
         let fixture_actions = self
             .state
             .current_room()
             .fixture_positions
             .iter()
+            .filter(|_| can_see)
             .flat_map(|fixture_position| Some(&fixture_position.fixture))
             .flat_map(|fixture| {
-                let mut actions = vec![
-                    Action::LookAtFixture(LookAtFixture {
+                let mut actions = Vec::new();
+                if sees_clearly {
+                    actions.push(Action::LookAtFixture(LookAtFixture {
                         fixture_id: fixture.id.to_string(),
-                    }),
-                    Action::InspectFixture(InspectFixture {
+                    }));
+                    actions.push(Action::InspectFixture(InspectFixture {
                         fixture_id: fixture.id.to_string(),
                         discover_hidden_compartment: true,
-                    }),
-                ];
+                    }));
+                }
 
                 if fixture.can_be_opened && !fixture.open {
                     actions.push(Action::OpenFixture(OpenFixture {
@@ -98,7 +128,7 @@ impl Game {
                     item_ids.push(fixture_item.item.id.to_string());
                 }
 
-                if !item_ids.is_empty() {
+                if sees_clearly && !item_ids.is_empty() {
                     actions.push(Action::LootFixture(LootFixture {
                         fixture_id: fixture.id.to_string(),
                         item_ids,
@@ -113,31 +143,64 @@ impl Game {
             .current_room()
             .npc_positions
             .iter()
+            .filter(|_| can_see)
             .map(|npc_position| &npc_position.npc)
             .flat_map(|npc| {
-                let mut actions = vec![
-                    Action::LookAtNpc(LookAtNpc {
+                let mut actions = Vec::new();
+                if sees_clearly {
+                    actions.push(Action::LookAtNpc(LookAtNpc {
                         npc_id: npc.id.to_string(),
-                    }),
-                    Action::InspectNpc(InspectNpc {
+                    }));
+                    actions.push(Action::InspectNpc(InspectNpc {
                         npc_id: npc.id.to_string(),
                         discover_health: true,
                         discover_packed_items: true,
-                    }),
-                ];
+                    }));
+                }
 
                 if !npc.character.is_dead() {
                     actions.push(Action::AttackNpc(AttackNpc {
                         npc_id: npc.id.to_string(),
                     }));
 
+                    for approach in [
+                        ParleyApproach::Talk,
+                        ParleyApproach::Bribe,
+                        ParleyApproach::Intimidate,
+                    ] {
+                        actions.push(Action::ParleyWithNpc(ParleyWithNpc {
+                            npc_id: npc.id.to_string(),
+                            approach,
+                        }));
+                    }
+
+                    if self.can_try_recruiting(npc) {
+                        actions.push(Action::RecruitNpc(RecruitNpc {
+                            npc_id: npc.id.to_string(),
+                        }));
+                    }
+
                     for learned_spell in self.player.character.spell_memory.spells.iter() {
                         actions.push(Action::CastSpellOnNpc(CastSpellOnNpc {
                             spell_id: learned_spell.id.to_string(),
                             npc_id: npc.id.to_string(),
                         }));
                     }
-                } else {
+
+                    for character_item in self
+                        .player
+                        .character
+                        .inventory
+                        .equipment
+                        .iter()
+                        .filter(|character_item| character_item.item.throwable.is_some())
+                    {
+                        actions.push(Action::ThrowItemAtNpc(ThrowItemAtNpc {
+                            item_id: character_item.item.id.to_string(),
+                            npc_id: npc.id.to_string(),
+                        }));
+                    }
+                } else if sees_clearly {
                     let item_ids = npc
                         .character
                         .inventory
@@ -161,6 +224,23 @@ impl Game {
             })
         });
 
+        // Neighbouring rooms are only an exit away, so only offer the longer journeys.
+        let travel_actions = self
+            .state
+            .rooms_seen
+            .iter()
+            .filter(|room_id| {
+                self.state
+                    .path_to_room(room_id)
+                    .map(|exit_ids| exit_ids.len() > 1)
+                    .unwrap_or(false)
+            })
+            .map(|room_id| {
+                Action::TravelToRoom(TravelToRoom {
+                    room_id: room_id.to_string(),
+                })
+            });
+
         let spell_actions = self
             .player
             .character
@@ -182,11 +262,35 @@ impl Game {
                 .flat_map(|character_item| {
                     let mut actions: Vec<Action> = Vec::new();
 
+                    if !self.state.all_knowledge_unlocked
+                        && !self
+                            .state
+                            .item_knowledge(&character_item.item.id)
+                            .reveals_all_of(&character_item.item)
+                    {
+                        actions.push(Action::InspectItem(InspectItem {
+                            item_id: character_item.item.id.to_string(),
+                        }));
+                    }
+
+                    if let Some(light_source) = &character_item.item.light_source {
+                        if light_source.is_lit {
+                            actions.push(Action::ExtinguishItem(ExtinguishItem {
+                                item_id: character_item.item.id.to_string(),
+                            }));
+                        } else if !light_source.is_burned_out() {
+                            actions.push(Action::LightItem(LightItem {
+                                item_id: character_item.item.id.to_string(),
+                            }));
+                        }
+                    }
+
                     if character_item.is_consumable() {
                         match &character_item.item.consumable {
                             Some(consumable) => match &consumable.effect.name {
                                 ConsumableEffectName::LearnSpell
-                                | ConsumableEffectName::HealingGrog => {
+                                | ConsumableEffectName::HealingGrog
+                                | ConsumableEffectName::Identify => {
                                     actions.push(Action::UseItemOnPlayer(UseItemOnPlayer {
                                         item_id: character_item.item.id.to_string(),
                                     }));
@@ -216,8 +320,40 @@ impl Game {
                     actions
                 });
 
+        let companion_actions = self.state.party.iter().flat_map(|companion| {
+            let take_actions =
+                companion
+                    .character
+                    .inventory
+                    .equipment
+                    .iter()
+                    .map(|character_item| {
+                        Action::TakeItemFromCompanion(TakeItemFromCompanion {
+                            companion_id: companion.id.to_string(),
+                            item_id: character_item.item.id.to_string(),
+                        })
+                    });
+
+            let give_actions =
+                self.player
+                    .character
+                    .inventory
+                    .equipment
+                    .iter()
+                    .map(|character_item| {
+                        Action::GiveItemToCompanion(GiveItemToCompanion {
+                            companion_id: companion.id.to_string(),
+                            item_id: character_item.item.id.to_string(),
+                        })
+                    });
+
+            take_actions.chain(give_actions).collect::<Vec<Action>>()
+        });
+
         npc_actions
+            .chain(companion_actions)
             .chain(exit_actions)
+            .chain(travel_actions)
             .chain(fixture_actions)
             .chain(spell_actions)
             .chain(item_actions)
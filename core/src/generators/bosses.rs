@@ -0,0 +1,148 @@
+use rand::Rng;
+use strum::IntoEnumIterator;
+
+use crate::{
+    components::{
+        items::{CharacterItem, ItemType, LocationTag},
+        Boss, NonPlayer, Species, Tagged,
+    },
+    ruleset::Ruleset,
+};
+
+use super::{
+    generator::Generator, items::item_generator_for_level, name::generate_name,
+    non_players::NonPlayerGeneratorBuilder, CharacterGeneratorBuilder, InventoryGeneratorBuilder,
+};
+
+/// The highest boss milestone the danger level has reached.
+pub fn boss_milestone(danger_level: u32, ruleset: &Ruleset) -> u32 {
+    danger_level - danger_level % ruleset.boss_danger_level_interval
+}
+
+/// Kills and bigger steps on harder difficulties skip levels, so a boss is due
+/// whenever a milestone has been passed since the last one was spawned.
+pub fn is_boss_due(danger_level: u32, last_boss_milestone: u32, ruleset: &Ruleset) -> bool {
+    boss_milestone(danger_level, ruleset) > last_boss_milestone
+}
+
+pub fn is_final_boss_level(danger_level: u32, ruleset: &Ruleset) -> bool {
+    danger_level >= ruleset.final_boss_danger_level
+}
+
+pub fn boss_generator(danger_level: u32, ruleset: &Ruleset) -> impl Generator<NonPlayer> {
+    BossPrototype {
+        danger_level,
+        ruleset: ruleset.clone(),
+    }
+}
+
+struct BossPrototype {
+    danger_level: u32,
+    ruleset: Ruleset,
+}
+
+impl Generator<NonPlayer> for BossPrototype {
+    fn generate(&self) -> NonPlayer {
+        let mut rng = rand::thread_rng();
+        let boss_level = self.danger_level + self.ruleset.boss_danger_level_bonus;
+
+        let species = boss_species();
+        let species = species[rng.gen_range(0..species.len())];
+
+        let inventory_generator = InventoryGeneratorBuilder::new()
+            .danger_level(boss_level)
+            .num_equipped_weapons(2..=2)
+            .num_equipped_wearables(4..=8)
+            .to_owned();
+
+        let character_gen_builder = CharacterGeneratorBuilder::new()
+            .danger_level(boss_level)
+            .inventory_generator_builder(inventory_generator)
+            .species(species)
+            .to_owned();
+
+        let name = generate_name().unwrap_or_else(|| format!("{:?}", species));
+        let mut npc = NonPlayerGeneratorBuilder::new()
+            .name(&name)
+            .danger_level(boss_level)
+            .character_gen_builder(character_gen_builder)
+            .build()
+            .generate();
+
+        let titles = titles();
+        npc.boss = Some(Boss {
+            title: titles[rng.gen_range(0..titles.len())].to_string(),
+            is_final: is_final_boss_level(self.danger_level, &self.ruleset),
+        });
+
+        let loot_types: Vec<ItemType> = ItemType::iter()
+            .filter(|item_type| item_type.tags().iter().any(|tag| tag.is_weapon()))
+            .collect();
+        let loot_type = loot_types[rng.gen_range(0..loot_types.len())];
+        let mut unique_loot = item_generator_for_level(
+            &loot_type,
+            false,
+            self.danger_level + self.ruleset.unique_loot_level_bonus,
+        )
+        .generate();
+        unique_loot.name = Some(format!("{}'s {:?}", name, loot_type));
+        npc.character.add_item(CharacterItem {
+            item: unique_loot,
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        });
+
+        npc
+    }
+}
+
+fn boss_species() -> Vec<Species> {
+    vec![
+        Species::Bugbear,
+        Species::Dragonkin,
+        Species::Hobgoblin,
+        Species::Ogre,
+        Species::Orc,
+        Species::Rockoblin,
+    ]
+}
+
+fn titles() -> Vec<&'static str> {
+    vec![
+        "the Butcher",
+        "the Cruel",
+        "the Defiler",
+        "the Unbroken",
+        "Warlord of the Deep",
+        "Keeper of the Dark",
+        "the Bloodied",
+        "the Tyrant",
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{components::games::Difficulty, ruleset::Ruleset};
+
+    use super::{boss_milestone, is_boss_due, is_final_boss_level};
+
+    #[test]
+    fn hardcore_steps_still_reach_every_boss() {
+        let ruleset = Ruleset::default();
+        let step = Difficulty::Hardcore.danger_level_increase();
+        let mut last_boss_milestone = 0;
+        let mut bosses = Vec::new();
+
+        let mut danger_level = 1;
+        while danger_level <= 60 {
+            if is_boss_due(danger_level, last_boss_milestone, &ruleset) {
+                last_boss_milestone = boss_milestone(danger_level, &ruleset);
+                bosses.push(danger_level);
+            }
+            danger_level += step;
+        }
+
+        assert_eq!(vec![11, 21, 31, 41, 51], bosses);
+        assert!(is_final_boss_level(*bosses.last().unwrap(), &ruleset));
+    }
+}
@@ -108,6 +108,7 @@ impl Generator<Character> for CharacterPrototype {
             stats,
             inventory,
             species: self.species,
+            custom_species: None,
             life_modifier: self.life_modifier,
             current_effects: Effects::default(),
             spell_memory: SpellMemory::default(),
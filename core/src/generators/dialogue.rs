@@ -0,0 +1,58 @@
+use rand::Rng;
+
+use crate::components::DialogueTopic;
+
+/// Pick a random line for the NPC to say about the topic.
+pub fn dialogue_line(topic: &DialogueTopic) -> String {
+    let lines = lines(topic);
+    let mut rng = rand::thread_rng();
+    let index = rng.gen_range(0..lines.len());
+    lines[index].to_string()
+}
+
+/// What an NPC has to say about the room past one of the exits.
+/// `num_enemies` is None when nobody the NPC knows has been through it.
+pub fn room_information_line(num_enemies: Option<usize>) -> String {
+    match num_enemies {
+        None => "Never been through that one. Can't say what's waiting.".to_string(),
+        Some(0) => "Through there? Quiet as a tomb, last I looked.".to_string(),
+        Some(1) => "There's one of them through there. Watch yourself.".to_string(),
+        Some(it) => format!("I counted {} of them through there. I wouldn't.", it),
+    }
+}
+
+fn lines(topic: &DialogueTopic) -> Vec<&'static str> {
+    match *topic {
+        DialogueTopic::BribeAccepted => vec![
+            "Well now, that changes things. We're friends, you and I.",
+            "Gold speaks louder than you do. Go on then.",
+            "I didn't see anything. Didn't see you either.",
+        ],
+        DialogueTopic::Greeting => vec![
+            "Ah, a face that isn't trying to eat me. Welcome.",
+            "Keep your voice down. The walls have ears down here.",
+            "Another one come to try their luck? Sit, rest a moment.",
+        ],
+        DialogueTopic::Intimidated => vec![
+            "Alright, alright! I want no trouble with you.",
+            "Easy! I'll stay over here. Out of your way.",
+            "You can have the room. I was leaving anyway.",
+        ],
+        DialogueTopic::NotIntimidated => vec![
+            "Is that meant to frighten me? Come closer and try again.",
+            "I've seen rats bigger than you.",
+            "You'll regret that.",
+        ],
+        DialogueTopic::Refusal => vec![
+            "I don't talk to strangers. Move along.",
+            "Keep walking.",
+            "What do you want? Nothing? Good.",
+        ],
+        DialogueTopic::RoomInformation => vec!["Let me tell you what I know."],
+        DialogueTopic::Threat => vec![
+            "Talk? I'll talk with my blade.",
+            "You picked the wrong room to start a conversation.",
+            "Fresh meat, and it speaks!",
+        ],
+    }
+}
@@ -169,7 +169,7 @@ impl Generator<Fixture> for FixturePrototype {
     }
 }
 
-fn items_go_inside(fixture_type: &FixtureType) -> bool {
+pub fn items_go_inside(fixture_type: &FixtureType) -> bool {
     match *fixture_type {
         FixtureType::Barrel | FixtureType::Chest | FixtureType::Coffin | FixtureType::Crate => true,
         FixtureType::Bed
@@ -0,0 +1,356 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::RangeInclusive,
+};
+
+use rand::{seq::SliceRandom, Rng};
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        games::Difficulty,
+        rooms::{Biome, Coordinates, Exit, ExitType, Room, RoomType},
+        worlds::ExitMap,
+    },
+    content::RoomTemplate,
+    generators::{
+        bosses::{boss_milestone, is_boss_due},
+        random_biome,
+        rooms::{build_exit, forced_template_depth},
+        ExitGenerationArgs, RoomGeneratorBuilder, RoomNpcGenerationArgs,
+    },
+    ruleset::Ruleset,
+    utils::rolls::roll_percent_succeeds,
+};
+
+use super::generator::Generator;
+
+/// Exits that make sense between two rooms side by side on the same floor.
+const CONNECTING_EXIT_TYPES: [ExitType; 4] = [
+    ExitType::Door,
+    ExitType::Hallway,
+    ExitType::HoleInTheWall,
+    ExitType::DugOutTunnelEntrance,
+];
+
+/// A whole floor of connected rooms, generated up front.
+pub struct Floor {
+    pub rooms: Vec<Room>,
+    pub exit_graph: Vec<ExitMap>,
+    pub entrance_room_id: Uuid,
+    /// The room holding the staircase down, as far from the entrance as the floor allows.
+    pub stairs_room_id: Uuid,
+    /// The boss milestone the stairs room's boss was spawned for, if it has one.
+    pub last_boss_milestone: u32,
+    /// The depth each room template was forced at on this floor.
+    pub template_depths_used: HashMap<String, u32>,
+}
+
+#[derive(Default, Clone)]
+pub struct FloorGeneratorBuilder {
+    num_rooms: Option<RangeInclusive<usize>>,
+    loop_chance: Option<i32>,
+    danger_level: Option<u32>,
+    difficulty: Option<Difficulty>,
+    ruleset: Option<Ruleset>,
+    room_templates: Option<Vec<RoomTemplate>>,
+    biome: Option<Biome>,
+}
+
+impl FloorGeneratorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn num_rooms(&mut self, num_rooms: RangeInclusive<usize>) -> &mut Self {
+        self.num_rooms = Some(num_rooms);
+
+        self
+    }
+
+    /// Chance that two neighbouring rooms which aren't already joined get an exit between them.
+    pub fn loop_chance(&mut self, loop_chance: i32) -> &mut Self {
+        self.loop_chance = Some(loop_chance);
+
+        self
+    }
+
+    pub fn danger_level(&mut self, danger_level: u32) -> &mut Self {
+        self.danger_level = Some(danger_level);
+
+        self
+    }
+
+    pub fn difficulty(&mut self, difficulty: Difficulty) -> &mut Self {
+        self.difficulty = Some(difficulty);
+
+        self
+    }
+
+    pub fn ruleset(&mut self, ruleset: Ruleset) -> &mut Self {
+        self.ruleset = Some(ruleset);
+
+        self
+    }
+
+    /// Templates keep their contents, but their exits are replaced by the floor's own.
+    pub fn room_templates(&mut self, room_templates: Vec<RoomTemplate>) -> &mut Self {
+        self.room_templates = Some(room_templates);
+
+        self
+    }
+
+    /// The whole floor shares one biome, picked at random when not set.
+    pub fn biome(&mut self, biome: Biome) -> &mut Self {
+        self.biome = Some(biome);
+
+        self
+    }
+
+    pub fn build(&self) -> impl Generator<Floor> {
+        FloorPrototype {
+            num_rooms: self.num_rooms.clone().unwrap_or(8..=14),
+            loop_chance: self.loop_chance.unwrap_or(15),
+            danger_level: self.danger_level.unwrap_or(1),
+            difficulty: self.difficulty.unwrap_or_default(),
+            ruleset: self.ruleset.clone().unwrap_or_default(),
+            room_templates: self.room_templates.clone().unwrap_or_default(),
+            biome: self.biome.unwrap_or_else(random_biome),
+        }
+    }
+}
+
+struct FloorPrototype {
+    num_rooms: RangeInclusive<usize>,
+    loop_chance: i32,
+    danger_level: u32,
+    difficulty: Difficulty,
+    ruleset: Ruleset,
+    room_templates: Vec<RoomTemplate>,
+    biome: Biome,
+}
+
+impl Generator<Floor> for FloorPrototype {
+    fn generate(&self) -> Floor {
+        let mut rng = rand::thread_rng();
+        let num_rooms = rng.gen_range(self.num_rooms.clone()).max(2);
+
+        // Grow a spanning tree out from the entrance, which leaves plenty of dead ends,
+        // then join up some neighbours so the floor can loop back on itself.
+        let mut cells: Vec<Coordinates> = vec![Coordinates { x: 0, y: 0 }];
+        let mut connections: Vec<(usize, usize)> = Vec::new();
+        while cells.len() < num_rooms {
+            let from = rng.gen_range(0..cells.len());
+            let open: Vec<Coordinates> = cells[from]
+                .neighbours()
+                .into_iter()
+                .filter(|cell| !cells.contains(cell))
+                .collect();
+            if let Some(cell) = open.choose(&mut rng) {
+                cells.push(*cell);
+                connections.push((from, cells.len() - 1));
+            }
+        }
+
+        for first in 0..cells.len() {
+            for second in (first + 1)..cells.len() {
+                if cells[first].neighbours().contains(&cells[second])
+                    && !connections.contains(&(first, second))
+                    && !connections.contains(&(second, first))
+                    && roll_percent_succeeds(&mut rng, self.loop_chance)
+                {
+                    connections.push((first, second));
+                }
+            }
+        }
+
+        // The tree keeps every room reachable, so the farthest one always has a path to it.
+        let distances = distances_from_entrance(cells.len(), &connections);
+        let stairs_index = (0..cells.len())
+            .max_by_key(|index| distances[*index])
+            .unwrap_or_default();
+
+        let connection_exits: Vec<Exit> = connections
+            .iter()
+            .map(|_| {
+                let exit_type = *CONNECTING_EXIT_TYPES.choose(&mut rng).unwrap();
+                build_exit(&mut rng, Uuid::new_v4(), exit_type)
+            })
+            .collect();
+        let stairs_exit = build_exit(&mut rng, Uuid::new_v4(), ExitType::StaircaseDown);
+
+        let mut template_depths_used: HashMap<String, u32> = HashMap::new();
+        let rooms: Vec<Room> = cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let danger_level = self.danger_level
+                    + distances[index] as u32 * self.difficulty.danger_level_increase();
+                let mut builder = RoomGeneratorBuilder::new();
+                builder
+                    .danger_level(danger_level)
+                    .biome(self.biome)
+                    .difficulty(self.difficulty)
+                    .ruleset(self.ruleset.clone())
+                    .exit_generation_args(ExitGenerationArgs {
+                        num_exits: Some(0..=0),
+                        possible_exit_types: None,
+                    })
+                    .room_npc_generation_args(RoomNpcGenerationArgs {
+                        include_boss: Some(
+                            index == stairs_index && is_boss_due(danger_level, 0, &self.ruleset),
+                        ),
+                        ..Default::default()
+                    });
+                if index == 0 {
+                    builder.room_type(RoomType::EntryWay);
+                } else {
+                    builder
+                        .room_templates(self.room_templates.clone())
+                        .template_depths_used(template_depths_used.clone());
+                }
+
+                let mut room = builder.build().generate();
+                if let (Some(depth), Some(name)) = (
+                    forced_template_depth(
+                        &self.room_templates,
+                        &room,
+                        danger_level,
+                        &template_depths_used,
+                    ),
+                    &room.name,
+                ) {
+                    template_depths_used.insert(name.clone(), depth);
+                }
+                room.coordinates = Some(*cell);
+                room.exits = connections
+                    .iter()
+                    .zip(connection_exits.iter())
+                    .filter(|((first, second), _)| *first == index || *second == index)
+                    .map(|(_, exit)| exit.clone())
+                    .collect();
+                if index == stairs_index {
+                    room.exits.push(stairs_exit.clone());
+                }
+                room
+            })
+            .collect();
+
+        let last_boss_milestone = if rooms[stairs_index]
+            .npc_positions
+            .iter()
+            .any(|npc_position| npc_position.npc.is_boss())
+        {
+            boss_milestone(
+                self.danger_level
+                    + distances[stairs_index] as u32 * self.difficulty.danger_level_increase(),
+                &self.ruleset,
+            )
+        } else {
+            0
+        };
+
+        let exit_graph = connections
+            .iter()
+            .zip(connection_exits.iter())
+            .map(|((first, second), exit)| ExitMap {
+                exit_id: exit.id,
+                left_room_id: Some(rooms[*first].id),
+                right_room_id: Some(rooms[*second].id),
+            })
+            .chain(std::iter::once(ExitMap {
+                exit_id: stairs_exit.id,
+                left_room_id: Some(rooms[stairs_index].id),
+                right_room_id: None,
+            }))
+            .collect();
+
+        Floor {
+            entrance_room_id: rooms[0].id,
+            stairs_room_id: rooms[stairs_index].id,
+            last_boss_milestone,
+            template_depths_used,
+            rooms,
+            exit_graph,
+        }
+    }
+}
+
+fn distances_from_entrance(num_rooms: usize, connections: &[(usize, usize)]) -> Vec<usize> {
+    let mut distances: Vec<Option<usize>> = vec![None; num_rooms];
+    distances[0] = Some(0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let distance = distances[index].unwrap_or_default();
+        for (first, second) in connections {
+            let other = if *first == index {
+                *second
+            } else if *second == index {
+                *first
+            } else {
+                continue;
+            };
+
+            if distances[other].is_none() {
+                distances[other] = Some(distance + 1);
+                queue.push_back(other);
+            }
+        }
+    }
+
+    distances
+        .into_iter()
+        .map(Option::unwrap_or_default)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::generators::generator::Generator;
+
+    use super::FloorGeneratorBuilder;
+
+    #[test]
+    fn every_room_is_connected_and_placed() {
+        let floor = FloorGeneratorBuilder::new()
+            .num_rooms(10..=10)
+            .loop_chance(50)
+            .build()
+            .generate();
+
+        assert_eq!(10, floor.rooms.len());
+        let coordinates: HashSet<_> = floor
+            .rooms
+            .iter()
+            .filter_map(|room| room.coordinates)
+            .collect();
+        assert_eq!(10, coordinates.len());
+
+        let mut reached = vec![floor.entrance_room_id];
+        let mut index = 0;
+        while index < reached.len() {
+            let room_id = reached[index];
+            for exit_map in floor.exit_graph.iter() {
+                if exit_map.left_room_id == Some(room_id) {
+                    if let Some(other) = exit_map.right_room_id {
+                        if !reached.contains(&other) {
+                            reached.push(other);
+                        }
+                    }
+                } else if exit_map.right_room_id == Some(room_id) {
+                    if let Some(other) = exit_map.left_room_id {
+                        if !reached.contains(&other) {
+                            reached.push(other);
+                        }
+                    }
+                }
+            }
+            index += 1;
+        }
+
+        assert_eq!(10, reached.len());
+        assert!(reached.contains(&floor.stairs_room_id));
+    }
+}
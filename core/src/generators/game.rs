@@ -1,29 +1,100 @@
 use std::collections::HashMap;
 
+use rand::Rng;
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-use crate::components::{
-    games::GameState,
-    rooms::RoomType,
-    worlds::{ExitMap, World},
+use crate::{
+    components::{
+        games::{Difficulty, GameState},
+        quests::{ObjectiveType, Quest},
+        rooms::RoomType,
+        worlds::{ExitMap, World},
+    },
+    content::RoomTemplate,
+    ruleset::Ruleset,
 };
 
-use super::{generator::Generator, RoomGeneratorBuilder};
+use super::{
+    floors::FloorGeneratorBuilder, generator::Generator, quests::quest_generator, random_biome,
+    rooms::forced_template_depth, RoomGeneratorBuilder,
+};
+
+const NUM_QUESTS: usize = 2;
 
-pub fn game_generator() -> impl Generator<GameState> {
-    GamePrototype {}
+/// Starts in a single entry room, with the rest generated as the player explores.
+pub fn game_generator(
+    difficulty: Difficulty,
+    ruleset: Ruleset,
+    room_templates: Vec<RoomTemplate>,
+) -> impl Generator<GameState> {
+    GamePrototype {
+        difficulty,
+        ruleset,
+        room_templates,
+        floor_generator_builder: None,
+    }
 }
 
-struct GamePrototype {}
+/// Starts on a whole floor generated up front. Its staircase down leads on to rooms generated
+/// as the player explores, the same as `game_generator`.
+pub fn floor_game_generator(
+    difficulty: Difficulty,
+    floor_generator_builder: FloorGeneratorBuilder,
+) -> impl Generator<GameState> {
+    GamePrototype {
+        difficulty,
+        ruleset: Ruleset::default(),
+        room_templates: Vec::new(),
+        floor_generator_builder: Some(floor_generator_builder),
+    }
+}
+
+struct GamePrototype {
+    difficulty: Difficulty,
+    ruleset: Ruleset,
+    room_templates: Vec<RoomTemplate>,
+    floor_generator_builder: Option<FloorGeneratorBuilder>,
+}
+
+impl GamePrototype {
+    /// The world, its entrance, the boss milestone already spawned in it and the depths its
+    /// templates were forced at.
+    fn world(&self) -> (World, Uuid, u32, HashMap<String, u32>) {
+        if let Some(builder) = &self.floor_generator_builder {
+            let floor = builder
+                .clone()
+                .difficulty(self.difficulty)
+                .build()
+                .generate();
+            return (
+                World {
+                    rooms: floor.rooms,
+                    exit_graph: floor.exit_graph,
+                },
+                floor.entrance_room_id,
+                floor.last_boss_milestone,
+                floor.template_depths_used,
+            );
+        }
 
-impl Generator<GameState> for GamePrototype {
-    fn generate(&self) -> GameState {
         let entry = RoomGeneratorBuilder::new()
             .room_type(RoomType::EntryWay)
+            .biome(random_biome())
+            .difficulty(self.difficulty)
+            .ruleset(self.ruleset.clone())
+            .room_templates(self.room_templates.clone())
             .build()
             .generate();
+        let mut template_depths_used: HashMap<String, u32> = HashMap::new();
+        if let (Some(depth), Some(name)) = (
+            forced_template_depth(&self.room_templates, &entry, 1, &template_depths_used),
+            &entry.name,
+        ) {
+            template_depths_used.insert(name.clone(), depth);
+        }
 
-        let exit_maps: Vec<ExitMap> = entry
+        let exit_graph: Vec<ExitMap> = entry
             .exits
             .iter()
             .map(|exit| ExitMap {
@@ -32,22 +103,55 @@ impl Generator<GameState> for GamePrototype {
                 right_room_id: None,
             })
             .collect();
+        let entry_id = entry.id;
+
+        (
+            World {
+                rooms: vec![entry],
+                exit_graph,
+            },
+            entry_id,
+            0,
+            template_depths_used,
+        )
+    }
+}
+
+impl Generator<GameState> for GamePrototype {
+    fn generate(&self) -> GameState {
+        let (world, entry_id, last_boss_milestone, template_depths_used) = self.world();
+
+        let mut rng = rand::thread_rng();
+        let mut objective_types: Vec<ObjectiveType> = ObjectiveType::iter().collect();
+        let quests: Vec<Quest> = (0..NUM_QUESTS)
+            .map(|_| {
+                let index = rng.gen_range(0..objective_types.len());
+                let objective_type = objective_types.remove(index);
+                quest_generator(objective_type, 1, &self.ruleset).generate()
+            })
+            .collect();
 
         GameState {
             id: Uuid::new_v4(),
             name: None,
-            current_room_id: entry.id,
-            rooms_seen: vec![entry.id],
-            world: World {
-                rooms: vec![entry],
-                exit_graph: exit_maps,
-            },
+            current_room_id: entry_id,
+            rooms_seen: vec![entry_id],
+            world,
             all_knowledge_unlocked: false,
             player_npc_knowledge: HashMap::new(),
             player_fixture_knowledge: HashMap::new(),
+            player_item_knowledge: HashMap::new(),
             player_statistics: HashMap::new(),
             danger_level: 1,
+            last_boss_milestone,
+            template_depths_used,
             ghosts: Vec::new(),
+            quests,
+            run_summary: None,
+            reputation: HashMap::new(),
+            party: Vec::new(),
+            turn: 0,
+            difficulty: self.difficulty,
         }
     }
 }
@@ -6,13 +6,15 @@ use uuid::Uuid;
 use crate::{
     components::{
         items::{
-            CharacterItem, Consumable, HealingEffect, Item, ItemType, LocationTag, OilSplashEffect,
-            Throwable, ThrowableEffect, ThrowableEffectName,
+            AcidFlaskEffect, CaltropsEffect, CharacterItem, Consumable, FirebombEffect,
+            HealingEffect, HolyWaterEffect, Item, ItemType, LocationTag, OilSplashEffect,
+            SmokeBombEffect, Throwable, ThrowableEffect, ThrowableEffectName,
             {ConsumableEffect, ConsumableEffectName, LearnSpellEffect},
         },
         spells::SpellName,
         Inventory, Tagged, {Attack, Defense},
     },
+    ruleset::Ruleset,
     utils::rolls::roll_percent_succeeds,
 };
 
@@ -30,11 +32,10 @@ pub struct InventoryGeneratorBuilder {
     danger_level: Option<u32>,
     generate_consumable_chance: Option<i32>,
     generate_throwable_chance: Option<i32>,
+    weapon_in_hand_chance: Option<i32>,
 }
 
-const GENERATE_CONSUMABLE_CHANCE: i32 = 25;
-const GENERATE_POT_CHANCE: i32 = 20;
-const WEAPON_IN_HAND_CHANCE: i32 = 95;
+const SMOKE_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
 
 impl InventoryGeneratorBuilder {
     pub fn new() -> Self {
@@ -104,7 +105,14 @@ impl InventoryGeneratorBuilder {
         self
     }
 
+    pub fn weapon_in_hand_chance(&mut self, weapon_in_hand_chance: i32) -> &mut Self {
+        self.weapon_in_hand_chance = Some(weapon_in_hand_chance);
+
+        self
+    }
+
     pub fn build(&self) -> impl Generator<Inventory> {
+        let defaults = Ruleset::default();
         let item_types = match &self.possible_item_types {
             Some(it) => it.clone(),
             None => ItemType::iter().collect(),
@@ -127,10 +135,13 @@ impl InventoryGeneratorBuilder {
             danger_level: self.danger_level.unwrap_or(1),
             generate_consumable_chance: self
                 .generate_consumable_chance
-                .unwrap_or(GENERATE_CONSUMABLE_CHANCE),
+                .unwrap_or(defaults.generate_consumable_chance),
             generate_throwable_chance: self
                 .generate_throwable_chance
-                .unwrap_or(GENERATE_POT_CHANCE),
+                .unwrap_or(defaults.generate_pot_chance),
+            weapon_in_hand_chance: self
+                .weapon_in_hand_chance
+                .unwrap_or(defaults.weapon_in_hand_chance),
         }
     }
 }
@@ -142,6 +153,7 @@ struct InventoryPrototype {
     pub danger_level: u32,
     pub generate_consumable_chance: i32,
     pub generate_throwable_chance: i32,
+    pub weapon_in_hand_chance: i32,
 }
 
 impl InventoryPrototype {
@@ -164,16 +176,25 @@ impl InventoryPrototype {
                 break;
             }
 
-            let tag = if roll_percent_succeeds(rng, WEAPON_IN_HAND_CHANCE) {
+            let tag = if roll_percent_succeeds(rng, self.weapon_in_hand_chance) {
                 LocationTag::Hand
             } else {
                 let tag_index = rng.gen_range(0..location_tags.len());
                 location_tags.remove(tag_index)
             };
 
+            let held: Vec<ItemType> = equipped_weapons
+                .iter()
+                .filter(|weapon| weapon.at_the_ready)
+                .map(|weapon| weapon.item.item_type)
+                .collect();
+
             let possible_weapon_types: Vec<&ItemType> = weapon_types
                 .iter()
                 .filter(|item_type| item_type_is_for_tags(item_type, &tag))
+                .filter(|item_type| {
+                    !tag.eq(&LocationTag::Hand) || item_type.fits_in_hands_with(&held)
+                })
                 .cloned()
                 .collect();
 
@@ -266,8 +287,93 @@ impl InventoryPrototype {
         }
     }
 
-    fn pots(&self, rng: &mut ThreadRng) -> Vec<CharacterItem> {
-        let possible_materials = super::utils::materials::possible_materials(&ItemType::Pot);
+    fn throwable_damage(&self) -> Attack {
+        let num_rolls = if (1..=10).contains(&self.danger_level) {
+            1
+        } else if (11..=25).contains(&self.danger_level) {
+            2
+        } else if (26..=40).contains(&self.danger_level) {
+            4
+        } else if (41..=60).contains(&self.danger_level) {
+            6
+        } else {
+            8
+        };
+
+        Attack {
+            num_rolls,
+            modifier: 0,
+            effects: Vec::new(),
+        }
+    }
+
+    fn throwable_effect(&self, rng: &mut ThreadRng, name: ThrowableEffectName) -> ThrowableEffect {
+        let mut effect = ThrowableEffect {
+            name,
+            oil_splash_effect: None,
+            smoke_bomb_effect: None,
+            caltrops_effect: None,
+            holy_water_effect: None,
+            acid_flask_effect: None,
+            firebomb_effect: None,
+        };
+
+        match name {
+            ThrowableEffectName::OilSplash => {
+                effect.oil_splash_effect = Some(OilSplashEffect {
+                    covers_all_enemies: roll_percent_succeeds(rng, 90),
+                });
+            }
+            ThrowableEffectName::SmokeBomb => {
+                effect.smoke_bomb_effect = Some(SmokeBombEffect {
+                    duration: rng.gen_range(SMOKE_DURATION_RANGE),
+                });
+            }
+            ThrowableEffectName::Caltrops => {
+                effect.caltrops_effect = Some(CaltropsEffect {
+                    damage: Attack {
+                        num_rolls: 1,
+                        modifier: 0,
+                        effects: Vec::new(),
+                    },
+                });
+            }
+            ThrowableEffectName::HolyWater => {
+                effect.holy_water_effect = Some(HolyWaterEffect {
+                    damage: self.throwable_damage(),
+                    covers_all_enemies: roll_percent_succeeds(rng, 50),
+                });
+            }
+            ThrowableEffectName::AcidFlask => {
+                effect.acid_flask_effect = Some(AcidFlaskEffect {
+                    covers_all_enemies: roll_percent_succeeds(rng, 25),
+                });
+            }
+            ThrowableEffectName::Firebomb => {
+                effect.firebomb_effect = Some(FirebombEffect {
+                    damage: self.throwable_damage(),
+                    covers_all_enemies: roll_percent_succeeds(rng, 25),
+                });
+            }
+        }
+
+        effect
+    }
+
+    fn throwables(&self, rng: &mut ThreadRng) -> Vec<CharacterItem> {
+        let names: Vec<ThrowableEffectName> = ThrowableEffectName::iter().collect();
+        let name_index = rng.gen_range(0..names.len());
+        let throwable_name = names.get(name_index).cloned().unwrap();
+
+        let item_type = match throwable_name {
+            ThrowableEffectName::OilSplash
+            | ThrowableEffectName::SmokeBomb
+            | ThrowableEffectName::Firebomb => ItemType::Pot,
+            ThrowableEffectName::HolyWater | ThrowableEffectName::AcidFlask => ItemType::Flask,
+            ThrowableEffectName::Caltrops => ItemType::Caltrops,
+        };
+
+        let possible_materials = super::utils::materials::possible_materials(&item_type);
         let material = if possible_materials.is_empty() {
             None
         } else {
@@ -275,10 +381,8 @@ impl InventoryPrototype {
             possible_materials.get(material_index).cloned()
         };
 
-        let covers_all_enemies = roll_percent_succeeds(rng, 90);
-
         let possible_descriptors = super::utils::item_descriptors::possible_descriptors(
-            &ItemType::Pot,
+            &item_type,
             &material,
             self.danger_level,
         );
@@ -294,8 +398,8 @@ impl InventoryPrototype {
             item: Item {
                 id: Uuid::new_v4(),
                 name: None,
-                item_type: ItemType::Pot,
-                tags: ItemType::Pot.tags(),
+                tags: item_type.tags(),
+                item_type,
                 descriptors,
                 material,
                 attack: None,
@@ -303,11 +407,9 @@ impl InventoryPrototype {
                 consumable: None,
                 throwable: Some(Throwable {
                     uses: 1,
-                    effect: ThrowableEffect {
-                        name: ThrowableEffectName::OilSplash,
-                        oil_splash_effect: Some(OilSplashEffect { covers_all_enemies }),
-                    },
+                    effect: self.throwable_effect(rng, throwable_name),
                 }),
+                light_source: None,
             },
             equipped_location: LocationTag::Packed,
             at_the_ready: false,
@@ -460,6 +562,17 @@ impl InventoryPrototype {
         }
     }
 
+    fn identify_consumable(&self) -> Consumable {
+        Consumable {
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::Identify,
+                learn_spell_effect: None,
+                healing_effect: None,
+            },
+            uses: 1,
+        }
+    }
+
     fn consumables(&self, rng: &mut ThreadRng) -> Vec<CharacterItem> {
         let names: Vec<ConsumableEffectName> = ConsumableEffectName::iter().collect();
         let name_index = rng.gen_range(0..names.len());
@@ -468,10 +581,11 @@ impl InventoryPrototype {
         let consumable = match consumable_name {
             ConsumableEffectName::LearnSpell => self.spell_consumable(rng),
             ConsumableEffectName::HealingGrog => self.healing_grog_consumable(rng),
+            ConsumableEffectName::Identify => self.identify_consumable(),
         };
 
         let item_type = match consumable_name {
-            ConsumableEffectName::LearnSpell => ItemType::Scroll,
+            ConsumableEffectName::LearnSpell | ConsumableEffectName::Identify => ItemType::Scroll,
             ConsumableEffectName::HealingGrog => ItemType::Flask,
         };
 
@@ -508,6 +622,7 @@ impl InventoryPrototype {
                 defense: None,
                 consumable: Some(consumable),
                 throwable: None,
+                light_source: None,
             },
             equipped_location: LocationTag::Packed,
             at_the_ready: false,
@@ -528,8 +643,8 @@ impl Generator<Inventory> for InventoryPrototype {
             Vec::new()
         };
 
-        let pots = if roll_percent_succeeds(&mut rng, self.generate_throwable_chance) {
-            self.pots(&mut rng)
+        let throwables = if roll_percent_succeeds(&mut rng, self.generate_throwable_chance) {
+            self.throwables(&mut rng)
         } else {
             Vec::new()
         };
@@ -539,7 +654,7 @@ impl Generator<Inventory> for InventoryPrototype {
                 .into_iter()
                 .chain(equipped_wearables)
                 .chain(consumables)
-                .chain(pots)
+                .chain(throwables)
                 .collect(),
         }
     }
@@ -581,7 +696,10 @@ fn item_type_is_for_tags(item_type: &ItemType, tag: &LocationTag) -> bool {
         ItemType::Shackles => tag.eq(&LocationTag::Wrist) | tag.eq(&LocationTag::Ankle),
         ItemType::Trousers => tag.eq(&LocationTag::Leg),
         ItemType::Scroll => tag.eq(&LocationTag::Packed) | tag.eq(&LocationTag::Pockets),
-        ItemType::Pot => tag.eq(&LocationTag::Packed),
+        ItemType::Pot | ItemType::Caltrops => tag.eq(&LocationTag::Packed),
         ItemType::Flask => tag.eq(&LocationTag::Packed),
+        ItemType::Lantern | ItemType::Torch => {
+            tag.eq(&LocationTag::Hand) || tag.eq(&LocationTag::Hip)
+        }
     }
 }
@@ -4,7 +4,7 @@ use uuid::Uuid;
 
 use crate::components::{
     damage::AttackEffect,
-    items::{Descriptor, Item, ItemType},
+    items::{Descriptor, Item, ItemType, LightSource},
     Material, Tagged, {Attack, Defense},
 };
 
@@ -52,6 +52,10 @@ impl Generator<Item> for ItemPrototype {
             defense,
             consumable: None,
             throwable: None,
+            light_source: self.item_type.burn_time().map(|burn_time| LightSource {
+                burn_time,
+                is_lit: false,
+            }),
         }
     }
 }
@@ -126,12 +130,15 @@ impl ItemPrototype {
             | ItemType::PlateGauntlets
             | ItemType::PlateHelmet
             | ItemType::Pot
+            | ItemType::Caltrops
             | ItemType::Scroll
             | ItemType::Shirt
             | ItemType::Shackles
             | ItemType::TopHat
             | ItemType::Trousers
-            | ItemType::Vest => return None,
+            | ItemType::Vest
+            | ItemType::Lantern
+            | ItemType::Torch => return None,
         };
 
         let level = self.level();
@@ -250,10 +257,13 @@ impl ItemPrototype {
             | ItemType::Morningstar
             | ItemType::Pike
             | ItemType::Pot
+            | ItemType::Caltrops
             | ItemType::Scroll
             | ItemType::ShortSword
             | ItemType::Spear
-            | ItemType::Whip => return None,
+            | ItemType::Whip
+            | ItemType::Lantern
+            | ItemType::Torch => return None,
         };
 
         let level = self.level();
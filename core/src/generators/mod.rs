@@ -1,5 +1,8 @@
+pub mod bosses;
 pub mod characters;
+pub mod dialogue;
 pub mod fixtures;
+pub mod floors;
 pub mod game;
 pub mod generator;
 pub mod inventory;
@@ -7,6 +10,7 @@ pub mod items;
 pub mod name;
 pub mod non_players;
 pub mod players;
+pub mod quests;
 pub mod rooms;
 pub mod stats;
 mod utils;
@@ -14,5 +18,6 @@ mod utils;
 pub use characters::CharacterGeneratorBuilder;
 pub use inventory::InventoryGeneratorBuilder;
 pub use rooms::{
-    ExitGenerationArgs, RoomFixtureGenerationArgs, RoomGeneratorBuilder, RoomNpcGenerationArgs,
+    random_biome, ExitGenerationArgs, RoomFixtureGenerationArgs, RoomGeneratorBuilder,
+    RoomNpcGenerationArgs,
 };
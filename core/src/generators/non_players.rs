@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::components::NonPlayer;
+use crate::components::{Disposition, NonPlayer};
 
 use super::{generator::Generator, name::generate_name, CharacterGeneratorBuilder};
 
@@ -70,6 +70,10 @@ impl Generator<NonPlayer> for NonPlayerPrototype {
             character,
             id: Uuid::new_v4(),
             name: self.name.clone(),
+            boss: None,
+            disposition: Disposition::default(),
+            has_fled: false,
+            is_prisoner: false,
         }
     }
 }
@@ -4,7 +4,7 @@ use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 use crate::components::{
-    items::{CharacterItem, Descriptor, Item, ItemType, LocationTag},
+    items::{CharacterItem, Descriptor, Item, ItemType, LightSource, LocationTag},
     spells::{LearnedSpell, Spell, SpellMemory, SpellName},
     Character, Effects, Inventory, Material, PlayerCharacter, Size, Species, Stats, Tag,
     {Attack, Defense},
@@ -57,11 +57,12 @@ impl Generator<PlayerCharacter> for PlayerCharacterPrototype {
             character: Character {
                 stats,
                 species,
+                custom_species: None,
                 life_modifier: None,
                 inventory: Inventory {
                     equipment: starter_wearables
                         .into_iter()
-                        .chain(vec![starter_weapon])
+                        .chain(vec![starter_weapon, starter_torch()])
                         .collect(),
                 },
                 current_effects: Effects::default(),
@@ -99,6 +100,7 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        light_source: None,
     };
 
     let shirt = Item {
@@ -114,6 +116,7 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        light_source: None,
     };
 
     let boots = Item {
@@ -129,6 +132,7 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        light_source: None,
     };
 
     vec![
@@ -150,6 +154,31 @@ fn starter_wearables() -> Vec<CharacterItem> {
     ]
 }
 
+fn starter_torch() -> CharacterItem {
+    let torch = Item {
+        id: Uuid::new_v4(),
+        name: None,
+        item_type: ItemType::Torch,
+        tags: vec![Tag::Light, Tag::Wood, Tag::Equippable],
+        descriptors: Vec::new(),
+        material: Some(Material::Wooden),
+        attack: None,
+        defense: None,
+        consumable: None,
+        throwable: None,
+        light_source: ItemType::Torch.burn_time().map(|burn_time| LightSource {
+            burn_time,
+            is_lit: false,
+        }),
+    };
+
+    CharacterItem {
+        item: torch,
+        at_the_ready: false,
+        equipped_location: LocationTag::Packed,
+    }
+}
+
 fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
     let weapon_types: Vec<ItemType> = vec![
         ItemType::Dagger,
@@ -194,6 +223,7 @@ fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
         defense: None,
         consumable: None,
         throwable: None,
+        light_source: None,
     };
 
     CharacterItem {
@@ -202,3 +232,30 @@ fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
         at_the_ready: true,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use crate::{
+        components::{Size, Species},
+        generators::generator::Generator,
+    };
+
+    use super::player_generator;
+
+    #[test]
+    fn no_one_starts_over_encumbered() {
+        for species in Species::iter() {
+            for size in [Size::Tiny, Size::Small, Size::Narrow] {
+                let player = player_generator(None, Some(species), Some(size)).generate();
+                assert!(
+                    !player.character.is_over_encumbered(),
+                    "{:?} {:?} starts over-encumbered",
+                    size,
+                    species
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use rand::Rng;
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        items::ItemType,
+        quests::{Objective, ObjectiveType, Quest, QuestReward},
+        Species, Tagged,
+    },
+    ruleset::Ruleset,
+    utils::rolls::roll_percent_succeeds,
+};
+
+use super::{generator::Generator, items::item_generator_for_level};
+
+/// The only items that are worth sending someone out to retrieve.
+const RETRIEVABLE_ITEM_TYPES: [ItemType; 3] = [ItemType::Crown, ItemType::Mask, ItemType::Scroll];
+
+pub fn quest_generator(
+    objective_type: ObjectiveType,
+    danger_level: u32,
+    ruleset: &Ruleset,
+) -> impl Generator<Quest> {
+    QuestPrototype {
+        objective_type,
+        danger_level,
+        ruleset: ruleset.clone(),
+    }
+}
+
+struct QuestPrototype {
+    objective_type: ObjectiveType,
+    danger_level: u32,
+    ruleset: Ruleset,
+}
+
+impl Generator<Quest> for QuestPrototype {
+    fn generate(&self) -> Quest {
+        let mut rng = rand::thread_rng();
+
+        let (name, objective) = match self.objective_type {
+            ObjectiveType::FreePrisoner => (
+                "Free the prisoner".to_string(),
+                Objective {
+                    objective_type: self.objective_type,
+                    item_type: None,
+                    species: None,
+                    required: 1,
+                },
+            ),
+            ObjectiveType::ReachDepth => {
+                let required =
+                    self.danger_level + rng.gen_range(self.ruleset.quest_depth_range.clone());
+                (
+                    format!("Reach danger level {}", required),
+                    Objective {
+                        objective_type: self.objective_type,
+                        item_type: None,
+                        species: None,
+                        required,
+                    },
+                )
+            }
+            ObjectiveType::RetrieveItem => {
+                let index = rng.gen_range(0..RETRIEVABLE_ITEM_TYPES.len());
+                let item_type = RETRIEVABLE_ITEM_TYPES[index];
+                (
+                    format!("Retrieve a {:?}", item_type),
+                    Objective {
+                        objective_type: self.objective_type,
+                        item_type: Some(item_type),
+                        species: None,
+                        required: 1,
+                    },
+                )
+            }
+            ObjectiveType::SlaySpecies => {
+                let all: Vec<Species> = Species::iter().collect();
+                let species = all[rng.gen_range(0..all.len())];
+                let required = rng.gen_range(self.ruleset.quest_slay_count_range.clone());
+                (
+                    format!("Slay {} {:?}", required, species),
+                    Objective {
+                        objective_type: self.objective_type,
+                        item_type: None,
+                        species: Some(species),
+                        required,
+                    },
+                )
+            }
+        };
+
+        let item = if roll_percent_succeeds(&mut rng, self.ruleset.quest_reward_item_chance) {
+            let all: Vec<ItemType> = ItemType::iter()
+                .filter(|item_type| {
+                    item_type
+                        .tags()
+                        .iter()
+                        .any(|tag| tag.is_weapon() || tag.is_wearable())
+                })
+                .collect();
+            let item_type = all[rng.gen_range(0..all.len())];
+            Some(item_generator_for_level(&item_type, false, self.danger_level + 1).generate())
+        } else {
+            None
+        };
+
+        Quest {
+            id: Uuid::new_v4(),
+            name,
+            reward: QuestReward {
+                gold: self.ruleset.quest_base_gold_reward
+                    + objective.required * self.ruleset.quest_gold_per_step,
+                item,
+            },
+            objective,
+            progress: 0,
+            completed: false,
+        }
+    }
+}
@@ -1,28 +1,42 @@
+mod biomes;
 mod builder;
 mod dimensions;
 mod exits;
 mod fixtures;
 pub mod npcs;
+mod templates;
+
+pub use biomes::random_biome;
+pub(crate) use exits::build_exit;
+pub use templates::forced_template_depth;
 
 pub use builder::{
     ExitGenerationArgs, RoomFixtureGenerationArgs, RoomGeneratorBuilder, RoomNpcGenerationArgs,
 };
 
-use std::ops::RangeInclusive;
+use std::{collections::HashMap, ops::RangeInclusive};
 
 use rand::Rng;
 use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-use crate::components::{
-    fixtures::FixtureType,
-    rooms::{Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
-    Ghost, LifeModifier, Species,
+use crate::{
+    components::{
+        fixtures::FixtureType,
+        games::Difficulty,
+        rooms::{Biome, Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
+        Ghost, LifeModifier, Species,
+    },
+    content::RoomTemplate,
+    ruleset::Ruleset,
 };
 
 use self::{
-    dimensions::build_dimensions, exits::build_exits, fixtures::build_fixture_positions,
+    dimensions::build_dimensions,
+    exits::build_exits,
+    fixtures::build_fixture_positions,
     npcs::build_npc_positions,
+    templates::{build_room_from_template, choose_template},
 };
 
 use super::generator::Generator;
@@ -33,6 +47,10 @@ pub struct BuildNpcsArgs {
     pub possible_life_modifiers: Vec<LifeModifier>,
     pub allow_npcs_to_spawn_dead: bool,
     pub ghosts: Vec<Ghost>,
+    pub include_boss: bool,
+    pub include_prisoner: bool,
+    pub difficulty: Difficulty,
+    pub ruleset: Ruleset,
 }
 
 impl Default for BuildNpcsArgs {
@@ -43,6 +61,10 @@ impl Default for BuildNpcsArgs {
             possible_life_modifiers: LifeModifier::iter().collect(),
             allow_npcs_to_spawn_dead: true,
             ghosts: Vec::new(),
+            include_boss: false,
+            include_prisoner: false,
+            difficulty: Difficulty::default(),
+            ruleset: Ruleset::default(),
         }
     }
 }
@@ -81,6 +103,7 @@ struct RoomPrototype {
     pub possible_descriptors: Vec<Descriptor>,
     pub entrance_id: Option<Uuid>,
     pub danger_level: u32,
+    pub difficulty: Difficulty,
     pub possible_flavour_texts: Vec<Flavour>,
     pub include_flavour_text: bool,
     pub name: Option<String>,
@@ -88,10 +111,29 @@ struct RoomPrototype {
     pub build_exit_args: BuildExitArgs,
     pub build_npc_args: BuildNpcsArgs,
     pub build_fixtures_args: BuildFixturesArgs,
+    pub room_templates: Vec<RoomTemplate>,
+    pub template_depths_used: HashMap<String, u32>,
+    pub biome: Option<Biome>,
 }
 
 impl Generator<Room> for RoomPrototype {
     fn generate(&self) -> Room {
+        if let Some(template) = choose_template(
+            &self.room_templates,
+            self.danger_level,
+            &self.template_depths_used,
+        ) {
+            let mut room = build_room_from_template(
+                template,
+                self.entrance_id,
+                self.danger_level,
+                self.difficulty,
+                &self.build_exit_args,
+            );
+            room.biome = self.biome;
+            return room;
+        }
+
         let mut rng = rand::thread_rng();
 
         let mut descriptors: Vec<Descriptor> = Vec::new();
@@ -119,7 +161,7 @@ impl Generator<Room> for RoomPrototype {
         let (fixture_positions, used_fixtures) = build_fixture_positions(
             &self.build_fixtures_args,
             &self.room_type,
-            self.danger_level,
+            self.difficulty.loot_danger_level(self.danger_level),
         );
 
         Room {
@@ -135,8 +177,11 @@ impl Generator<Room> for RoomPrototype {
                 &self.build_npc_args,
             ),
             flavour,
+            flavour_text: None,
             exits: build_exits(self.entrance_id, &self.build_exit_args),
             loose_items: Vec::new(),
+            coordinates: None,
+            biome: self.biome,
         }
     }
 }
@@ -153,10 +198,18 @@ impl RoomType {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use uuid::Uuid;
 
     use crate::{
-        components::rooms::{Descriptor, RoomType},
+        components::{
+            fixtures::FixtureType,
+            items::ItemType,
+            rooms::{Biome, Descriptor, ExitType, RoomType},
+            Species,
+        },
+        content::{RoomTemplate, TemplateExit, TemplateFixture, TemplateNpc},
         generators::generator::Generator,
     };
 
@@ -181,4 +234,111 @@ mod tests {
             room.descriptors.first().unwrap().clone()
         );
     }
+
+    #[test]
+    fn biomes_theme_the_room() {
+        let room = RoomGeneratorBuilder::new()
+            .biome(Biome::FloodedCaverns)
+            .build()
+            .generate();
+
+        assert_eq!(Some(Biome::FloodedCaverns), room.biome);
+        assert!(Biome::FloodedCaverns.room_types().contains(&room.room_type));
+        assert!(room
+            .npc_positions
+            .iter()
+            .all(|position| Biome::FloodedCaverns
+                .species()
+                .contains(&position.npc.character.species)));
+    }
+
+    #[test]
+    fn prison_cells_hold_a_prisoner() {
+        let room = RoomGeneratorBuilder::new()
+            .room_type(RoomType::PrisonCell)
+            .build()
+            .generate();
+
+        assert!(room.npc_positions.iter().any(|npc_position| {
+            npc_position.npc.is_prisoner
+                && !npc_position.npc.character.is_dead()
+                && !npc_position.npc.disposition.is_hostile()
+        }));
+    }
+
+    #[test]
+    fn templates_are_forced_at_or_past_their_depths() {
+        let entrance_id = Uuid::new_v4();
+        let template = RoomTemplate {
+            name: "Goblin Kitchen".to_string(),
+            room_type: RoomType::TavernHall,
+            spawn_weight: 0,
+            depths: vec![5],
+            min_danger_level: None,
+            max_danger_level: None,
+            descriptors: vec![Descriptor::Freezing],
+            flavour: None,
+            flavour_text: Some("Something is burning in the stew pot.".to_string()),
+            dimensions: None,
+            fixtures: vec![TemplateFixture {
+                fixture_type: FixtureType::Barrel,
+                name: None,
+                material: None,
+                position_descriptor: None,
+                items: vec![ItemType::Dagger],
+                hidden_items: Vec::new(),
+            }],
+            npcs: vec![TemplateNpc {
+                species: Species::Goblin,
+                name: Some("Cook".to_string()),
+                life_modifier: None,
+                disposition: None,
+                position_descriptor: None,
+                items: vec![ItemType::Club],
+                packed_items: Vec::new(),
+            }],
+            exits: vec![TemplateExit {
+                exit_type: ExitType::Door,
+                name: None,
+                material: None,
+                size: None,
+                descriptors: Vec::new(),
+            }],
+        };
+
+        let room = RoomGeneratorBuilder::new()
+            .entrance_id(entrance_id)
+            .danger_level(6)
+            .room_templates(vec![template.clone()])
+            .build()
+            .generate();
+
+        let used_room = RoomGeneratorBuilder::new()
+            .danger_level(7)
+            .room_templates(vec![template])
+            .template_depths_used(HashMap::from([("Goblin Kitchen".to_string(), 5)]))
+            .build()
+            .generate();
+        assert_ne!(Some("Goblin Kitchen".to_string()), used_room.name);
+
+        assert_eq!(Some("Goblin Kitchen".to_string()), room.name);
+        assert_eq!(RoomType::TavernHall, room.room_type);
+        assert_eq!(entrance_id, room.exits.first().unwrap().id);
+        assert_eq!(
+            1,
+            room.fixture_positions.first().unwrap().fixture.items.len()
+        );
+        let npc = &room.npc_positions.first().unwrap().npc;
+        assert_eq!(Some("Cook".to_string()), npc.name);
+        assert_eq!(
+            ItemType::Club,
+            npc.character
+                .inventory
+                .equipment
+                .first()
+                .unwrap()
+                .item
+                .item_type
+        );
+    }
 }
@@ -0,0 +1,190 @@
+use rand::Rng;
+use strum::IntoEnumIterator;
+
+use crate::components::{
+    fixtures::FixtureType,
+    rooms::{Biome, Descriptor, Flavour, RoomType},
+    LifeModifier, Species,
+};
+
+pub fn random_biome() -> Biome {
+    let biomes: Vec<Biome> = Biome::iter().collect();
+    let mut rng = rand::thread_rng();
+    biomes[rng.gen_range(0..biomes.len())]
+}
+
+impl Biome {
+    pub fn room_types(&self) -> Vec<RoomType> {
+        match *self {
+            Biome::CryptComplex => vec![
+                RoomType::Cemetery,
+                RoomType::Crypt,
+                RoomType::Mausoleum,
+                RoomType::Room,
+                RoomType::TempleHall,
+            ],
+            Biome::GoblinWarren => vec![
+                RoomType::Cave,
+                RoomType::Cavern,
+                RoomType::PrisonCell,
+                RoomType::Room,
+                RoomType::TavernHall,
+            ],
+            Biome::FloodedCaverns => vec![RoomType::Cave, RoomType::Cavern],
+            Biome::VampireManor => vec![
+                RoomType::Crypt,
+                RoomType::Mausoleum,
+                RoomType::PrisonCell,
+                RoomType::Room,
+                RoomType::TavernHall,
+                RoomType::TempleHall,
+            ],
+        }
+    }
+
+    pub fn descriptors(&self) -> Vec<Descriptor> {
+        match *self {
+            Biome::CryptComplex => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Dim,
+                Descriptor::Grimy,
+            ],
+            Biome::GoblinWarren => vec![
+                Descriptor::Dim,
+                Descriptor::Grimy,
+                Descriptor::Moist,
+                Descriptor::Steamy,
+            ],
+            Biome::FloodedCaverns => vec![
+                Descriptor::Chill,
+                Descriptor::Dark,
+                Descriptor::Freezing,
+                Descriptor::Moist,
+            ],
+            Biome::VampireManor => vec![Descriptor::Chill, Descriptor::Dark, Descriptor::Dim],
+        }
+    }
+
+    pub fn flavours(&self) -> Vec<Flavour> {
+        match *self {
+            Biome::CryptComplex => vec![
+                Flavour::AStrangeBreezeBlows,
+                Flavour::IsSomethingWatchingYou,
+                Flavour::UnseenLightsFlickerWalls,
+                Flavour::YouHearScratchingAllAroundYou,
+            ],
+            Biome::GoblinWarren => vec![
+                Flavour::SmellsLikeOldGoblinSocks,
+                Flavour::SomethingSquishyAllOverFloor,
+                Flavour::YouHearScratchingAllAroundYou,
+            ],
+            Biome::FloodedCaverns => vec![
+                Flavour::AStrangeBreezeBlows,
+                Flavour::MoldMossCoversWalls,
+                Flavour::SomethingSquishyAllOverFloor,
+            ],
+            Biome::VampireManor => vec![
+                Flavour::AStrangeBreezeBlows,
+                Flavour::IsSomethingWatchingYou,
+                Flavour::UnseenLightsFlickerWalls,
+            ],
+        }
+    }
+
+    pub fn fixture_types(&self) -> Vec<FixtureType> {
+        match *self {
+            Biome::CryptComplex => vec![
+                FixtureType::Chest,
+                FixtureType::Coffin,
+                FixtureType::Pillar,
+                FixtureType::StatueTentacledMonstrosity,
+                FixtureType::StatueWarrior,
+            ],
+            Biome::GoblinWarren => vec![
+                FixtureType::Barrel,
+                FixtureType::Bucket,
+                FixtureType::Chair,
+                FixtureType::Cot,
+                FixtureType::Crate,
+                FixtureType::SleepingRoll,
+                FixtureType::Table,
+                FixtureType::WeaponRack,
+            ],
+            Biome::FloodedCaverns => vec![
+                FixtureType::Barrel,
+                FixtureType::Bucket,
+                FixtureType::Crate,
+                FixtureType::Pillar,
+                FixtureType::StatueTentacledMonstrosity,
+            ],
+            Biome::VampireManor => vec![
+                FixtureType::Bed,
+                FixtureType::Chair,
+                FixtureType::Chest,
+                FixtureType::Coffin,
+                FixtureType::Pillar,
+                FixtureType::StatueWarrior,
+                FixtureType::Table,
+            ],
+        }
+    }
+
+    pub fn species(&self) -> Vec<Species> {
+        match *self {
+            Biome::CryptComplex => vec![
+                Species::Goblin,
+                Species::Hobgoblin,
+                Species::Kobold,
+                Species::Orc,
+                Species::Phantom,
+                Species::Shadow,
+            ],
+            Biome::GoblinWarren => vec![
+                Species::Bugbear,
+                Species::Goblin,
+                Species::Hobgoblin,
+                Species::Kobold,
+                Species::Moblin,
+                Species::Rockoblin,
+            ],
+            Biome::FloodedCaverns => vec![
+                Species::Dragonkin,
+                Species::Frogkin,
+                Species::Kobold,
+                Species::Lizardkin,
+                Species::Turtlekin,
+            ],
+            Biome::VampireManor => vec![
+                Species::Dragonkin,
+                Species::Hobgoblin,
+                Species::Ogre,
+                Species::Orc,
+                Species::Phantom,
+                Species::Shadow,
+            ],
+        }
+    }
+
+    pub fn life_modifiers(&self) -> Vec<LifeModifier> {
+        match *self {
+            Biome::CryptComplex => vec![
+                LifeModifier::Ghost,
+                LifeModifier::Skeleton,
+                LifeModifier::Zombie,
+            ],
+            Biome::GoblinWarren => vec![LifeModifier::Skeleton, LifeModifier::Zombie],
+            Biome::FloodedCaverns => vec![LifeModifier::Ghost, LifeModifier::Zombie],
+            Biome::VampireManor => vec![LifeModifier::Ghost, LifeModifier::Vampire],
+        }
+    }
+
+    /// The undead are far more common in some places than others.
+    pub fn undead_chance(&self, base_chance: i32) -> i32 {
+        match *self {
+            Biome::CryptComplex | Biome::VampireManor => base_chance * 2,
+            Biome::FloodedCaverns => base_chance,
+            Biome::GoblinWarren => base_chance / 2,
+        }
+    }
+}
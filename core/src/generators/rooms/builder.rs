@@ -1,4 +1,4 @@
-use std::ops::RangeInclusive;
+use std::{collections::HashMap, ops::RangeInclusive};
 
 use rand::Rng;
 use strum::IntoEnumIterator;
@@ -7,10 +7,13 @@ use uuid::Uuid;
 use crate::{
     components::{
         fixtures::FixtureType,
-        rooms::{Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
+        games::Difficulty,
+        rooms::{Biome, Descriptor, Dimensions, ExitType, Flavour, Room, RoomType},
         Ghost, LifeModifier, Species,
     },
+    content::RoomTemplate,
     generators::generator::Generator,
+    ruleset::Ruleset,
 };
 
 use super::{BuildExitArgs, BuildFixturesArgs, BuildNpcsArgs, RoomPrototype};
@@ -28,6 +31,7 @@ pub struct RoomNpcGenerationArgs {
     pub possible_life_modifiers: Option<Vec<LifeModifier>>,
     pub allow_npcs_to_spawn_dead: Option<bool>,
     pub ghosts: Option<Vec<Ghost>>,
+    pub include_boss: Option<bool>,
 }
 
 #[derive(Default, Clone)]
@@ -43,6 +47,8 @@ pub struct RoomGeneratorBuilder {
     possible_descriptors: Option<Vec<Descriptor>>,
     entrance_id: Option<Uuid>,
     danger_level: Option<u32>,
+    difficulty: Option<Difficulty>,
+    ruleset: Option<Ruleset>,
     include_flavour_text: Option<bool>,
     possible_flavour_texts: Option<Vec<Flavour>>,
     name: Option<String>,
@@ -50,6 +56,9 @@ pub struct RoomGeneratorBuilder {
     exit_generation_args: Option<ExitGenerationArgs>,
     room_npc_generation_args: Option<RoomNpcGenerationArgs>,
     room_fixture_generation_args: Option<RoomFixtureGenerationArgs>,
+    room_templates: Option<Vec<RoomTemplate>>,
+    template_depths_used: Option<HashMap<String, u32>>,
+    biome: Option<Biome>,
 }
 
 impl RoomGeneratorBuilder {
@@ -87,6 +96,18 @@ impl RoomGeneratorBuilder {
         self
     }
 
+    pub fn difficulty(&mut self, difficulty: Difficulty) -> &mut Self {
+        self.difficulty = Some(difficulty);
+
+        self
+    }
+
+    pub fn ruleset(&mut self, ruleset: Ruleset) -> &mut Self {
+        self.ruleset = Some(ruleset);
+
+        self
+    }
+
     pub fn include_flavour_text(&mut self, include_flavour: bool) -> &mut Self {
         self.include_flavour_text = Some(include_flavour);
 
@@ -135,6 +156,30 @@ impl RoomGeneratorBuilder {
         self
     }
 
+    /// Handcrafted rooms that may be generated in place of a random one.
+    pub fn room_templates(&mut self, room_templates: Vec<RoomTemplate>) -> &mut Self {
+        self.room_templates = Some(room_templates);
+
+        self
+    }
+
+    /// The depth each template was last forced at, so it isn't forced again for the same one.
+    pub fn template_depths_used(
+        &mut self,
+        template_depths_used: HashMap<String, u32>,
+    ) -> &mut Self {
+        self.template_depths_used = Some(template_depths_used);
+
+        self
+    }
+
+    /// Themes the room after a biome. Anything set explicitly on the builder still wins.
+    pub fn biome(&mut self, biome: Biome) -> &mut Self {
+        self.biome = Some(biome);
+
+        self
+    }
+
     pub fn build(&self) -> impl Generator<Room> {
         let num_descriptors = match &self.num_descriptors {
             Some(it) => it.clone(),
@@ -144,7 +189,10 @@ impl RoomGeneratorBuilder {
         let room_type = match &self.room_type {
             Some(it) => *it,
             None => {
-                let room_types: Vec<RoomType> = RoomType::iter().collect();
+                let room_types: Vec<RoomType> = match &self.biome {
+                    Some(biome) => biome.room_types(),
+                    None => RoomType::iter().collect(),
+                };
                 let mut rng = rand::thread_rng();
                 let index = rng.gen_range(0..room_types.len());
                 *room_types.get(index).unwrap()
@@ -153,12 +201,18 @@ impl RoomGeneratorBuilder {
 
         let possible_descriptors = match &self.possible_descriptors {
             Some(it) => it.clone(),
-            None => room_type.possible_descriptors(),
+            None => match &self.biome {
+                Some(biome) => biome.descriptors(),
+                None => room_type.possible_descriptors(),
+            },
         };
 
         let possible_flavour_texts = match &self.possible_flavour_texts {
             Some(it) => it.clone(),
-            None => room_type.possible_flavours(),
+            None => match &self.biome {
+                Some(biome) => biome.flavours(),
+                None => room_type.possible_flavours(),
+            },
         };
 
         let build_exit_args = match &self.exit_generation_args {
@@ -184,6 +238,23 @@ impl RoomGeneratorBuilder {
             },
         };
 
+        let difficulty = self.difficulty.unwrap_or_default();
+        let mut ruleset = self.ruleset.clone().unwrap_or_default();
+        let default_species = match &self.biome {
+            Some(biome) => {
+                ruleset.undead_chance = biome.undead_chance(ruleset.undead_chance);
+                biome.species()
+            }
+            None => Species::iter().collect(),
+        };
+        let default_life_modifiers = match &self.biome {
+            Some(biome) => biome.life_modifiers(),
+            None => LifeModifier::iter().collect(),
+        };
+        let default_fixtures = match &self.biome {
+            Some(biome) => biome_fixtures(biome, &room_type),
+            None => possible_fixtures(&room_type),
+        };
         let build_npc_args = match &self.room_npc_generation_args {
             Some(room_npc_generation_args) => {
                 let num_groups = match &room_npc_generation_args.num_groups {
@@ -193,13 +264,13 @@ impl RoomGeneratorBuilder {
 
                 let possible_species = match &room_npc_generation_args.possible_species {
                     Some(it) => it.clone(),
-                    None => Species::iter().collect(),
+                    None => default_species,
                 };
 
                 let possible_life_modifiers =
                     match &room_npc_generation_args.possible_life_modifiers {
                         Some(it) => it.clone(),
-                        None => LifeModifier::iter().collect(),
+                        None => default_life_modifiers,
                     };
 
                 let allow_npcs_to_spawn_dead =
@@ -219,14 +290,22 @@ impl RoomGeneratorBuilder {
                     possible_life_modifiers,
                     allow_npcs_to_spawn_dead,
                     ghosts,
+                    include_boss: room_npc_generation_args.include_boss.unwrap_or(false),
+                    include_prisoner: room_type == RoomType::PrisonCell,
+                    difficulty,
+                    ruleset: ruleset.clone(),
                 }
             }
             None => BuildNpcsArgs {
                 num_groups: num_groups(&room_type),
-                possible_species: Species::iter().collect(),
-                possible_life_modifiers: LifeModifier::iter().collect(),
+                possible_species: default_species,
+                possible_life_modifiers: default_life_modifiers,
                 allow_npcs_to_spawn_dead: true,
                 ghosts: Vec::new(),
+                include_boss: false,
+                include_prisoner: room_type == RoomType::PrisonCell,
+                difficulty,
+                ruleset,
             },
         };
 
@@ -239,7 +318,7 @@ impl RoomGeneratorBuilder {
 
                 let possible_types = match &room_fixture_gen_args.possible_types {
                     Some(it) => it.to_owned(),
-                    None => possible_fixtures(&room_type),
+                    None => default_fixtures,
                 };
 
                 BuildFixturesArgs {
@@ -249,7 +328,7 @@ impl RoomGeneratorBuilder {
             }
             None => BuildFixturesArgs {
                 num_groups: num_fixture_groups(&room_type),
-                possible_types: possible_fixtures(&room_type),
+                possible_types: default_fixtures,
             },
         };
 
@@ -259,6 +338,7 @@ impl RoomGeneratorBuilder {
             possible_descriptors,
             entrance_id: self.entrance_id,
             danger_level: self.danger_level.unwrap_or(1),
+            difficulty,
             possible_flavour_texts,
             include_flavour_text: self.include_flavour_text.unwrap_or(true),
             name: self.name.clone(),
@@ -266,10 +346,29 @@ impl RoomGeneratorBuilder {
             build_exit_args,
             build_npc_args,
             build_fixtures_args,
+            room_templates: self.room_templates.clone().unwrap_or_default(),
+            template_depths_used: self.template_depths_used.clone().unwrap_or_default(),
+            biome: self.biome,
         }
     }
 }
 
+/// The biome's fixtures that also suit the room type, or all of the biome's when none do.
+fn biome_fixtures(biome: &Biome, room_type: &RoomType) -> Vec<FixtureType> {
+    let room_fixtures = possible_fixtures(room_type);
+    let fixtures: Vec<FixtureType> = biome
+        .fixture_types()
+        .into_iter()
+        .filter(|fixture_type| room_fixtures.contains(fixture_type))
+        .collect();
+
+    if fixtures.is_empty() {
+        biome.fixture_types()
+    } else {
+        fixtures
+    }
+}
+
 fn possible_fixtures(room_type: &RoomType) -> Vec<FixtureType> {
     match *room_type {
         RoomType::PrisonCell => vec![
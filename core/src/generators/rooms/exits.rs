@@ -25,22 +25,26 @@ pub fn build_exits(entrance_id: Option<Uuid>, args: &BuildExitArgs) -> Vec<Exit>
 
             let index = rng.gen_range(0..args.exit_types.len());
             let exit_type = args.exit_types.get(index).cloned().unwrap();
-            let material = material(&mut rng, &exit_type);
-            let size = size(&mut rng, &exit_type);
-            let descriptors = descriptors(&mut rng, &exit_type, &material);
-
-            Exit {
-                exit_type,
-                material,
-                size,
-                descriptors,
-                id,
-                name: None,
-            }
+            build_exit(&mut rng, id, exit_type)
         })
         .collect()
 }
 
+pub fn build_exit(rng: &mut ThreadRng, id: Uuid, exit_type: ExitType) -> Exit {
+    let material = material(rng, &exit_type);
+    let size = size(rng, &exit_type);
+    let descriptors = descriptors(rng, &exit_type, &material);
+
+    Exit {
+        exit_type,
+        material,
+        size,
+        descriptors,
+        id,
+        name: None,
+    }
+}
+
 fn material(rng: &mut ThreadRng, exit_type: &ExitType) -> Option<Material> {
     let possible_materials: Vec<Material> = match *exit_type {
         ExitType::Door | ExitType::StaircaseUp | ExitType::StaircaseDown => vec![
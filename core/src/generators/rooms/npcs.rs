@@ -4,20 +4,20 @@ use uuid::Uuid;
 use crate::{
     components::{
         fixtures::FixtureType,
-        rooms::{NpcPosition, NpcPositionDescriptor},
-        LifeModifier, NonPlayer, Species,
+        games::Difficulty,
+        rooms::{NpcPosition, NpcPositionDescriptor, Room},
+        Disposition, LifeModifier, NonPlayer, Species,
     },
     generators::{
-        generator::Generator, non_players::NonPlayerGeneratorBuilder, CharacterGeneratorBuilder,
-        InventoryGeneratorBuilder,
+        bosses::boss_generator, generator::Generator, non_players::NonPlayerGeneratorBuilder,
+        CharacterGeneratorBuilder, InventoryGeneratorBuilder,
     },
+    ruleset::Ruleset,
     utils::rolls::{roll_d100, roll_percent_succeeds},
 };
 
 use super::BuildNpcsArgs;
 
-const SPAWN_FROM_GHOST_CHANCE: i32 = 10;
-
 pub fn build_npc_positions(
     fixtures_in_room: Vec<FixtureType>,
     danger_level: u32,
@@ -25,10 +25,21 @@ pub fn build_npc_positions(
 ) -> Vec<NpcPosition> {
     let mut rng = rand::thread_rng();
 
+    let mut fixed_positions: Vec<NpcPosition> = Vec::new();
+    if args.include_boss {
+        fixed_positions.push(NpcPosition {
+            npc: boss_generator(danger_level, &args.ruleset).generate(),
+            position_descriptor: position_descriptor(&fixtures_in_room, false),
+        });
+    }
+    if args.include_prisoner {
+        fixed_positions.push(prisoner_position(danger_level, args));
+    }
+
     // Decide how many "groups" I would like in the room.
     let num_groups = rng.gen_range(args.num_groups.clone());
     if num_groups == 0 {
-        return Vec::new();
+        return fixed_positions;
     }
 
     let mut ghost_spawned = false;
@@ -38,15 +49,26 @@ pub fn build_npc_positions(
             // For each group, find a starting race.
             let starter_species = choose_species(&args.possible_species);
             // Get the group size based on the species.
-            let group_size = group_size(&starter_species);
-            let life_modifier = life_modifier(&starter_species, &args.possible_life_modifiers);
+            let group_size = args
+                .difficulty
+                .scale_group_size(group_size(&starter_species));
+            let life_modifier = life_modifier(
+                &starter_species,
+                &args.possible_life_modifiers,
+                args.ruleset.undead_chance,
+            );
+            // The whole group shares the same feelings towards the player.
+            let disposition = disposition(&starter_species, &life_modifier);
             let mut species = starter_species;
-            let mut prototype = npc_prototype(&species, life_modifier, danger_level);
+            let mut prototype = npc_prototype(&species, life_modifier, danger_level, &args.ruleset);
 
             let mut npc_positions: Vec<NpcPosition> = Vec::new();
             (0..group_size).for_each(|index| {
-                if roll_percent_succeeds(&mut rng, SPAWN_FROM_GHOST_CHANCE)
-                    && !args.ghosts.is_empty()
+                if roll_percent_succeeds(
+                    &mut rng,
+                    args.difficulty
+                        .spawn_from_ghost_chance(args.ruleset.spawn_from_ghost_chance),
+                ) && !args.ghosts.is_empty()
                     && !ghost_spawned
                 {
                     let index = rng.gen_range(0..args.ghosts.len());
@@ -56,6 +78,10 @@ pub fn build_npc_positions(
                         character: ghost.character.clone(),
                         id: Uuid::new_v4(),
                         name: ghost.name.clone(),
+                        boss: None,
+                        disposition: Disposition::Hostile,
+                        has_fled: false,
+                        is_prisoner: false,
                     };
 
                     let position_descriptor = position_descriptor(&fixtures_in_room, false);
@@ -67,10 +93,12 @@ pub fn build_npc_positions(
                     ghost_spawned = true;
                 } else {
                     if index > 0 {
-                        species = switch_species(&species);
-                        prototype = npc_prototype(&species, life_modifier, danger_level);
+                        species = switch_species(&species, args.ruleset.keep_species_chance);
+                        prototype =
+                            npc_prototype(&species, life_modifier, danger_level, &args.ruleset);
                     }
                     let mut npc = prototype.generate();
+                    npc.disposition = disposition;
 
                     let include_dead_spawn_positions = args.allow_npcs_to_spawn_dead
                         && !matches!(&npc.character.species, Species::Phantom | Species::Shadow)
@@ -92,18 +120,54 @@ pub fn build_npc_positions(
 
             npc_positions
         })
+        .chain(fixed_positions)
         .collect()
 }
 
-const KEEP_SPECIES_CHANCE: i32 = 90;
+/// Whoever is locked up in a prison cell, waiting for someone to deal with their captors.
+fn prisoner_position(danger_level: u32, args: &BuildNpcsArgs) -> NpcPosition {
+    let species = choose_species(&args.possible_species);
+    let mut npc = npc_prototype(&species, None, danger_level, &args.ruleset).generate();
+    npc.disposition = Disposition::Wary;
+    npc.is_prisoner = true;
+
+    NpcPosition {
+        npc,
+        position_descriptor: Some(NpcPositionDescriptor::InCornerStands),
+    }
+}
+
+/// A single new group to wander into a room that has been cleared out.
+pub fn repopulation_npc_positions(
+    room: &Room,
+    danger_level: u32,
+    difficulty: Difficulty,
+    ruleset: &Ruleset,
+) -> Vec<NpcPosition> {
+    let fixtures_in_room = room
+        .fixture_positions
+        .iter()
+        .map(|fixture_position| fixture_position.fixture.fixture_type)
+        .collect();
+
+    let args = BuildNpcsArgs {
+        num_groups: 1..=1,
+        allow_npcs_to_spawn_dead: false,
+        difficulty,
+        ruleset: ruleset.clone(),
+        ..Default::default()
+    };
+
+    build_npc_positions(fixtures_in_room, danger_level, &args)
+}
 
-fn switch_species(species: &Species) -> Species {
+fn switch_species(species: &Species, keep_species_chance: i32) -> Species {
     let mut rng = rand::thread_rng();
-    if roll_percent_succeeds(&mut rng, KEEP_SPECIES_CHANCE) {
+    if roll_percent_succeeds(&mut rng, keep_species_chance) {
         return *species;
     }
 
-    let choices = match *species {
+    let mut choices = match *species {
         Species::Bugbear => vec![Species::Kobold, Species::Bugbear, Species::Orc],
         Species::Goblin | Species::Hobgoblin | Species::Moblin => {
             vec![Species::Goblin, Species::Hobgoblin, Species::Moblin]
@@ -123,6 +187,9 @@ fn switch_species(species: &Species) -> Species {
         Species::Phantom | Species::Shadow => vec![Species::Phantom, Species::Shadow],
     };
 
+    // A group only ever mixes species that won't turn on each other.
+    choices.retain(|choice| !choice.faction().is_hostile_to(&species.faction()));
+
     let index = rng.gen_range(0..choices.len());
     choices.get(index).cloned().unwrap_or(*species)
 }
@@ -133,6 +200,30 @@ fn choose_species(species: &[Species]) -> Species {
     species.get(index).cloned().unwrap_or(Species::Shadow)
 }
 
+pub(super) fn disposition(species: &Species, life_modifier: &Option<LifeModifier>) -> Disposition {
+    if life_modifier.is_some() {
+        return Disposition::Hostile;
+    }
+
+    let (friendly_chance, wary_chance) = match *species {
+        Species::Frogkin | Species::Turtlekin => (30, 40),
+        Species::Goblin | Species::Kobold | Species::Lizardkin => (15, 35),
+        Species::Dragonkin | Species::Hobgoblin | Species::Moblin | Species::Rockoblin => (10, 30),
+        Species::Bugbear | Species::Ogre | Species::Orc => (5, 20),
+        Species::Phantom | Species::Shadow => (0, 0),
+    };
+
+    let mut rng = rand::thread_rng();
+    let roll = roll_d100(&mut rng, 1, 0);
+    if roll <= friendly_chance {
+        Disposition::Friendly
+    } else if roll <= friendly_chance + wary_chance {
+        Disposition::Wary
+    } else {
+        Disposition::Hostile
+    }
+}
+
 fn group_size(species: &Species) -> usize {
     let range = match *species {
         Species::Bugbear | Species::Hobgoblin => 1..=2,
@@ -239,15 +330,17 @@ fn weapon_rack_positions() -> Vec<NpcPositionDescriptor> {
     vec![NpcPositionDescriptor::IsLookingAtTheWeaponRack]
 }
 
-const UNDEAD_CHANCE: i32 = 15;
-
-fn life_modifier(species: &Species, possible_modifiers: &[LifeModifier]) -> Option<LifeModifier> {
+fn life_modifier(
+    species: &Species,
+    possible_modifiers: &[LifeModifier],
+    undead_chance: i32,
+) -> Option<LifeModifier> {
     let mut rng = rand::thread_rng();
     if matches!(species, &Species::Phantom | &Species::Shadow) {
         return None;
     }
 
-    if roll_percent_succeeds(&mut rng, UNDEAD_CHANCE) {
+    if roll_percent_succeeds(&mut rng, undead_chance) {
         let type_roll = roll_d100(&mut rng, 1, 0);
         if (0..=33).contains(&type_roll) && possible_modifiers.contains(&LifeModifier::Skeleton) {
             Some(LifeModifier::Skeleton)
@@ -269,6 +362,7 @@ fn npc_prototype(
     species: &Species,
     life_modifier: Option<LifeModifier>,
     danger_level: u32,
+    ruleset: &Ruleset,
 ) -> impl Generator<NonPlayer> {
     let num_equipped_weapons = if (1..=10).contains(&danger_level) {
         1..=1
@@ -292,6 +386,9 @@ fn npc_prototype(
         .danger_level(danger_level)
         .num_equipped_weapons(num_equipped_weapons)
         .num_equipped_wearables(num_equipped_wearables)
+        .generate_consumable_chance(ruleset.generate_consumable_chance)
+        .generate_throwable_chance(ruleset.generate_pot_chance)
+        .weapon_in_hand_chance(ruleset.weapon_in_hand_chance)
         .to_owned();
 
     let mut character_gen_builder = CharacterGeneratorBuilder::new()
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        games::Difficulty,
+        items::{ready_tag_for_item_type, CharacterItem, FixtureItem, LocationTag},
+        rooms::{Exit, FixturePosition, NpcPosition, Room},
+        Inventory,
+    },
+    content::{choose, RoomTemplate, TemplateExit, TemplateFixture, TemplateNpc},
+    generators::{
+        fixtures::{items_go_inside, FixturePrototype},
+        generator::Generator,
+        items::item_generator_for_level,
+        non_players::NonPlayerGeneratorBuilder,
+        CharacterGeneratorBuilder,
+    },
+};
+
+use super::{dimensions::build_dimensions, exits::build_exits, npcs::disposition, BuildExitArgs};
+
+/// Random rooms compete with weighted templates as if they had this spawn weight.
+const PROCEDURAL_ROOM_SPAWN_WEIGHT: u32 = 100;
+
+/// A template that is due at this danger level, or one that won the roll against generating a
+/// random room.
+pub fn choose_template<'a>(
+    templates: &'a [RoomTemplate],
+    danger_level: u32,
+    template_depths_used: &HashMap<String, u32>,
+) -> Option<&'a RoomTemplate> {
+    let forced: Vec<&RoomTemplate> = templates
+        .iter()
+        .filter(|template| {
+            template
+                .forced_depth(
+                    danger_level,
+                    template_depths_used.get(&template.name).copied(),
+                )
+                .is_some()
+        })
+        .collect();
+    if !forced.is_empty() {
+        let mut rng = rand::thread_rng();
+        return forced.get(rng.gen_range(0..forced.len())).cloned();
+    }
+
+    let candidates = templates
+        .iter()
+        .filter(|template| template.can_spawn_at(danger_level))
+        .map(|template| (template, template.spawn_weight));
+    choose(
+        &mut rand::thread_rng(),
+        candidates,
+        PROCEDURAL_ROOM_SPAWN_WEIGHT,
+    )
+}
+
+/// The depth a room was forced at, when it was built from a template that was due.
+pub fn forced_template_depth(
+    templates: &[RoomTemplate],
+    room: &Room,
+    danger_level: u32,
+    template_depths_used: &HashMap<String, u32>,
+) -> Option<u32> {
+    let name = room.name.as_ref()?;
+    templates
+        .iter()
+        .filter(|template| &template.name == name)
+        .find_map(|template| {
+            template.forced_depth(danger_level, template_depths_used.get(name).copied())
+        })
+}
+
+pub fn build_room_from_template(
+    template: &RoomTemplate,
+    entrance_id: Option<Uuid>,
+    danger_level: u32,
+    difficulty: Difficulty,
+    default_exit_args: &BuildExitArgs,
+) -> Room {
+    let loot_level = difficulty.loot_danger_level(danger_level);
+
+    let exits = if template.exits.is_empty() {
+        build_exits(entrance_id, default_exit_args)
+    } else {
+        template
+            .exits
+            .iter()
+            .enumerate()
+            .map(|(index, exit)| {
+                let id = match (index, entrance_id) {
+                    (0, Some(it)) => it,
+                    _ => Uuid::new_v4(),
+                };
+                build_exit(id, exit)
+            })
+            .collect()
+    };
+
+    Room {
+        id: Uuid::new_v4(),
+        name: Some(template.name.clone()),
+        descriptors: template.descriptors.clone(),
+        room_type: template.room_type,
+        fixture_positions: template
+            .fixtures
+            .iter()
+            .map(|fixture| build_fixture_position(fixture, loot_level))
+            .collect(),
+        dimensions: template.dimensions.clone().unwrap_or_else(build_dimensions),
+        npc_positions: template
+            .npcs
+            .iter()
+            .map(|npc| build_npc_position(npc, danger_level, loot_level))
+            .collect(),
+        flavour: template.flavour,
+        flavour_text: template.flavour_text.clone(),
+        exits,
+        loose_items: Vec::new(),
+        coordinates: None,
+        biome: None,
+    }
+}
+
+fn build_exit(id: Uuid, exit: &TemplateExit) -> Exit {
+    Exit {
+        id,
+        name: exit.name.clone(),
+        exit_type: exit.exit_type,
+        material: exit.material,
+        descriptors: exit.descriptors.clone(),
+        size: exit.size,
+    }
+}
+
+fn build_fixture_position(template: &TemplateFixture, loot_level: u32) -> FixturePosition {
+    let prototype = FixturePrototype {
+        fixture_type: template.fixture_type,
+        num_items: 0..=0,
+        num_hidden_items: 0..=0,
+        has_hidden_compartment: !template.hidden_items.is_empty(),
+        danger_level: loot_level,
+    };
+    let mut fixture = prototype.generate();
+    fixture.name = template.name.clone();
+    if template.material.is_some() {
+        fixture.material = template.material;
+    }
+
+    let is_inside = items_go_inside(&template.fixture_type);
+    let items = template.items.iter().map(|item_type| FixtureItem {
+        item: item_generator_for_level(item_type, false, loot_level).generate(),
+        is_inside,
+        is_in_hidden_compartment: false,
+    });
+    let hidden_items = template.hidden_items.iter().map(|item_type| FixtureItem {
+        item: item_generator_for_level(item_type, false, loot_level).generate(),
+        is_inside: false,
+        is_in_hidden_compartment: true,
+    });
+    fixture.items = items.chain(hidden_items).collect();
+
+    FixturePosition {
+        fixture,
+        position_descriptor: template.position_descriptor,
+    }
+}
+
+fn build_npc_position(template: &TemplateNpc, danger_level: u32, loot_level: u32) -> NpcPosition {
+    let mut character_gen_builder = CharacterGeneratorBuilder::new();
+    character_gen_builder
+        .species(template.species)
+        .danger_level(danger_level)
+        .has_inventory(false);
+    if let Some(life_modifier) = template.life_modifier {
+        character_gen_builder.life_modifier(life_modifier);
+    }
+
+    let mut npc_gen_builder = NonPlayerGeneratorBuilder::new();
+    npc_gen_builder
+        .character_gen_builder(character_gen_builder)
+        .danger_level(danger_level);
+    if let Some(name) = &template.name {
+        npc_gen_builder.name(name);
+    }
+
+    let mut npc = npc_gen_builder.build().generate();
+    npc.disposition = template
+        .disposition
+        .unwrap_or_else(|| disposition(&template.species, &template.life_modifier));
+
+    let equipped = template.items.iter().map(|item_type| CharacterItem {
+        item: item_generator_for_level(item_type, true, loot_level).generate(),
+        equipped_location: ready_tag_for_item_type(item_type),
+        at_the_ready: true,
+    });
+    let packed = template.packed_items.iter().map(|item_type| CharacterItem {
+        item: item_generator_for_level(item_type, false, loot_level).generate(),
+        equipped_location: LocationTag::Packed,
+        at_the_ready: false,
+    });
+    npc.character.inventory = Inventory {
+        equipment: equipped.chain(packed).collect(),
+    };
+
+    NpcPosition {
+        npc,
+        position_descriptor: template.position_descriptor,
+    }
+}
@@ -121,6 +121,9 @@ pub fn possible_materials(item_type: &ItemType) -> Vec<Material> {
             ]
         }
         ItemType::Pot => vec![Material::Bone, Material::Ceramic],
+        ItemType::Torch => vec![Material::Wooden],
+        ItemType::Lantern => vec![Material::Glass, Material::Iron, Material::Steel],
+        ItemType::Caltrops => vec![Material::Bone, Material::Iron, Material::Steel],
         ItemType::Flask => vec![
             Material::Ceramic,
             Material::Bone,
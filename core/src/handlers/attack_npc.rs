@@ -1,26 +1,25 @@
-use std::ops::RangeInclusive;
-
 use rand::Rng;
 
 use crate::{
     actions::AttackNpc,
-    components::{damage::AttackEffect, games::GameState, PlayerCharacter, Species},
+    components::{
+        damage::AttackEffect,
+        games::{GameState, ItemKnowledge},
+        PlayerCharacter, Species,
+    },
     errors::Error,
-    events::{DeadNpcBeaten, Event, NpcItemDestroyed, NpcMissed, NpcPoisoned},
+    events::{DeadNpcBeaten, Event, ItemIdentified, NpcItemDestroyed, NpcMissed, NpcPoisoned},
+    ruleset::Ruleset,
     utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
-use super::helpers::damage_npc;
-
-const TOXIC_RANGE: RangeInclusive<i32> = 3..=6;
-const TOXIC_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
-
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 25;
+use super::helpers::{damage_npc, misses_in_the_dark};
 
 pub fn handle(
     attack_npc: &AttackNpc,
     state: &GameState,
     player: &PlayerCharacter,
+    ruleset: &Ruleset,
 ) -> Result<Vec<Event>, Error> {
     let mut events: Vec<Event> = Vec::new();
     let mut rng = rand::thread_rng();
@@ -38,7 +37,9 @@ pub fn handle(
             attacker_id: player.id,
             npc_id,
         }));
-    } else if npc_will_dodge(&npc.character.species) {
+    } else if npc_will_dodge(&npc.character.species, ruleset)
+        || misses_in_the_dark(state, player, ruleset)
+    {
         events.push(Event::NpcMissed(NpcMissed {
             attacker_id: player.id,
             npc_id,
@@ -49,7 +50,7 @@ pub fn handle(
         let attack_damage = player_attack.attack_damage(&mut rng);
         let calculated_damage = npc_defense.calculate_damage_taken(&attack_damage);
         let damage = calculated_damage.min(npc.character.get_current_health());
-        let (mut damage_events, npc_dead) = damage_npc(player, npc, damage);
+        let (mut damage_events, npc_dead) = damage_npc(player, npc, damage, &state.difficulty);
 
         // If npc is alive, handle any attack effects on player weapons
         if !npc_dead {
@@ -58,12 +59,12 @@ pub fn handle(
                     AttackEffect::Toxic => {
                         events.push(Event::NpcPoisoned(NpcPoisoned {
                             npc_id: npc.id,
-                            damage: rng.gen_range(TOXIC_RANGE),
-                            duration: rng.gen_range(TOXIC_DURATION_RANGE),
+                            damage: rng.gen_range(ruleset.toxic_damage_range.clone()),
+                            duration: rng.gen_range(ruleset.toxic_duration_range.clone()),
                         }));
                     }
                     AttackEffect::Acidic => {
-                        if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+                        if roll_percent_succeeds(&mut rng, ruleset.npc_acid_destroys_item_chance) {
                             let equipped_items = npc.character.inventory.readied_weapons();
                             let index = rng.gen_range(0..equipped_items.len());
                             if let Some(character_item) = equipped_items.get(index) {
@@ -81,19 +82,35 @@ pub fn handle(
         }
 
         events.append(&mut damage_events);
+
+        // Landing a blow shows what the weapons that swung can do.
+        for character_item in player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .filter(|character_item| character_item.is_at_the_ready())
+            .filter(|character_item| character_item.item.attack.is_some())
+            .filter(|character_item| !state.item_knowledge(&character_item.item.id).knows_attack)
+        {
+            events.push(Event::ItemIdentified(ItemIdentified {
+                item_id: character_item.item.id,
+                knowledge: ItemKnowledge {
+                    knows_attack: true,
+                    ..ItemKnowledge::default()
+                },
+            }));
+        }
     }
 
     Ok(events)
 }
 
-const PHANTOM_DODGE_CHANCE: i32 = 15;
-const SHADOW_DODGE_CHANCE: i32 = 25;
-
-fn npc_will_dodge(species: &Species) -> bool {
+fn npc_will_dodge(species: &Species, ruleset: &Ruleset) -> bool {
     let mut rng = rand::thread_rng();
     match *species {
-        Species::Phantom => roll_percent_succeeds(&mut rng, PHANTOM_DODGE_CHANCE),
-        Species::Shadow => roll_percent_succeeds(&mut rng, SHADOW_DODGE_CHANCE),
+        Species::Phantom => roll_percent_succeeds(&mut rng, ruleset.phantom_dodge_chance),
+        Species::Shadow => roll_percent_succeeds(&mut rng, ruleset.shadow_dodge_chance),
         _ => false,
     }
 }
@@ -4,12 +4,13 @@ use rand::Rng;
 
 use crate::{
     actions::CastSpellOnNpc,
-    components::{games::GameState, spells::SpellName, PlayerCharacter},
+    components::{games::GameState, rooms::Condition, spells::SpellName, PlayerCharacter},
     errors::Error,
     events::{
         Event, NpcItemDestroyed, NpcPoisonEffectDurationChanged, NpcPoisonLevelChanged,
         NpcPoisoned, PlayerSpellForgotten, PlayerSpellUsed,
     },
+    ruleset::Ruleset,
     utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
@@ -21,12 +22,11 @@ const POISON_DART_DURATION_RANGE: RangeInclusive<i32> = 1..=4;
 const POISON_CLOUD_DAMAGE_RANGE: RangeInclusive<i32> = 1..=8;
 const POISON_CLOUD_DURATION_RANGE: RangeInclusive<i32> = 2..=5;
 
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 75;
-
 pub fn handle(
     cast_spell_on_npc: &CastSpellOnNpc,
     state: &GameState,
     player: &PlayerCharacter,
+    ruleset: &Ruleset,
 ) -> Result<Vec<Event>, Error> {
     let spell_id = parse_id(&cast_spell_on_npc.spell_id)?;
     let learned_spell = match player.character.find_spell(&spell_id) {
@@ -47,19 +47,26 @@ pub fn handle(
 
     match learned_spell.spell.name {
         SpellName::ElectricBlast => {
-            let spell_damage = learned_spell.spell.damage();
+            let spell_damage = if room.has_condition(Condition::Moist) {
+                reduce_by_percent(learned_spell.spell.damage(), ruleset.moist_electric_penalty)
+            } else {
+                learned_spell.spell.damage()
+            };
             let damage = spell_damage.min(npc.character.get_current_health());
-            let (mut damage_events, _) = damage_npc(player, npc, damage);
+            let (mut damage_events, _) = damage_npc(player, npc, damage, &state.difficulty);
             events.append(&mut damage_events);
         }
         SpellName::RagingFireball => {
-            let spell_damage = if npc.character.current_effects.covered_in_oil {
+            let mut spell_damage = if npc.character.current_effects.covered_in_oil {
                 learned_spell.spell.damage() * 2
             } else {
                 learned_spell.spell.damage()
             };
+            if room.has_condition(Condition::Steamy) {
+                spell_damage = reduce_by_percent(spell_damage, ruleset.steam_fire_resistance);
+            }
             let damage = spell_damage.min(npc.character.get_current_health());
-            let (mut damage_events, _) = damage_npc(player, npc, damage);
+            let (mut damage_events, _) = damage_npc(player, npc, damage, &state.difficulty);
             events.append(&mut damage_events);
         }
         SpellName::PoisonDart => {
@@ -119,7 +126,7 @@ pub fn handle(
         }
         SpellName::AcidSplash => {
             let mut rng = rand::thread_rng();
-            if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+            if roll_percent_succeeds(&mut rng, ruleset.spell_acid_destroys_item_chance) {
                 let equipped_items = npc.character.inventory.readied_weapons();
                 let index = rng.gen_range(0..equipped_items.len());
                 if let Some(character_item) = equipped_items.get(index) {
@@ -149,3 +156,8 @@ pub fn handle(
 
     Ok(events)
 }
+
+/// Conditions can weaken a spell, but never so much that it does nothing.
+fn reduce_by_percent(damage: i32, percent: i32) -> i32 {
+    (damage * (100 - percent) / 100).max(1)
+}
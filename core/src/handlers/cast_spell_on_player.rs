@@ -3,6 +3,7 @@ use rand::Rng;
 use crate::{
     actions::CastSpellOnPlayer,
     components::{
+        games::GameState,
         spells::SpellName,
         PlayerCharacter, {Attack, Defense},
     },
@@ -11,14 +12,15 @@ use crate::{
         Event, PlayerGainsRetributionAura, PlayerGainsShieldAura, PlayerHealed, PlayerHit,
         PlayerPoisoned, PlayerSpellForgotten, PlayerSpellUsed,
     },
+    ruleset::Ruleset,
     utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 75;
-
 pub fn handle(
     cast_spell_on_player: &CastSpellOnPlayer,
+    state: &GameState,
     player: &PlayerCharacter,
+    ruleset: &Ruleset,
 ) -> Result<Vec<Event>, Error> {
     let spell_id = parse_id(&cast_spell_on_player.spell_id)?;
     let learned_spell = match player.character.find_spell(&spell_id) {
@@ -39,7 +41,7 @@ pub fn handle(
             }));
         }
         SpellName::Heal | SpellName::QuickHeal => {
-            let healing = learned_spell.spell.damage();
+            let healing = state.difficulty.scale_healing(learned_spell.spell.damage());
             let damage_healed = healing
                 .min(player.character.stats.health.max - player.character.stats.health.current);
             events.push(Event::PlayerHealed(PlayerHealed { damage_healed }));
@@ -79,7 +81,7 @@ pub fn handle(
         }
         SpellName::AcidSplash => {
             let mut rng = rand::thread_rng();
-            if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+            if roll_percent_succeeds(&mut rng, ruleset.spell_acid_destroys_item_chance) {
                 let equipped_items = player.character.inventory.readied_weapons();
                 let index = rng.gen_range(0..equipped_items.len());
                 if let Some(character_item) = equipped_items.get(index) {
@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::{
+    components::{games::GameState, PlayerCharacter},
+    events::{apply_events, Event},
+};
+
+use super::helpers::{npc_attack_companion, npc_attack_npc};
+
+/// Every companion still standing takes a swing at the first hostile NPC in the room,
+/// and takes a hit back from it if it survives.
+/// The state and player passed in should already have the events applied.
+pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+    let mut companion_events: Vec<Event> = Vec::new();
+
+    if player.character.is_dead() {
+        return companion_events;
+    }
+
+    let companion_ids: Vec<Uuid> = state.party.iter().map(|companion| companion.id).collect();
+
+    let mut current_state = state.clone();
+    for companion_id in companion_ids {
+        let target = match current_state.current_room().first_alive_hostile_npc() {
+            Some(it) => it.clone(),
+            None => break,
+        };
+
+        let companion = match current_state.find_companion(&companion_id) {
+            Some(it) => it.clone(),
+            None => continue,
+        };
+
+        let mut attack_events = npc_attack_npc(&companion, &target);
+        (current_state, _) = apply_events(&attack_events, &current_state, player);
+        companion_events.append(&mut attack_events);
+
+        let target_dead = current_state
+            .current_room()
+            .find_npc(&target.id)
+            .map(|npc| npc.character.is_dead())
+            .unwrap_or(true);
+
+        if !target_dead {
+            let mut counter_events = npc_attack_companion(&target, &companion);
+            (current_state, _) = apply_events(&counter_events, &current_state, player);
+            companion_events.append(&mut counter_events);
+        }
+    }
+
+    companion_events
+}
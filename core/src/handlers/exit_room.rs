@@ -1,13 +1,26 @@
 use crate::{
     actions::ExitRoom,
     components::games::GameState,
+    content::ContentRegistry,
     errors::Error,
     events::{Event, RoomExited, RoomFirstSeen, RoomGenerated},
-    generators::{generator::Generator, RoomGeneratorBuilder, RoomNpcGenerationArgs},
-    utils::ids::parse_id,
+    generators::{
+        bosses::{boss_milestone, is_boss_due},
+        generator::Generator,
+        random_biome,
+        rooms::forced_template_depth,
+        RoomGeneratorBuilder, RoomNpcGenerationArgs,
+    },
+    ruleset::Ruleset,
+    utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
-pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    exit_room: &ExitRoom,
+    state: &GameState,
+    ruleset: &Ruleset,
+    content: &ContentRegistry,
+) -> Result<Vec<Event>, Error> {
     // We need to check the exit maps for one with the room_id and exit.
     // If there's another exit id then find the room with that exit id and move
     // the player to that room.
@@ -32,8 +45,21 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
     let room_id = match other_room_id {
         Some(id) => id,
         None => {
+            // Rooms keep to the biome they were found in, with the odd wander into another.
+            let mut rng = rand::thread_rng();
+            let biome = match state.current_room().biome {
+                Some(biome) if !roll_percent_succeeds(&mut rng, ruleset.biome_change_chance) => {
+                    biome
+                }
+                _ => random_biome(),
+            };
             let room_generator = RoomGeneratorBuilder::new()
+                .biome(biome)
                 .danger_level(state.danger_level)
+                .difficulty(state.difficulty)
+                .ruleset(ruleset.clone())
+                .room_templates(content.room_templates.clone())
+                .template_depths_used(state.template_depths_used.clone())
                 .entrance_id(exit_id)
                 .room_npc_generation_args(RoomNpcGenerationArgs {
                     num_groups: None,
@@ -41,13 +67,32 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
                     possible_life_modifiers: None,
                     allow_npcs_to_spawn_dead: None,
                     ghosts: Some(state.ghosts.to_vec()),
+                    include_boss: Some(is_boss_due(
+                        state.danger_level,
+                        state.last_boss_milestone,
+                        ruleset,
+                    )),
                 })
                 .build();
-            let room = room_generator.generate();
+            let mut room = room_generator.generate();
+            let forced_template_depth = forced_template_depth(
+                &content.room_templates,
+                &room,
+                state.danger_level,
+                &state.template_depths_used,
+            );
+            content.apply_to_room(&mut room);
+            let boss_milestone = room
+                .npc_positions
+                .iter()
+                .any(|npc_position| npc_position.npc.is_boss())
+                .then(|| boss_milestone(state.danger_level, ruleset));
             let room_id = room.id;
             events.push(Event::RoomGenerated(RoomGenerated {
                 room,
                 entrance_id: exit_id,
+                forced_template_depth,
+                boss_milestone,
             }));
             room_id
         }
@@ -61,7 +106,9 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
 
     if !state.rooms_seen.contains(&room_id) {
         events.push(Event::RoomFirstSeen(RoomFirstSeen { room_id }));
-        events.push(Event::GameDangerLevelIncreased(1));
+        events.push(Event::GameDangerLevelIncreased(
+            state.difficulty.danger_level_increase(),
+        ));
         events.push(Event::PlayerMaxHealthChanged(1))
     }
 
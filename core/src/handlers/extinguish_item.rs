@@ -0,0 +1,30 @@
+use crate::{
+    actions::ExtinguishItem,
+    components::PlayerCharacter,
+    errors::Error,
+    events::{Event, PlayerItemExtinguished},
+    utils::ids::parse_id,
+};
+
+pub fn handle(
+    extinguish_item: &ExtinguishItem,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let item_id = parse_id(&extinguish_item.item_id)?;
+    let character_item = match player.character.find_item(&item_id) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+    };
+
+    if character_item.item.light_source.is_none() {
+        return Err(Error::ItemNotLightSourceError(item_id.to_string()));
+    }
+
+    if !character_item.item.is_lit() {
+        return Ok(Vec::new());
+    }
+
+    Ok(vec![Event::PlayerItemExtinguished(
+        PlayerItemExtinguished { item_id },
+    )])
+}
@@ -0,0 +1,93 @@
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use crate::{
+    components::{games::GameState, Faction, PlayerCharacter},
+    errors::Error,
+    events::{apply_events, Event, FactionReputationChanged},
+    ruleset::Ruleset,
+};
+
+use super::{handle_npc_action, NpcAction};
+
+/// Shift faction reputation for any kills the player made, then let any hostile
+/// factions sharing the room have a go at each other.
+/// The state and player passed in should already have the events applied.
+pub fn handle(
+    events: &[Event],
+    state: &GameState,
+    player: &PlayerCharacter,
+    ruleset: &Ruleset,
+) -> Result<Vec<Event>, Error> {
+    let mut faction_events = reputation_events(events, state, ruleset);
+
+    if player.character.is_dead() {
+        return Ok(faction_events);
+    }
+
+    let attacker_ids: Vec<Uuid> = state
+        .current_room()
+        .npc_positions
+        .iter()
+        .filter(|npc_position| !npc_position.npc.character.is_dead())
+        .map(|npc_position| npc_position.npc.id)
+        .collect();
+
+    // Apply each attack as it happens, so nobody keeps swinging after they have been cut down.
+    let mut current_state = state.clone();
+    for attacker_id in attacker_ids {
+        let room = current_state.current_room();
+        let attacker = match room.find_npc(&attacker_id) {
+            Some(it) if !it.character.is_dead() => it,
+            _ => continue,
+        };
+
+        let target = room.npc_positions.iter().find(|npc_position| {
+            !npc_position.npc.character.is_dead()
+                && attacker
+                    .faction()
+                    .is_hostile_to(&npc_position.npc.faction())
+        });
+
+        if let Some(target) = target {
+            let mut attack_events = handle_npc_action(
+                &NpcAction::AttackNpc(attacker.id, target.npc.id),
+                &current_state,
+                player,
+                ruleset,
+            )?;
+            (current_state, _) = apply_events(&attack_events, &current_state, player);
+            faction_events.append(&mut attack_events);
+        }
+    }
+
+    Ok(faction_events)
+}
+
+/// Killing a member of a faction angers it, and pleases anyone it was at war with.
+fn reputation_events(events: &[Event], state: &GameState, ruleset: &Ruleset) -> Vec<Event> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::PlayerKilledNpc(killed) => state.find_npc(&killed.npc_id),
+            _ => None,
+        })
+        .flat_map(|npc| {
+            let faction = npc.faction();
+            let rivals = Faction::iter()
+                .filter(move |rival| rival.is_hostile_to(&faction))
+                .map(|rival| {
+                    Event::FactionReputationChanged(FactionReputationChanged {
+                        faction: rival,
+                        change: ruleset.rival_kill_reputation_gain,
+                    })
+                });
+
+            std::iter::once(Event::FactionReputationChanged(FactionReputationChanged {
+                faction,
+                change: -ruleset.kill_reputation_loss,
+            }))
+            .chain(rivals)
+        })
+        .collect()
+}
@@ -0,0 +1,35 @@
+use crate::{
+    actions::GiveItemToCompanion,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{CompanionReceivedItem, Event},
+    utils::ids::parse_id,
+};
+
+pub fn handle(
+    give_item_to_companion: &GiveItemToCompanion,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let companion_id = parse_id(&give_item_to_companion.companion_id)?;
+    let item_id = parse_id(&give_item_to_companion.item_id)?;
+
+    let companion = match state.find_companion(&companion_id) {
+        Some(it) => it,
+        None => return Err(Error::CompanionNotFoundError(companion_id.to_string())),
+    };
+
+    let character_item = match player.character.find_item(&item_id) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+    };
+
+    if !companion.character.can_carry(character_item.item.weight()) {
+        return Err(Error::CarryCapacityExceededError(item_id.to_string()));
+    }
+
+    Ok(vec![Event::CompanionReceivedItem(CompanionReceivedItem {
+        companion_id,
+        item_id,
+    })])
+}
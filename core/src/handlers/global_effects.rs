@@ -1,24 +1,34 @@
 use crate::{
-    components::{games::GameState, PlayerCharacter},
+    components::{
+        games::{CauseOfDeath, GameState},
+        items::ItemType,
+        rooms::Condition,
+        PlayerCharacter,
+    },
     events::{
-        Event, NpcDamagedByPoison, NpcPoisonEffectDissipated, NpcPoisonEffectDurationChanged,
-        PlayerKilled, PlayerKilledNpc,
+        Event, NpcBlindnessDissipated, NpcBlindnessDurationChanged, NpcDamagedByPoison,
+        NpcPoisonEffectDissipated, NpcPoisonEffectDurationChanged, PlayerItemBurnTimeChanged,
+        PlayerItemExtinguished, PlayerKilled, PlayerKilledNpc,
     },
+    ruleset::Ruleset,
 };
 
-pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+/// Effects tick down once per turn, wherever in the world the affected NPC happens to be.
+pub fn handle(state: &GameState, player: &PlayerCharacter, ruleset: &Ruleset) -> Vec<Event> {
     let mut events: Vec<Event> = Vec::new();
 
+    let mut health = player.character.get_current_health();
     if !player.character.is_dead() {
         if let Some(poison_effect) = &player.character.current_effects.poison {
-            let damage = player
-                .character
-                .get_current_health()
-                .min(poison_effect.damage);
+            let damage = health.min(poison_effect.damage);
             events.push(Event::PlayerDamagedByPoison(damage));
+            health -= damage;
 
-            if damage >= player.character.get_current_health() {
-                events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+            if health <= 0 {
+                events.push(Event::PlayerKilled(PlayerKilled {
+                    killer_id: None,
+                    cause_of_death: CauseOfDeath::Poisoned,
+                }));
             } else if poison_effect.duration - 1 <= 0 {
                 events.push(Event::PlayerPoisonDissipated);
             } else {
@@ -27,16 +37,70 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
         }
     }
 
+    if health > 0
+        && state.current_room().has_condition(Condition::Freezing)
+        && !player.character.inventory.is_wearing(ItemType::Cloak)
+    {
+        let damage = health.min(ruleset.freezing_damage);
+        if damage > 0 {
+            events.push(Event::PlayerDamagedByCold(damage));
+            if damage >= health {
+                events.push(Event::PlayerKilled(PlayerKilled {
+                    killer_id: None,
+                    cause_of_death: CauseOfDeath::Cold,
+                }));
+            }
+        }
+    }
+
+    // Lit light sources burn down whether or not they are being held up to see by.
+    for character_item in player.character.inventory.equipment.iter() {
+        if let Some(light_source) = character_item
+            .item
+            .light_source
+            .as_ref()
+            .filter(|light_source| light_source.is_lit)
+        {
+            events.push(Event::PlayerItemBurnTimeChanged(
+                PlayerItemBurnTimeChanged {
+                    item_id: character_item.item.id,
+                    burn_time: -1,
+                },
+            ));
+            if light_source.burn_time - 1 <= 0 {
+                events.push(Event::PlayerItemExtinguished(PlayerItemExtinguished {
+                    item_id: character_item.item.id,
+                }));
+            }
+        }
+    }
+
     for npc in state
-        .current_room()
-        .npc_positions
+        .world
+        .rooms
         .iter()
+        .flat_map(|room| room.npc_positions.iter())
         .map(|npc_position| &npc_position.npc)
     {
         if npc.character.is_dead() {
             continue;
         }
 
+        if let Some(blindness) = &npc.character.current_effects.blindness {
+            if blindness.duration - 1 > 0 {
+                events.push(Event::NpcBlindnessDurationChanged(
+                    NpcBlindnessDurationChanged {
+                        npc_id: npc.id,
+                        duration: -1,
+                    },
+                ));
+            } else {
+                events.push(Event::NpcBlindnessDissipated(NpcBlindnessDissipated {
+                    npc_id: npc.id,
+                }));
+            }
+        }
+
         if let Some(poison_effect) = &npc.character.current_effects.poison {
             let damage = npc.character.get_current_health().min(poison_effect.damage);
             events.push(Event::NpcDamagedByPoison(NpcDamagedByPoison {
@@ -50,7 +114,9 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
                     npc_id: npc.id,
                 }));
 
-                events.push(Event::GameDangerLevelIncreased(1));
+                events.push(Event::GameDangerLevelIncreased(
+                    state.difficulty.danger_level_increase(),
+                ));
                 events.push(Event::PlayerMaxHealthChanged(1))
             }
 
@@ -71,3 +137,56 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
 
     events
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::{
+            games::CauseOfDeath,
+            items::{CharacterItem, ItemType, LocationTag},
+            rooms::Descriptor,
+        },
+        events::Event,
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            players::player_generator,
+        },
+        ruleset::Ruleset,
+    };
+
+    #[test]
+    fn freezing_rooms_hurt_players_without_a_cloak() {
+        let mut state =
+            game_generator(Default::default(), Ruleset::default(), Vec::new()).generate();
+        state.current_room_mut().descriptors = vec![Descriptor::Freezing];
+        let mut player = player_generator(None, None, None).generate();
+        player
+            .character
+            .inventory
+            .equipment
+            .retain(|character_item| character_item.item.item_type != ItemType::Cloak);
+        let ruleset = Ruleset::default();
+
+        let events = super::handle(&state, &player, &ruleset);
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerDamagedByCold(_))));
+
+        player.character.stats.health.current = 1;
+        let events = super::handle(&state, &player, &ruleset);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerKilled(killed) if killed.cause_of_death == CauseOfDeath::Cold
+        )));
+
+        player.character.inventory.add_item(CharacterItem {
+            item: item_generator_for_level(&ItemType::Cloak, true, 1).generate(),
+            equipped_location: LocationTag::Shoulder,
+            at_the_ready: true,
+        });
+        let events = super::handle(&state, &player, &ruleset);
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerDamagedByCold(_))));
+    }
+}
@@ -1,10 +1,10 @@
-use uuid::Uuid;
-
 use crate::{
-    actions::Action,
-    components::{games::GameState, LifeModifier, PlayerCharacter},
+    actions::{Action, ParleyApproach},
+    components::{games::GameState, LifeModifier, NonPlayer, PlayerCharacter},
+    content::ContentRegistry,
     errors::Error,
-    events::{apply_events, Event, GhostEscapesToTheVoid},
+    events::{apply_events, Event, GhostEscapesToTheVoid, PlayerVictorious},
+    ruleset::Ruleset,
 };
 
 use super::NpcAction;
@@ -15,19 +15,40 @@ pub struct HandledAction {
     pub new_state: GameState,
 }
 
-fn is_being_targeted(action: &Action, npc_id: &Uuid) -> bool {
+fn is_being_targeted(action: &Action, npc: &NonPlayer) -> bool {
+    let npc_id = npc.id.to_string();
     match action {
-        Action::AttackNpc(attack_npc) => attack_npc.npc_id.eq(&npc_id.to_string()),
-        Action::CastSpellOnNpc(cast_spell) => cast_spell.npc_id.eq(&npc_id.to_string()),
-        Action::InspectNpc(inspect_npc) => inspect_npc.npc_id.eq(&npc_id.to_string()),
+        Action::AttackNpc(attack_npc) => attack_npc.npc_id.eq(&npc_id),
+        Action::CastSpellOnNpc(cast_spell) => cast_spell.npc_id.eq(&npc_id),
+        Action::InspectNpc(inspect_npc) => inspect_npc.npc_id.eq(&npc_id),
+        // Hostile NPCs don't wait around for the player to finish talking.
+        Action::ParleyWithNpc(parley) => {
+            parley.npc_id.eq(&npc_id)
+                && parley.approach == ParleyApproach::Talk
+                && npc.disposition.is_hostile()
+        }
         _ => false,
     }
 }
 
+/// Actions where an over-encumbered player is too slow or too loud to avoid being noticed.
+fn is_hindered_by_encumbrance(action: &Action) -> bool {
+    matches!(
+        action,
+        Action::ExitRoom(_)
+            | Action::LookAtFixture(_)
+            | Action::LootFixture(_)
+            | Action::LootNpc(_)
+            | Action::PickUpItem(_)
+    )
+}
+
 pub fn handle_action(
     action: &Action,
     state: &GameState,
     player: &PlayerCharacter,
+    ruleset: &Ruleset,
+    content: &ContentRegistry,
 ) -> Result<HandledAction, Error> {
     if player.character.is_dead() {
         // TODO: Later we might want more specific handling for this,
@@ -35,6 +56,14 @@ pub fn handle_action(
         return Err(Error::PlayerIsDeadError);
     }
 
+    if state.is_completed() {
+        return Err(Error::GameCompletedError);
+    }
+
+    if let Action::TravelToRoom(travel_to_room) = action {
+        return super::travel_to_room::handle(travel_to_room, state, player, ruleset, content);
+    }
+
     let mut npc_actions: Vec<NpcAction> = Vec::new();
     for npc_position in state
         .current_room()
@@ -42,16 +71,17 @@ pub fn handle_action(
         .iter()
         .filter(|npc_position| !npc_position.npc.character.is_dead())
     {
-        if is_being_targeted(action, &npc_position.npc.id) {
+        if is_being_targeted(action, &npc_position.npc) {
             npc_actions.push(NpcAction::AttackPlayer(npc_position.npc.id));
         }
     }
 
     npc_actions.append(&mut match action {
         Action::InspectFixture(_)
+        | Action::InspectItem(_)
         | Action::OpenFixture(_)
         | Action::OpenFixtureHiddenCompartment(_) => {
-            if let Some(npc) = state.current_room().first_alive_npc() {
+            if let Some(npc) = state.current_room().first_alive_hostile_npc() {
                 vec![NpcAction::AttackPlayer(npc.id)]
             } else {
                 Vec::new()
@@ -61,51 +91,78 @@ pub fn handle_action(
         | Action::CastSpellOnNpc(_)
         | Action::CastSpellOnPlayer(_)
         | Action::ExitRoom(_)
+        | Action::ExtinguishItem(_)
         | Action::InspectNpc(_)
+        | Action::LightItem(_)
         | Action::LookAtFixture(_)
         | Action::LookAtNpc(_)
         | Action::LootFixture(_)
         | Action::LootNpc(_)
         | Action::MovePlayerItem(_)
+        | Action::ParleyWithNpc(_)
         | Action::PickUpItem(_)
+        | Action::GiveItemToCompanion(_)
+        | Action::RecruitNpc(_)
         | Action::SellPlayerItem(_)
+        | Action::TakeItemFromCompanion(_)
         | Action::UseItemOnPlayer(_)
-        | Action::ThrowItemAtNpc(_) => Vec::new(),
+        | Action::ThrowItemAtNpc(_)
+        | Action::TravelToRoom(_) => Vec::new(),
     });
 
+    if npc_actions.is_empty()
+        && player.character.is_over_encumbered()
+        && is_hindered_by_encumbrance(action)
+    {
+        if let Some(npc) = state.current_room().first_alive_hostile_npc() {
+            npc_actions.push(NpcAction::AttackPlayer(npc.id));
+        }
+    }
+
     let mut events: Vec<Event> = Vec::new();
 
     // Go through all npc actions and handle those. I'll do them first.
     // This means the NPCs essentially get "first hit" on the player, which I think
     // is fine.
     for npc_action in npc_actions.iter() {
-        events.append(&mut super::handle_npc_action(npc_action, state, player)?);
+        events.append(&mut super::handle_npc_action(
+            npc_action, state, player, ruleset,
+        )?);
     }
 
     events.append(&mut match action {
-        Action::ExitRoom(exit_room) => super::exit_room::handle(exit_room, state)?,
-        Action::AttackNpc(attack_npc) => super::attack_npc::handle(attack_npc, state, player)?,
-        Action::LootNpc(loot_npc) => super::loot_npc::handle(loot_npc, state)?,
-        Action::LookAtNpc(look_at_npc) => super::view_npc::handle(look_at_npc, state)?,
+        Action::ExitRoom(exit_room) => {
+            super::exit_room::handle(exit_room, state, ruleset, content)?
+        }
+        Action::AttackNpc(attack_npc) => {
+            super::attack_npc::handle(attack_npc, state, player, ruleset)?
+        }
+        Action::LootNpc(loot_npc) => super::loot_npc::handle(loot_npc, state, player)?,
+        Action::LookAtNpc(look_at_npc) => super::view_npc::handle(look_at_npc, state, player)?,
         Action::MovePlayerItem(move_player_item) => {
             super::move_player_item::handle(move_player_item, player)?
         }
-        Action::InspectNpc(inspect_npc) => super::inspect_npc::handle(inspect_npc, state)?,
+        Action::InspectNpc(inspect_npc) => super::inspect_npc::handle(inspect_npc, state, ruleset)?,
         Action::InspectFixture(inspect_fixture) => {
-            super::inspect_fixture::handle(inspect_fixture, state)?
+            super::inspect_fixture::handle(inspect_fixture, state, ruleset)?
+        }
+        Action::InspectItem(inspect_item) => {
+            super::inspect_item::handle(inspect_item, state, player, ruleset)?
         }
         Action::LookAtFixture(look_at_fixture) => {
-            super::view_fixture::handle(look_at_fixture, state)?
+            super::view_fixture::handle(look_at_fixture, state, player)?
+        }
+        Action::LootFixture(loot_fixture) => {
+            super::loot_fixture::handle(loot_fixture, state, player)?
         }
-        Action::LootFixture(loot_fixture) => super::loot_fixture::handle(loot_fixture, state)?,
         Action::CastSpellOnNpc(cast_spell_on_npc) => {
-            super::cast_spell_on_npc::handle(cast_spell_on_npc, state, player)?
+            super::cast_spell_on_npc::handle(cast_spell_on_npc, state, player, ruleset)?
         }
         Action::CastSpellOnPlayer(cast_spell_on_player) => {
-            super::cast_spell_on_player::handle(cast_spell_on_player, player)?
+            super::cast_spell_on_player::handle(cast_spell_on_player, state, player, ruleset)?
         }
         Action::UseItemOnPlayer(use_item_on_player) => {
-            super::use_item_on_player::handle(use_item_on_player, player)?
+            super::use_item_on_player::handle(use_item_on_player, state, player, ruleset)?
         }
         Action::OpenFixture(open_fixture) => super::open_fixture::handle(open_fixture, state)?,
         Action::OpenFixtureHiddenCompartment(open_fixture_hidden_compartment) => {
@@ -114,20 +171,92 @@ pub fn handle_action(
         Action::SellPlayerItem(sell_player_item) => {
             super::sell_player_item::handle(sell_player_item, player)?
         }
+        Action::LightItem(light_item) => super::light_item::handle(light_item, player)?,
+        Action::ExtinguishItem(extinguish_item) => {
+            super::extinguish_item::handle(extinguish_item, player)?
+        }
         Action::ThrowItemAtNpc(throw_item_at_npc) => {
-            super::throw_item_at_npc::handle(throw_item_at_npc, state, player)?
+            super::throw_item_at_npc::handle(throw_item_at_npc, state, player, ruleset)?
+        }
+        Action::ParleyWithNpc(parley_with_npc) => {
+            super::parley_with_npc::handle(parley_with_npc, state, player, ruleset)?
+        }
+        Action::PickUpItem(pick_up_item) => {
+            super::pick_up_item::handle(pick_up_item, state, player)?
         }
-        Action::PickUpItem(pick_up_item) => super::pick_up_item::handle(pick_up_item, state)?,
+        Action::RecruitNpc(recruit_npc) => {
+            super::recruit_npc::handle(recruit_npc, state, player, ruleset)?
+        }
+        Action::GiveItemToCompanion(give_item_to_companion) => {
+            super::give_item_to_companion::handle(give_item_to_companion, state, player)?
+        }
+        Action::TakeItemFromCompanion(take_item_from_companion) => {
+            super::take_item_from_companion::handle(take_item_from_companion, state, player)?
+        }
+        // Already split up into one exit at a time above.
+        Action::TravelToRoom(_) => Vec::new(),
     });
 
     let (mut intermediate_state, mut intermediate_player) = apply_events(&events, state, player);
 
-    let mut global_events =
-        super::global_effects::handle(&intermediate_state, &intermediate_player);
+    // The clock moves on by however long the action took, one turn at a time. Effects tick
+    // and the rest of the world moves every turn.
+    for _ in 0..action.turn_cost() {
+        let mut turn_events = vec![Event::TurnsPassed(1)];
+        (intermediate_state, intermediate_player) =
+            apply_events(&turn_events, &intermediate_state, &intermediate_player);
+
+        let mut effect_events =
+            super::global_effects::handle(&intermediate_state, &intermediate_player, ruleset);
+        (intermediate_state, intermediate_player) =
+            apply_events(&effect_events, &intermediate_state, &intermediate_player);
+        turn_events.append(&mut effect_events);
+
+        let mut tick_events =
+            super::world_tick::handle(&intermediate_state, &intermediate_player, ruleset, content);
+        (intermediate_state, intermediate_player) =
+            apply_events(&tick_events, &intermediate_state, &intermediate_player);
+        turn_events.append(&mut tick_events);
+
+        events.append(&mut turn_events);
+    }
+
+    let mut identification_events =
+        super::identification::handle(&events, &intermediate_state, &intermediate_player);
+    (intermediate_state, intermediate_player) = apply_events(
+        &identification_events,
+        &intermediate_state,
+        &intermediate_player,
+    );
+
+    events.append(&mut identification_events);
+
+    let mut faction_events =
+        super::factions::handle(&events, &intermediate_state, &intermediate_player, ruleset)?;
+    (intermediate_state, intermediate_player) =
+        apply_events(&faction_events, &intermediate_state, &intermediate_player);
+
+    events.append(&mut faction_events);
+
+    let mut companion_events = super::companions::handle(&intermediate_state, &intermediate_player);
+    (intermediate_state, intermediate_player) =
+        apply_events(&companion_events, &intermediate_state, &intermediate_player);
+
+    events.append(&mut companion_events);
+
+    let mut quest_events =
+        super::quests::handle(&events, &intermediate_state, &intermediate_player);
+    (intermediate_state, intermediate_player) =
+        apply_events(&quest_events, &intermediate_state, &intermediate_player);
+
+    events.append(&mut quest_events);
+
+    let mut victory_events =
+        victory_events(&events, state, &intermediate_state, &intermediate_player);
     (intermediate_state, intermediate_player) =
-        apply_events(&global_events, &intermediate_state, &intermediate_player);
+        apply_events(&victory_events, &intermediate_state, &intermediate_player);
 
-    events.append(&mut global_events);
+    events.append(&mut victory_events);
 
     let mut dead_events = dead_player_events(&intermediate_player);
 
@@ -142,6 +271,34 @@ pub fn handle_action(
     })
 }
 
+/// Beating the final boss ends the run, as long as the player survived the fight.
+fn victory_events(
+    events: &[Event],
+    state: &GameState,
+    new_state: &GameState,
+    player: &PlayerCharacter,
+) -> Vec<Event> {
+    if player.character.is_dead() {
+        return Vec::new();
+    }
+
+    let final_boss = events
+        .iter()
+        .filter_map(|event| match event {
+            Event::PlayerKilledNpc(killed) => state.find_npc(&killed.npc_id),
+            _ => None,
+        })
+        .find(|npc| npc.is_final_boss());
+
+    match final_boss {
+        Some(boss) => vec![Event::PlayerVictorious(PlayerVictorious {
+            boss_id: boss.id,
+            summary: new_state.build_run_summary(player),
+        })],
+        None => Vec::new(),
+    }
+}
+
 fn dead_player_events(player: &PlayerCharacter) -> Vec<Event> {
     if !player.character.is_dead() {
         return Vec::new();
@@ -1,15 +1,20 @@
 use crate::{
     components::{games::GameState, PlayerCharacter},
     errors::Error,
-    events::Event,
+    events::{Event, PlayerMissed},
+    ruleset::Ruleset,
 };
 
-use super::{helpers::npc_attack_player, NpcAction};
+use super::{
+    helpers::{misses_in_the_dark, npc_attack_npc, npc_attack_player},
+    NpcAction,
+};
 
 pub fn handle_npc_action(
     npc_action: &NpcAction,
     state: &GameState,
     player: &PlayerCharacter,
+    ruleset: &Ruleset,
 ) -> Result<Vec<Event>, Error> {
     match npc_action {
         NpcAction::AttackPlayer(npc_id) => {
@@ -18,7 +23,32 @@ pub fn handle_npc_action(
                 None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
             };
 
-            Ok(npc_attack_player(player, npc, true))
+            if misses_in_the_dark(state, player, ruleset) {
+                return Ok(vec![Event::PlayerMissed(PlayerMissed {
+                    attacker_id: npc.id,
+                })]);
+            }
+
+            Ok(npc_attack_player(
+                player,
+                npc,
+                true,
+                &state.difficulty,
+                ruleset,
+            ))
+        }
+        NpcAction::AttackNpc(attacker_id, target_id) => {
+            let room = state.current_room();
+            let attacker = match room.find_npc(attacker_id) {
+                Some(it) => it,
+                None => return Err(Error::NpcNotFoundError(attacker_id.to_string())),
+            };
+            let target = match room.find_npc(target_id) {
+                Some(it) => it,
+                None => return Err(Error::NpcNotFoundError(target_id.to_string())),
+            };
+
+            Ok(npc_attack_npc(attacker, target))
         }
     }
 }
@@ -1,32 +1,56 @@
-use std::ops::RangeInclusive;
-
 use rand::Rng;
 
 use crate::{
-    components::{damage::AttackEffect, NonPlayer, PlayerCharacter},
+    components::{
+        damage::AttackEffect,
+        games::{CauseOfDeath, Difficulty, GameState},
+        items::ItemType,
+        rooms::Condition,
+        Disposition, NonPlayer, PlayerCharacter,
+    },
     events::{
-        Event, NpcWeaponReadied, PlayerHit, PlayerHitNpc, PlayerKilled, PlayerKilledNpc,
-        PlayerMissed, PlayerPoisoned,
+        CompanionHit, CompanionKilled, Event, NpcDispositionChanged, NpcHitNpc, NpcKilledNpc,
+        NpcMissedNpc, NpcWeaponReadied, PlayerBlockedAttack, PlayerHit, PlayerHitNpc, PlayerKilled,
+        PlayerKilledNpc, PlayerMissed, PlayerPoisoned,
     },
+    ruleset::Ruleset,
     utils::rolls::{roll_d6, roll_percent_succeeds},
 };
 
-const PLAYER_DODGE_CHANCE: i32 = 1;
-
-const TOXIC_RANGE: RangeInclusive<i32> = 3..=6;
-const TOXIC_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
+/// Swings between the player and an NPC can go wide in a dark room, unless the player
+/// holds up a lit light to fight by.
+pub fn misses_in_the_dark(state: &GameState, player: &PlayerCharacter, ruleset: &Ruleset) -> bool {
+    let mut rng = rand::thread_rng();
+    state.current_room().has_condition(Condition::Dark)
+        && !player.character.inventory.carries_light()
+        && roll_percent_succeeds(&mut rng, ruleset.darkness_miss_chance)
+}
 
-const ACID_DESTROYS_ITEM_CHANCE: i32 = 20;
+fn block_chance(item_type: &ItemType, ruleset: &Ruleset) -> Option<i32> {
+    match *item_type {
+        ItemType::Shield => Some(ruleset.shield_block_chance),
+        ItemType::Buckler => Some(ruleset.buckler_block_chance),
+        _ => None,
+    }
+}
 
 pub fn npc_attack_player(
     player: &PlayerCharacter,
     npc: &NonPlayer,
     npc_can_ready: bool,
+    difficulty: &Difficulty,
+    ruleset: &Ruleset,
 ) -> Vec<Event> {
     let mut rng = rand::thread_rng();
     let dodge_roll = roll_d6(&mut rng, 1, 0);
+    let dodge_chance = if player.character.is_over_encumbered() {
+        0
+    } else {
+        difficulty.player_dodge_chance(ruleset.player_dodge_chance)
+    };
 
-    if dodge_roll <= PLAYER_DODGE_CHANCE {
+    // A blinded NPC swings wildly and can't land a hit on the player.
+    if dodge_roll <= dodge_chance || npc.character.is_blinded() {
         return vec![Event::PlayerMissed(PlayerMissed {
             attacker_id: npc.id,
         })];
@@ -34,10 +58,31 @@ pub fn npc_attack_player(
 
     let mut events: Vec<Event> = Vec::new();
     if npc.character.has_weapons_readied() {
+        let blocking_shield = player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .filter(|character_item| character_item.is_at_the_ready())
+            .find_map(|character_item| {
+                block_chance(&character_item.item.item_type, ruleset)
+                    .map(|chance| (character_item, chance))
+            });
+
+        if let Some((shield, chance)) = blocking_shield {
+            if roll_percent_succeeds(&mut rng, chance) {
+                return vec![Event::PlayerBlockedAttack(PlayerBlockedAttack {
+                    attacker_id: npc.id,
+                    item_id: shield.item.id,
+                })];
+            }
+        }
+
         let npc_attack = npc.character.full_attack();
         let attack_damage = npc_attack.attack_damage(&mut rng);
         let player_defense = player.character.full_defense();
-        let mut player_damage = player_defense.calculate_damage_taken(&attack_damage);
+        let mut player_damage =
+            difficulty.scale_npc_damage(player_defense.calculate_damage_taken(&attack_damage));
         if let Some(defense_aura) = &player.character.current_effects.shield_aura {
             let actual_damage = player_damage - defense_aura.damage_resistance;
 
@@ -65,6 +110,7 @@ pub fn npc_attack_player(
         if player_damage >= player.character.get_current_health() {
             events.push(Event::PlayerKilled(PlayerKilled {
                 killer_id: Some(npc.id),
+                cause_of_death: CauseOfDeath::Attacked,
             }));
 
             if player.character.current_effects.resurrection_aura {
@@ -76,12 +122,15 @@ pub fn npc_attack_player(
                 match effect {
                     AttackEffect::Toxic => {
                         events.push(Event::PlayerPoisoned(PlayerPoisoned {
-                            damage: rng.gen_range(TOXIC_RANGE),
-                            duration: rng.gen_range(TOXIC_DURATION_RANGE),
+                            damage: rng.gen_range(ruleset.toxic_damage_range.clone()),
+                            duration: rng.gen_range(ruleset.toxic_duration_range.clone()),
                         }));
                     }
                     AttackEffect::Acidic => {
-                        if roll_percent_succeeds(&mut rng, ACID_DESTROYS_ITEM_CHANCE) {
+                        if roll_percent_succeeds(
+                            &mut rng,
+                            difficulty.acid_destroys_item_chance(ruleset.acid_destroys_item_chance),
+                        ) {
                             let equipped_items = player.character.inventory.readied_weapons();
                             let index = rng.gen_range(0..equipped_items.len());
                             if let Some(character_item) = equipped_items.get(index) {
@@ -98,7 +147,7 @@ pub fn npc_attack_player(
         if let Some(retribution_aura) = &player.character.current_effects.retribution_aura {
             let mut rng = rand::thread_rng();
             let damage = retribution_aura.attack_roll(&mut rng);
-            let (mut damage_events, _) = damage_npc(player, npc, damage);
+            let (mut damage_events, _) = damage_npc(player, npc, damage, difficulty);
             events.append(&mut damage_events);
             events.push(Event::PlayerRetributionAuraDissipated);
         }
@@ -106,23 +155,87 @@ pub fn npc_attack_player(
         // If there are no weapons readied, then all the NPC does is ready the weapon.
         let mut weapons = npc.character.inventory.non_readied_weapons();
         weapons.sort_by(|a, b| a.item.num_attack_rolls().cmp(&b.item.num_attack_rolls()));
-        if let Some(weapon) = weapons.first() {
-            events.push(Event::NpcWeaponReadied(NpcWeaponReadied {
-                npc_id: npc.id,
-                item_id: weapon.item.id,
-            }));
-        }
-        if let Some(weapon) = weapons.get(1) {
+        let mut held = npc.character.inventory.held_item_types();
+        for weapon in weapons.iter() {
+            if !weapon.item.item_type.fits_in_hands_with(&held) {
+                continue;
+            }
+
             events.push(Event::NpcWeaponReadied(NpcWeaponReadied {
                 npc_id: npc.id,
                 item_id: weapon.item.id,
             }));
+            held.push(weapon.item.item_type);
         }
     }
     events
 }
 
-pub fn damage_npc(player: &PlayerCharacter, npc: &NonPlayer, damage: i32) -> (Vec<Event>, bool) {
+/// NPCs fighting amongst themselves don't bother with anything fancier than a straight hit.
+pub fn npc_attack_npc(attacker: &NonPlayer, target: &NonPlayer) -> Vec<Event> {
+    if attacker.character.is_blinded() {
+        return vec![Event::NpcMissedNpc(NpcMissedNpc {
+            attacker_id: attacker.id,
+            npc_id: target.id,
+        })];
+    }
+
+    let mut rng = rand::thread_rng();
+    let attack_damage = attacker.character.full_attack().attack_damage(&mut rng);
+    let damage = target
+        .character
+        .full_defense()
+        .calculate_damage_taken(&attack_damage)
+        .min(target.character.get_current_health());
+
+    if damage <= 0 {
+        return vec![Event::NpcMissedNpc(NpcMissedNpc {
+            attacker_id: attacker.id,
+            npc_id: target.id,
+        })];
+    }
+
+    let mut events = vec![Event::NpcHitNpc(NpcHitNpc {
+        attacker_id: attacker.id,
+        npc_id: target.id,
+        damage,
+    })];
+
+    if damage >= target.character.get_current_health() {
+        events.push(Event::NpcKilledNpc(NpcKilledNpc {
+            killer_id: attacker.id,
+            npc_id: target.id,
+        }));
+    }
+
+    events
+}
+
+/// Hostile NPCs hit back at companions the same way they do each other.
+pub fn npc_attack_companion(npc: &NonPlayer, companion: &NonPlayer) -> Vec<Event> {
+    npc_attack_npc(npc, companion)
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::NpcHitNpc(hit) => Some(Event::CompanionHit(CompanionHit {
+                attacker_id: hit.attacker_id,
+                companion_id: hit.npc_id,
+                damage: hit.damage,
+            })),
+            Event::NpcKilledNpc(killed) => Some(Event::CompanionKilled(CompanionKilled {
+                killer_id: killed.killer_id,
+                companion_id: killed.npc_id,
+            })),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn damage_npc(
+    player: &PlayerCharacter,
+    npc: &NonPlayer,
+    damage: i32,
+    difficulty: &Difficulty,
+) -> (Vec<Event>, bool) {
     let mut events: Vec<Event> = vec![Event::PlayerHitNpc(PlayerHitNpc {
         npc_id: npc.id,
         damage,
@@ -141,9 +254,17 @@ pub fn damage_npc(player: &PlayerCharacter, npc: &NonPlayer, damage: i32) -> (Ve
             .iter()
             .any(|event| matches!(event, Event::PlayerKilled(_)))
         {
-            events.push(Event::GameDangerLevelIncreased(1));
+            events.push(Event::GameDangerLevelIncreased(
+                difficulty.danger_level_increase(),
+            ));
             events.push(Event::PlayerMaxHealthChanged(1))
         }
+    } else if !npc.disposition.is_hostile() {
+        // Nobody stays friendly after being hit.
+        events.push(Event::NpcDispositionChanged(NpcDispositionChanged {
+            npc_id: npc.id,
+            disposition: Disposition::Hostile,
+        }));
     }
 
     (events, npc_dead)
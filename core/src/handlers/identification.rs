@@ -0,0 +1,71 @@
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        games::{GameState, ItemKnowledge},
+        PlayerCharacter,
+    },
+    events::{Event, ItemIdentified},
+};
+
+/// Using an item teaches the player about it: drinking or reading reveals a consumable
+/// and taking a hit reveals the armour worn. Weapons are learned by attack_npc, since only
+/// a blow that was actually swung says anything about them.
+pub fn handle(events: &[Event], state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+    let mut learned: Vec<(Uuid, ItemKnowledge)> = Vec::new();
+    let mut learn = |item_id: Uuid, knowledge: ItemKnowledge| match learned
+        .iter_mut()
+        .find(|(id, _)| id.eq(&item_id))
+    {
+        Some((_, existing)) => *existing = existing.merge(&knowledge),
+        None => learned.push((item_id, knowledge)),
+    };
+
+    for event in events.iter() {
+        match event {
+            // Nothing is learned from something that did nothing.
+            Event::PlayerItemUsed(item_used) if !fizzled(events, &item_used.item_id) => learn(
+                item_used.item_id,
+                ItemKnowledge {
+                    knows_consumable: true,
+                    ..ItemKnowledge::default()
+                },
+            ),
+            Event::PlayerHit(_) => {
+                for character_item in player
+                    .character
+                    .inventory
+                    .equipment
+                    .iter()
+                    .filter(|character_item| !character_item.is_packed())
+                    .filter(|character_item| character_item.item.defense.is_some())
+                {
+                    learn(
+                        character_item.item.id,
+                        ItemKnowledge {
+                            knows_defense: true,
+                            ..ItemKnowledge::default()
+                        },
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    learned
+        .into_iter()
+        .filter(|(item_id, knowledge)| {
+            let known = state.item_knowledge(item_id);
+            known.merge(knowledge) != known
+        })
+        .map(|(item_id, knowledge)| Event::ItemIdentified(ItemIdentified { item_id, knowledge }))
+        .collect()
+}
+
+fn fizzled(events: &[Event], item_id: &Uuid) -> bool {
+    events.iter().any(|event| match event {
+        Event::PlayerItemFizzled(fizzled) => fizzled.item_id.eq(item_id),
+        _ => false,
+    })
+}
@@ -3,12 +3,15 @@ use crate::{
     components::games::GameState,
     errors::Error,
     events::{Event, FixtureHasHiddenCompartmentDiscovered},
+    ruleset::Ruleset,
     utils::{ids::parse_id, rolls::roll_d6},
 };
 
-const DISCOVER_HIDDEN_COMPARTMENT_CHANCE: i32 = 2;
-
-pub fn handle(inspect_fixture: &InspectFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    inspect_fixture: &InspectFixture,
+    state: &GameState,
+    ruleset: &Ruleset,
+) -> Result<Vec<Event>, Error> {
     let mut events: Vec<Event> = Vec::new();
     let fixture_id = parse_id(&inspect_fixture.fixture_id)?;
 
@@ -19,7 +22,7 @@ pub fn handle(inspect_fixture: &InspectFixture, state: &GameState) -> Result<Vec
     let mut rng = rand::thread_rng();
 
     if inspect_fixture.discover_hidden_compartment
-        && roll_d6(&mut rng, 1, 0) >= DISCOVER_HIDDEN_COMPARTMENT_CHANCE
+        && roll_d6(&mut rng, 1, 0) >= ruleset.discover_hidden_compartment_chance
     {
         events.push(Event::FixtureHasHiddenCompartmentDiscovered(
             FixtureHasHiddenCompartmentDiscovered { fixture_id },
@@ -0,0 +1,48 @@
+use crate::{
+    actions::InspectItem,
+    components::{
+        games::{GameState, ItemKnowledge},
+        PlayerCharacter,
+    },
+    errors::Error,
+    events::{Event, ItemIdentified},
+    ruleset::Ruleset,
+    utils::{ids::parse_id, rolls::roll_d6},
+};
+
+pub fn handle(
+    inspect_item: &InspectItem,
+    state: &GameState,
+    player: &PlayerCharacter,
+    ruleset: &Ruleset,
+) -> Result<Vec<Event>, Error> {
+    let item_id = parse_id(&inspect_item.item_id)?;
+
+    let item = match player.character.find_item(&item_id) {
+        Some(character_item) => character_item.item,
+        None => match state
+            .current_room()
+            .loose_items
+            .iter()
+            .find(|item| item.id.eq(&item_id))
+        {
+            Some(it) => it.clone(),
+            None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+        },
+    };
+
+    if state.item_knowledge(&item_id).reveals_all_of(&item) {
+        return Ok(Vec::new());
+    }
+
+    let mut rng = rand::thread_rng();
+
+    if roll_d6(&mut rng, 1, 0) >= ruleset.identify_item_chance {
+        Ok(vec![Event::ItemIdentified(ItemIdentified {
+            item_id,
+            knowledge: ItemKnowledge::identified(),
+        })])
+    } else {
+        Ok(Vec::new())
+    }
+}
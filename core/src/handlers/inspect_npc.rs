@@ -3,13 +3,15 @@ use crate::{
     components::games::GameState,
     errors::Error,
     events::{Event, NpcHealthDiscovered, NpcPackedDiscovered},
+    ruleset::Ruleset,
     utils::{ids::parse_id, rolls::roll_d6},
 };
 
-const DISCOVER_HEALTH_CHANCE: i32 = 5;
-const DISCOVER_PACKED_CHANCE: i32 = 4;
-
-pub fn handle(inspect_npc: &InspectNpc, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    inspect_npc: &InspectNpc,
+    state: &GameState,
+    ruleset: &Ruleset,
+) -> Result<Vec<Event>, Error> {
     let mut events: Vec<Event> = Vec::new();
     let npc_id = parse_id(&inspect_npc.npc_id)?;
 
@@ -24,11 +26,14 @@ pub fn handle(inspect_npc: &InspectNpc, state: &GameState) -> Result<Vec<Event>,
     } else {
         let mut rng = rand::thread_rng();
 
-        if inspect_npc.discover_health && roll_d6(&mut rng, 1, 0) >= DISCOVER_HEALTH_CHANCE {
+        if inspect_npc.discover_health && roll_d6(&mut rng, 1, 0) >= ruleset.discover_health_chance
+        {
             events.push(Event::NpcHealthDiscovered(NpcHealthDiscovered { npc_id }));
         }
 
-        if inspect_npc.discover_packed_items && roll_d6(&mut rng, 1, 0) >= DISCOVER_PACKED_CHANCE {
+        if inspect_npc.discover_packed_items
+            && roll_d6(&mut rng, 1, 0) >= ruleset.discover_packed_chance
+        {
             events.push(Event::NpcPackedDiscovered(NpcPackedDiscovered { npc_id }));
         }
     }
@@ -0,0 +1,30 @@
+use crate::{
+    actions::LightItem,
+    components::PlayerCharacter,
+    errors::Error,
+    events::{Event, PlayerItemLit},
+    utils::ids::parse_id,
+};
+
+pub fn handle(light_item: &LightItem, player: &PlayerCharacter) -> Result<Vec<Event>, Error> {
+    let item_id = parse_id(&light_item.item_id)?;
+    let character_item = match player.character.find_item(&item_id) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+    };
+
+    let light_source = match &character_item.item.light_source {
+        Some(it) => it,
+        None => return Err(Error::ItemNotLightSourceError(item_id.to_string())),
+    };
+
+    if light_source.is_burned_out() {
+        return Err(Error::LightSourceBurnedOutError(item_id.to_string()));
+    }
+
+    if light_source.is_lit {
+        return Ok(Vec::new());
+    }
+
+    Ok(vec![Event::PlayerItemLit(PlayerItemLit { item_id })])
+}
@@ -2,13 +2,17 @@ use uuid::Uuid;
 
 use crate::{
     actions::LootFixture,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, ItemTakenFromFixture},
     utils::ids::parse_id,
 };
 
-pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    loot_fixture: &LootFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let fixture_id = parse_id(&loot_fixture.fixture_id)?;
     let fixture_position = match state.current_room().find_fixture(&fixture_id) {
         Some(it) => it,
@@ -30,6 +34,7 @@ pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event
         .filter(|fixture_item| item_ids.contains(&fixture_item.item.id));
 
     let mut events: Vec<Event> = Vec::new();
+    let mut added_weight = 0;
     for matching_item in matching_items {
         if (matching_item.is_in_hidden_compartment
             && !fixture_position.fixture.hidden_compartment_open)
@@ -40,6 +45,13 @@ pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event
             ));
         }
 
+        added_weight += matching_item.item.weight();
+        if !player.character.can_carry(added_weight) {
+            return Err(Error::CarryCapacityExceededError(
+                matching_item.item.id.to_string(),
+            ));
+        }
+
         events.push(Event::ItemTakenFromFixture(ItemTakenFromFixture {
             fixture_id,
             item_id: matching_item.item.id,
@@ -1,12 +1,16 @@
 use crate::{
     actions::LootNpc,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, ItemTakenFromNpc},
     utils::ids::parse_id,
 };
 
-pub fn handle(loot_npc: &LootNpc, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    loot_npc: &LootNpc,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let npc_id = parse_id(&loot_npc.npc_id)?;
 
     let room = state.current_room();
@@ -19,13 +23,21 @@ pub fn handle(loot_npc: &LootNpc, state: &GameState) -> Result<Vec<Event>, Error
     let mut events: Vec<Event> = Vec::new();
 
     if npc.character.is_dead() {
+        let mut added_weight = 0;
         for id in &loot_npc.item_ids {
             let item_id = parse_id(id)?;
             match npc.character.find_item(&item_id) {
-                Some(_) => events.push(Event::ItemTakenFromNpc(ItemTakenFromNpc {
-                    item_id,
-                    npc_id,
-                })),
+                Some(character_item) => {
+                    added_weight += character_item.item.weight();
+                    if !player.character.can_carry(added_weight) {
+                        return Err(Error::CarryCapacityExceededError(item_id.to_string()));
+                    }
+
+                    events.push(Event::ItemTakenFromNpc(ItemTakenFromNpc {
+                        item_id,
+                        npc_id,
+                    }))
+                }
                 None => return Err(Error::ItemNotFoundError(item_id.to_string())),
             }
         }
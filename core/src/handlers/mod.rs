@@ -1,25 +1,38 @@
 mod attack_npc;
 mod cast_spell_on_npc;
 mod cast_spell_on_player;
+mod companions;
 mod exit_room;
+mod extinguish_item;
+mod factions;
+mod give_item_to_companion;
 mod global_effects;
 mod handle;
 mod handle_npc_action;
 mod helpers;
+mod identification;
 mod inspect_fixture;
+mod inspect_item;
 mod inspect_npc;
+mod light_item;
 mod loot_fixture;
 mod loot_npc;
 mod move_player_item;
 mod npc_action;
 mod open_fixture;
 mod open_fixture_hidden_compartment;
+mod parley_with_npc;
 mod pick_up_item;
+mod quests;
+mod recruit_npc;
 mod sell_player_item;
+mod take_item_from_companion;
 mod throw_item_at_npc;
+mod travel_to_room;
 mod use_item_on_player;
 mod view_fixture;
 mod view_npc;
+mod world_tick;
 
 use handle_npc_action::handle_npc_action;
 use npc_action::NpcAction;
@@ -3,8 +3,11 @@ use rand::Rng;
 use crate::{
     actions::MovePlayerItem,
     components::{
-        items::{packed_tags_for_item_type, ready_tag_for_item_type},
-        PlayerCharacter,
+        items::{
+            location_tags_for_item_type, packed_tags_for_item_type, ready_tag_for_item_type,
+            CharacterItem, EquipmentSlot, Handedness, ItemType, LocationTag,
+        },
+        Inventory, PlayerCharacter,
     },
     errors::Error,
     events::{Event, PlayerItemMoved},
@@ -32,22 +35,47 @@ pub fn handle(
         return Ok(Vec::new());
     }
 
-    if character_item.is_weapon()
-        && player.character.count_weapons_at_ready() >= MAX_WEAPONS_AT_READY
-        && move_player_item.put_at_the_ready
-    {
-        return Err(Error::TooManyWeaponsEquippedError);
-    }
+    // Whatever is in the hands the item needs gets packed away to make room.
+    let hand_occupants = if move_player_item.put_at_the_ready {
+        hands_to_free(&character_item.item.item_type, &player.character.inventory)
+    } else {
+        Vec::new()
+    };
 
-    if character_item.is_wearable()
-        && player.character.count_wearables_at_ready() >= MAX_WEARABLES_AT_READY
-        && move_player_item.put_at_the_ready
-    {
-        return Err(Error::TooManyWearablesEquippedError);
+    if move_player_item.put_at_the_ready {
+        let item_type = character_item.item.item_type;
+        let held = held_item_types_after(&player.character.inventory, &hand_occupants);
+
+        if character_item.is_weapon()
+            && weapons_at_ready_after(&player.character.inventory, &hand_occupants)
+                >= MAX_WEAPONS_AT_READY
+        {
+            return Err(Error::TooManyWeaponsEquippedError);
+        }
+
+        if !item_type.has_free_hands(&held) {
+            return Err(Error::NotEnoughFreeHandsError(item_id.to_string()));
+        }
+
+        if !item_type.can_dual_wield_with(&held) {
+            return Err(Error::OffHandWeaponRequiredError(item_id.to_string()));
+        }
     }
 
     let location = match &move_player_item.location_tag {
-        Some(it) => *it,
+        Some(it) => {
+            let allowed = if move_player_item.put_at_the_ready {
+                location_tags_for_item_type(&character_item.item.item_type)
+            } else {
+                packed_tags_for_item_type(&character_item.item.item_type)
+            };
+
+            if !allowed.contains(it) {
+                return Err(Error::InvalidItemLocationError(item_id.to_string()));
+            }
+
+            *it
+        }
         None => {
             if move_player_item.put_at_the_ready {
                 ready_tag_for_item_type(&character_item.item.item_type)
@@ -60,9 +88,160 @@ pub fn handle(
         }
     };
 
-    Ok(vec![Event::PlayerItemMoved(PlayerItemMoved {
+    let mut events: Vec<Event> = Vec::new();
+
+    // Whatever is already filling the slot gets packed away to make room.
+    let mut occupants = hand_occupants;
+    if move_player_item.put_at_the_ready {
+        if let Some(occupant) =
+            EquipmentSlot::for_worn_item(&character_item.item.item_type, &location)
+                .and_then(|slot| player.character.inventory.item_in_slot(&slot))
+        {
+            occupants.push(occupant);
+        }
+    }
+
+    if occupants.is_empty()
+        && character_item.is_wearable()
+        && player.character.count_wearables_at_ready() >= MAX_WEARABLES_AT_READY
+        && move_player_item.put_at_the_ready
+    {
+        return Err(Error::TooManyWearablesEquippedError);
+    }
+
+    for occupant in occupants {
+        events.push(Event::PlayerItemMoved(PlayerItemMoved {
+            item_id: occupant.item.id,
+            at_the_ready: false,
+            location: LocationTag::Packed,
+        }));
+    }
+
+    events.push(Event::PlayerItemMoved(PlayerItemMoved {
         item_id,
         at_the_ready: move_player_item.put_at_the_ready,
         location,
-    })])
+    }));
+
+    Ok(events)
+}
+
+/// The held items to pack away so the item fits in the player's hands. The one in the hand
+/// the item goes in is packed first, and the other hand only if that still isn't enough,
+/// like when taking up something two handed.
+fn hands_to_free<'a>(item_type: &ItemType, inventory: &'a Inventory) -> Vec<&'a CharacterItem> {
+    let handedness = match item_type.handedness() {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+
+    let slots = if handedness == Handedness::OffHand {
+        [EquipmentSlot::OffHand, EquipmentSlot::MainHand]
+    } else {
+        [EquipmentSlot::MainHand, EquipmentSlot::OffHand]
+    };
+
+    let is_weapon = item_type.is_weapon();
+    let mut to_free: Vec<&CharacterItem> = Vec::new();
+    for slot in slots.iter() {
+        if item_type.fits_in_hands_with(&held_item_types_after(inventory, &to_free))
+            && (!is_weapon || weapons_at_ready_after(inventory, &to_free) < MAX_WEAPONS_AT_READY)
+        {
+            break;
+        }
+
+        if let Some(occupant) = inventory.item_in_slot(slot) {
+            to_free.push(occupant);
+        }
+    }
+
+    to_free
+}
+
+fn held_item_types_after(inventory: &Inventory, packed: &[&CharacterItem]) -> Vec<ItemType> {
+    inventory
+        .equipment
+        .iter()
+        .filter(|item| item.is_at_the_ready() && item.item.item_type.handedness().is_some())
+        .filter(|item| !packed.iter().any(|packed| packed.item.id.eq(&item.item.id)))
+        .map(|item| item.item.item_type)
+        .collect()
+}
+
+fn weapons_at_ready_after(inventory: &Inventory, packed: &[&CharacterItem]) -> usize {
+    inventory.count_weapons_at_ready()
+        - packed
+            .iter()
+            .filter(|character_item| character_item.is_weapon())
+            .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::MovePlayerItem,
+        components::{
+            items::{CharacterItem, ItemType, LocationTag},
+            PlayerCharacter,
+        },
+        events::Event,
+        generators::{
+            generator::Generator, items::item_generator_for_level, players::player_generator,
+        },
+    };
+
+    fn give(player: &mut PlayerCharacter, item_type: ItemType, at_the_ready: bool) -> Uuid {
+        let item = item_generator_for_level(&item_type, at_the_ready, 1).generate();
+        let item_id = item.id;
+        player.character.inventory.equipment.push(CharacterItem {
+            item,
+            equipped_location: if at_the_ready {
+                LocationTag::Hand
+            } else {
+                LocationTag::Packed
+            },
+            at_the_ready,
+        });
+        item_id
+    }
+
+    fn packed_by_readying(player: &PlayerCharacter, item_id: &Uuid) -> Vec<Uuid> {
+        super::handle(
+            &MovePlayerItem {
+                item_id: item_id.to_string(),
+                location_tag: None,
+                put_at_the_ready: true,
+            },
+            player,
+        )
+        .unwrap()
+        .iter()
+        .filter_map(|event| match event {
+            Event::PlayerItemMoved(moved) if !moved.at_the_ready => Some(moved.item_id),
+            _ => None,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn readying_into_full_hands_packs_what_is_in_the_way() {
+        let mut player = player_generator(None, None, None).generate();
+        player.character.inventory.equipment.clear();
+        let sword_id = give(&mut player, ItemType::LongSword, true);
+        let shield_id = give(&mut player, ItemType::Shield, true);
+        let mace_id = give(&mut player, ItemType::Mace, false);
+        let buckler_id = give(&mut player, ItemType::Buckler, false);
+        let halberd_id = give(&mut player, ItemType::Halberd, false);
+
+        assert_eq!(vec![sword_id], packed_by_readying(&player, &mace_id));
+        assert_eq!(vec![shield_id], packed_by_readying(&player, &buckler_id));
+
+        let mut packed = packed_by_readying(&player, &halberd_id);
+        packed.sort();
+        let mut expected = vec![sword_id, shield_id];
+        expected.sort();
+        assert_eq!(expected, packed);
+    }
 }
@@ -2,4 +2,6 @@ use uuid::Uuid;
 
 pub enum NpcAction {
     AttackPlayer(Uuid),
+    /// An NPC turning on a rival from a hostile faction, attacker first.
+    AttackNpc(Uuid, Uuid),
 }
@@ -0,0 +1,144 @@
+use crate::{
+    actions::{ParleyApproach, ParleyWithNpc},
+    components::{games::GameState, DialogueTopic, Disposition, NonPlayer, PlayerCharacter},
+    errors::Error,
+    events::{Event, NpcBribed, NpcDispositionChanged, NpcSpoke},
+    generators::dialogue::{dialogue_line, room_information_line},
+    ruleset::Ruleset,
+    utils::{ids::parse_id, rolls::roll_percent_succeeds},
+};
+
+pub fn handle(
+    parley: &ParleyWithNpc,
+    state: &GameState,
+    player: &PlayerCharacter,
+    ruleset: &Ruleset,
+) -> Result<Vec<Event>, Error> {
+    let npc_id = parse_id(&parley.npc_id)?;
+    let npc = match state.current_room().find_npc(&npc_id) {
+        Some(it) => it,
+        None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
+    };
+
+    if npc.character.is_dead() {
+        return Err(Error::NpcCannotParleyError(npc_id.to_string()));
+    }
+
+    match parley.approach {
+        ParleyApproach::Talk => Ok(talk(npc, state)),
+        ParleyApproach::Bribe => bribe(npc, player, ruleset),
+        ParleyApproach::Intimidate => Ok(intimidate(npc, player, ruleset)),
+    }
+}
+
+fn spoke(npc: &NonPlayer, topic: DialogueTopic) -> Event {
+    Event::NpcSpoke(NpcSpoke {
+        npc_id: npc.id,
+        topic,
+        line: dialogue_line(&topic),
+        exit_id: None,
+    })
+}
+
+fn talk(npc: &NonPlayer, state: &GameState) -> Vec<Event> {
+    match npc.disposition {
+        Disposition::Hostile => vec![spoke(npc, DialogueTopic::Threat)],
+        Disposition::Wary => vec![spoke(npc, DialogueTopic::Refusal)],
+        Disposition::Friendly => {
+            let mut events = vec![
+                spoke(npc, DialogueTopic::Greeting),
+                spoke(npc, DialogueTopic::RoomInformation),
+            ];
+
+            // Friendly NPCs share what they know about the rooms past each exit.
+            for exit_id in state.current_room_exits() {
+                let num_enemies = state
+                    .world
+                    .exit_graph
+                    .iter()
+                    .find(|exit_map| exit_map.exit_id.eq(&exit_id))
+                    .and_then(|exit_map| exit_map.other_room_id(state.current_room_id))
+                    .and_then(|room_id| state.world.rooms.iter().find(|room| room.id.eq(&room_id)))
+                    .map(|room| {
+                        room.npc_positions
+                            .iter()
+                            .filter(|npc_position| {
+                                !npc_position.npc.character.is_dead()
+                                    && npc_position.npc.disposition.is_hostile()
+                            })
+                            .count()
+                    });
+
+                events.push(Event::NpcSpoke(NpcSpoke {
+                    npc_id: npc.id,
+                    topic: DialogueTopic::RoomInformation,
+                    line: room_information_line(num_enemies),
+                    exit_id: Some(exit_id),
+                }));
+            }
+
+            events
+        }
+    }
+}
+
+fn bribe(
+    npc: &NonPlayer,
+    player: &PlayerCharacter,
+    ruleset: &Ruleset,
+) -> Result<Vec<Event>, Error> {
+    if npc.is_boss() {
+        return Ok(vec![spoke(npc, DialogueTopic::Threat)]);
+    }
+
+    let gold = match npc.disposition {
+        Disposition::Friendly => return Ok(vec![spoke(npc, DialogueTopic::Greeting)]),
+        Disposition::Hostile => ruleset.bribe_gold_hostile,
+        Disposition::Wary => ruleset.bribe_gold_wary,
+    };
+
+    if player.gold < gold {
+        return Err(Error::NotEnoughGoldError);
+    }
+
+    Ok(vec![
+        Event::NpcBribed(NpcBribed {
+            npc_id: npc.id,
+            gold,
+        }),
+        Event::NpcDispositionChanged(NpcDispositionChanged {
+            npc_id: npc.id,
+            disposition: Disposition::Friendly,
+        }),
+        spoke(npc, DialogueTopic::BribeAccepted),
+    ])
+}
+
+fn intimidate(npc: &NonPlayer, player: &PlayerCharacter, ruleset: &Ruleset) -> Vec<Event> {
+    // Bosses have seen far scarier things than the player.
+    let chance = if npc.is_boss() {
+        0
+    } else {
+        let size_difference =
+            player.character.stats.height.scale() - npc.character.stats.height.scale();
+        ruleset.base_intimidate_chance + size_difference * ruleset.intimidate_chance_per_size
+    };
+
+    let mut rng = rand::thread_rng();
+    let (disposition, topic) = if roll_percent_succeeds(&mut rng, chance) {
+        (Disposition::Wary, DialogueTopic::Intimidated)
+    } else {
+        (Disposition::Hostile, DialogueTopic::NotIntimidated)
+    };
+
+    let mut events: Vec<Event> = Vec::new();
+    if npc.disposition != disposition {
+        events.push(Event::NpcDispositionChanged(NpcDispositionChanged {
+            npc_id: npc.id,
+            disposition,
+        }));
+    }
+    events.push(spoke(npc, topic));
+
+    events
+}
@@ -1,20 +1,31 @@
 use crate::{
-    actions::PickUpItem, components::games::GameState, errors::Error, events::Event,
+    actions::PickUpItem,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::Event,
     utils::parse_id,
 };
 
-pub fn handle(pick_up_item: &PickUpItem, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    pick_up_item: &PickUpItem,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let item_id = parse_id(&pick_up_item.item_id)?;
 
-    match state
+    let item = match state
         .current_room()
         .loose_items
         .iter()
         .find(|item| item.id.eq(&item_id))
     {
-        Some(_) => {}
+        Some(it) => it,
         None => return Err(Error::ItemNotFoundError(item_id.to_string())),
     };
 
+    if !player.character.can_carry(item.weight()) {
+        return Err(Error::CarryCapacityExceededError(item_id.to_string()));
+    }
+
     Ok(vec![Event::PlayerPicksUpItem(item_id)])
 }
@@ -0,0 +1,186 @@
+use crate::{
+    components::{
+        games::GameState,
+        quests::{ObjectiveType, Quest},
+        PlayerCharacter,
+    },
+    events::{Event, QuestCompleted, QuestProgressed},
+};
+
+/// Look through the events from an action and move along any quests they satisfy.
+/// The state and player passed in should already have the events applied.
+pub fn handle(events: &[Event], state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+    let mut quest_events: Vec<Event> = Vec::new();
+
+    if player.character.is_dead() {
+        return quest_events;
+    }
+
+    for quest in state.quests.iter().filter(|quest| !quest.completed) {
+        let progress = match quest.objective.objective_type {
+            ObjectiveType::FreePrisoner => {
+                quest.progress.max(free_prisoner_progress(events, state))
+            }
+            ObjectiveType::ReachDepth => state.danger_level,
+            ObjectiveType::RetrieveItem => retrieve_item_progress(quest, player),
+            ObjectiveType::SlaySpecies => {
+                quest.progress + slay_species_progress(quest, events, state)
+            }
+        }
+        .min(quest.objective.required);
+
+        if progress != quest.progress {
+            quest_events.push(Event::QuestProgressed(QuestProgressed {
+                quest_id: quest.id,
+                progress,
+            }));
+        }
+
+        if progress >= quest.objective.required {
+            quest_events.push(Event::QuestCompleted(QuestCompleted { quest_id: quest.id }));
+            if quest.reward.gold > 0 {
+                quest_events.push(Event::PlayerGainedGold(quest.reward.gold));
+            }
+        }
+    }
+
+    quest_events
+}
+
+/// The prisoner is only freed by something the player does for them: killing the last of
+/// their guards, or talking to or recruiting them once the guards are gone.
+fn free_prisoner_progress(events: &[Event], state: &GameState) -> u32 {
+    let room = state.current_room();
+    let freed_prisoner = room.freed_prisoner();
+    let frees_prisoner = |event: &Event| match event {
+        Event::PlayerKilledNpc(killed) => {
+            freed_prisoner.is_some()
+                && room
+                    .find_npc(&killed.npc_id)
+                    .is_some_and(|npc| npc.disposition.is_hostile())
+        }
+        Event::NpcSpoke(spoke) => freed_prisoner.is_some_and(|npc| npc.id.eq(&spoke.npc_id)),
+        Event::NpcRecruited(recruited) => state
+            .party
+            .iter()
+            .any(|npc| npc.id.eq(&recruited.npc_id) && npc.is_prisoner),
+        _ => false,
+    };
+
+    if events.iter().any(frees_prisoner) {
+        1
+    } else {
+        0
+    }
+}
+
+fn retrieve_item_progress(quest: &Quest, player: &PlayerCharacter) -> u32 {
+    let has_item = player
+        .character
+        .inventory
+        .equipment
+        .iter()
+        .any(|character_item| Some(character_item.item.item_type) == quest.objective.item_type);
+
+    if has_item {
+        1
+    } else {
+        0
+    }
+}
+
+fn slay_species_progress(quest: &Quest, events: &[Event], state: &GameState) -> u32 {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::PlayerKilledNpc(killed) => state.find_npc(&killed.npc_id),
+            _ => None,
+        })
+        .filter(|npc| Some(npc.character.species) == quest.objective.species)
+        .count() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        components::{
+            games::GameState,
+            quests::ObjectiveType,
+            rooms::{NpcPosition, RoomType},
+            DialogueTopic, Disposition, NonPlayer,
+        },
+        events::{Event, NpcSpoke, PlayerKilledNpc},
+        generators::{
+            game::game_generator, generator::Generator, non_players::NonPlayerGeneratorBuilder,
+            players::player_generator, quests::quest_generator,
+        },
+        ruleset::Ruleset,
+    };
+
+    fn prison_cell(prisoner: &NonPlayer, guard: &NonPlayer) -> GameState {
+        let mut state =
+            game_generator(Default::default(), Ruleset::default(), Vec::new()).generate();
+        state.quests =
+            vec![quest_generator(ObjectiveType::FreePrisoner, 1, &Ruleset::default()).generate()];
+        let room = state.current_room_mut();
+        room.room_type = RoomType::PrisonCell;
+        room.npc_positions = [prisoner, guard]
+            .into_iter()
+            .map(|npc| NpcPosition {
+                npc: npc.clone(),
+                position_descriptor: None,
+            })
+            .collect();
+        state
+    }
+
+    fn completes_quest(events: &[Event]) -> bool {
+        events
+            .iter()
+            .any(|event| matches!(event, Event::QuestCompleted(_)))
+    }
+
+    #[test]
+    fn prisoners_are_freed_by_the_player_and_not_by_an_empty_room() {
+        let player = player_generator(None, None, None).generate();
+        let mut prisoner = NonPlayerGeneratorBuilder::new().build().generate();
+        prisoner.disposition = Disposition::Wary;
+        prisoner.is_prisoner = true;
+        let mut guard = NonPlayerGeneratorBuilder::new().build().generate();
+        guard.disposition = Disposition::Hostile;
+        let talk = Event::NpcSpoke(NpcSpoke {
+            npc_id: prisoner.id,
+            topic: DialogueTopic::Refusal,
+            line: String::new(),
+            exit_id: None,
+        });
+
+        let state = prison_cell(&prisoner, &guard);
+        assert!(!completes_quest(&super::handle(
+            std::slice::from_ref(&talk),
+            &state,
+            &player
+        )));
+
+        guard.kill();
+        let state = prison_cell(&prisoner, &guard);
+        assert!(!completes_quest(&super::handle(&[], &state, &player)));
+        assert!(completes_quest(&super::handle(&[talk], &state, &player)));
+
+        let killed = Event::PlayerKilledNpc(PlayerKilledNpc {
+            npc_id: guard.id,
+            killer_id: Uuid::new_v4(),
+        });
+        assert!(completes_quest(&super::handle(
+            std::slice::from_ref(&killed),
+            &state,
+            &player
+        )));
+
+        prisoner.kill();
+        let state = prison_cell(&prisoner, &guard);
+        assert!(!completes_quest(&super::handle(&[killed], &state, &player)));
+    }
+}
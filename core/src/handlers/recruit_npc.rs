@@ -0,0 +1,44 @@
+use crate::{
+    actions::RecruitNpc,
+    components::{games::GameState, rooms::RoomType, PlayerCharacter},
+    errors::Error,
+    events::{Event, NpcRecruited},
+    ruleset::Ruleset,
+    utils::ids::parse_id,
+};
+
+pub fn handle(
+    recruit_npc: &RecruitNpc,
+    state: &GameState,
+    player: &PlayerCharacter,
+    ruleset: &Ruleset,
+) -> Result<Vec<Event>, Error> {
+    let npc_id = parse_id(&recruit_npc.npc_id)?;
+    let room = state.current_room();
+
+    let npc = match room.find_npc(&npc_id) {
+        Some(it) => it,
+        None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
+    };
+
+    if state.party_is_full() {
+        return Err(Error::PartyFullError);
+    }
+
+    if npc.character.is_dead() || npc.disposition.is_hostile() || npc.is_boss() {
+        return Err(Error::NpcCannotBeRecruitedError(npc_id.to_string()));
+    }
+
+    // Prisoners will follow whoever freed them, everyone else expects to be paid.
+    let gold = match room.room_type {
+        RoomType::PrisonCell if npc.is_prisoner && room.first_alive_hostile_npc().is_none() => 0,
+        RoomType::TavernHall => ruleset.hire_cost,
+        _ => return Err(Error::NpcCannotBeRecruitedError(npc_id.to_string())),
+    };
+
+    if player.gold < gold {
+        return Err(Error::NotEnoughGoldError);
+    }
+
+    Ok(vec![Event::NpcRecruited(NpcRecruited { npc_id, gold })])
+}
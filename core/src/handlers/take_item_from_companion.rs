@@ -0,0 +1,37 @@
+use crate::{
+    actions::TakeItemFromCompanion,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, ItemTakenFromCompanion},
+    utils::ids::parse_id,
+};
+
+pub fn handle(
+    take_item_from_companion: &TakeItemFromCompanion,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let companion_id = parse_id(&take_item_from_companion.companion_id)?;
+    let item_id = parse_id(&take_item_from_companion.item_id)?;
+
+    let companion = match state.find_companion(&companion_id) {
+        Some(it) => it,
+        None => return Err(Error::CompanionNotFoundError(companion_id.to_string())),
+    };
+
+    let character_item = match companion.character.find_item(&item_id) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+    };
+
+    if !player.character.can_carry(character_item.item.weight()) {
+        return Err(Error::CarryCapacityExceededError(item_id.to_string()));
+    }
+
+    Ok(vec![Event::ItemTakenFromCompanion(
+        ItemTakenFromCompanion {
+            companion_id,
+            item_id,
+        },
+    )])
+}
@@ -1,15 +1,21 @@
+use rand::Rng;
+
 use crate::{
     actions::ThrowItemAtNpc,
-    components::{games::GameState, PlayerCharacter},
+    components::{games::GameState, NonPlayer, PlayerCharacter},
     errors::Error,
-    events::{Event, PlayerItemRemoved},
-    utils::ids::parse_id,
+    events::{Event, NpcBlinded, NpcItemDestroyed, PlayerItemRemoved},
+    ruleset::Ruleset,
+    utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
+use super::helpers::damage_npc;
+
 pub fn handle(
     throw_item_at_npc: &ThrowItemAtNpc,
     state: &GameState,
     player: &PlayerCharacter,
+    ruleset: &Ruleset,
 ) -> Result<Vec<Event>, Error> {
     let item_id = parse_id(&throw_item_at_npc.item_id)?;
     let character_item = match player.character.find_item(&item_id) {
@@ -19,27 +25,304 @@ pub fn handle(
 
     let room = state.current_room();
     let npc_id = parse_id(&throw_item_at_npc.npc_id)?;
-    match room.find_npc(&npc_id) {
+    let npc = match room.find_npc(&npc_id) {
         Some(it) => it,
         None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
     };
 
+    if npc.character.is_dead() {
+        return Err(Error::NpcIsDeadError(npc_id.to_string()));
+    }
+
+    let throwable = match &character_item.item.throwable {
+        Some(it) => it,
+        None => return Err(Error::ItemNotThrowableError(item_id.to_string())),
+    };
+
+    let mut rng = rand::thread_rng();
     let mut events: Vec<Event> = Vec::new();
-    if let Some(throwable) = &character_item.item.throwable {
-        if let Some(oil_effect) = &throwable.effect.oil_splash_effect {
-            if oil_effect.covers_all_enemies {
-                for npc_position in room.npc_positions.iter() {
-                    events.push(Event::NpcCoveredInOil(npc_position.npc.id));
-                }
-            } else {
-                events.push(Event::NpcCoveredInOil(npc_id));
+
+    let living_npcs: Vec<&NonPlayer> = room
+        .npc_positions
+        .iter()
+        .map(|npc_position| &npc_position.npc)
+        .filter(|npc| !npc.character.is_dead())
+        .collect();
+
+    let targets = |covers_all_enemies: bool| -> Vec<&NonPlayer> {
+        if covers_all_enemies {
+            living_npcs.clone()
+        } else {
+            vec![npc]
+        }
+    };
+
+    if let Some(oil_effect) = &throwable.effect.oil_splash_effect {
+        if oil_effect.covers_all_enemies {
+            for npc_position in room.npc_positions.iter() {
+                events.push(Event::NpcCoveredInOil(npc_position.npc.id));
             }
+        } else {
+            events.push(Event::NpcCoveredInOil(npc_id));
+        }
+    }
+
+    if let Some(smoke_effect) = &throwable.effect.smoke_bomb_effect {
+        for target in living_npcs.iter() {
+            events.push(Event::NpcBlinded(NpcBlinded {
+                npc_id: target.id,
+                duration: smoke_effect.duration,
+            }));
+        }
+    }
+
+    if let Some(caltrops_effect) = &throwable.effect.caltrops_effect {
+        for target in living_npcs.iter() {
+            let damage = caltrops_effect
+                .damage
+                .attack_roll(&mut rng)
+                .min(target.character.get_current_health());
+            let (mut damage_events, _) = damage_npc(player, target, damage, &state.difficulty);
+            events.append(&mut damage_events);
+        }
+    }
+
+    if let Some(holy_water_effect) = &throwable.effect.holy_water_effect {
+        for target in targets(holy_water_effect.covers_all_enemies)
+            .into_iter()
+            .filter(|target| target.character.is_undead())
+        {
+            let damage = holy_water_effect
+                .damage
+                .attack_roll(&mut rng)
+                .min(target.character.get_current_health());
+            let (mut damage_events, _) = damage_npc(player, target, damage, &state.difficulty);
+            events.append(&mut damage_events);
+        }
+    }
+
+    if let Some(acid_effect) = &throwable.effect.acid_flask_effect {
+        for target in targets(acid_effect.covers_all_enemies) {
+            let readied_weapons = target.character.inventory.readied_weapons();
+            if readied_weapons.is_empty()
+                || !roll_percent_succeeds(
+                    &mut rng,
+                    state
+                        .difficulty
+                        .thrown_acid_destroys_item_chance(ruleset.thrown_acid_destroys_item_chance),
+                )
+            {
+                continue;
+            }
+
+            let index = rng.gen_range(0..readied_weapons.len());
+            if let Some(character_item) = readied_weapons.get(index) {
+                events.push(Event::NpcHitWithAcid(target.id));
+                events.push(Event::NpcItemDestroyed(NpcItemDestroyed {
+                    npc_id: target.id,
+                    item_id: character_item.item.id,
+                }));
+            }
+        }
+    }
+
+    if let Some(firebomb_effect) = &throwable.effect.firebomb_effect {
+        for target in targets(firebomb_effect.covers_all_enemies) {
+            let roll = firebomb_effect.damage.attack_roll(&mut rng);
+            let fire_damage = if target.character.current_effects.covered_in_oil {
+                events.push(Event::NpcOilIgnited(target.id));
+                roll * 2
+            } else {
+                roll
+            };
+            let damage = fire_damage.min(target.character.get_current_health());
+            let (mut damage_events, _) = damage_npc(player, target, damage, &state.difficulty);
+            events.append(&mut damage_events);
         }
-    } else {
-        return Err(Error::ItemNotThrowableError(item_id.to_string()));
     }
 
     events.push(Event::PlayerItemRemoved(PlayerItemRemoved { item_id }));
 
     Ok(events)
 }
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::ThrowItemAtNpc,
+        components::{
+            damage::Attack,
+            games::GameState,
+            items::{
+                CharacterItem, FirebombEffect, HolyWaterEffect, Item, ItemType, LocationTag,
+                Throwable, ThrowableEffect, ThrowableEffectName,
+            },
+            rooms::NpcPosition,
+            LifeModifier, NonPlayer, PlayerCharacter, Tagged,
+        },
+        errors::Error,
+        events::Event,
+        generators::{
+            game::game_generator, generator::Generator, non_players::NonPlayerGeneratorBuilder,
+            players::player_generator,
+        },
+        ruleset::Ruleset,
+    };
+
+    fn npc(undead: bool, covered_in_oil: bool) -> NonPlayer {
+        let mut npc = NonPlayerGeneratorBuilder::new().build().generate();
+        npc.character.stats.health.max = 100;
+        npc.character.stats.health.current = 100;
+        npc.character.life_modifier = if undead {
+            Some(LifeModifier::Zombie)
+        } else {
+            None
+        };
+        npc.character.current_effects.covered_in_oil = covered_in_oil;
+        npc
+    }
+
+    fn room_with(npcs: &[&NonPlayer]) -> GameState {
+        let mut state =
+            game_generator(Default::default(), Ruleset::default(), Vec::new()).generate();
+        state.current_room_mut().npc_positions = npcs
+            .iter()
+            .map(|npc| NpcPosition {
+                npc: (*npc).clone(),
+                position_descriptor: None,
+            })
+            .collect();
+        state
+    }
+
+    fn player_with(effect: ThrowableEffect) -> (PlayerCharacter, Uuid) {
+        let mut player = player_generator(None, None, None).generate();
+        let item_id = Uuid::new_v4();
+        player.character.add_item(CharacterItem {
+            item: Item {
+                id: item_id,
+                name: None,
+                item_type: ItemType::Flask,
+                tags: ItemType::Flask.tags(),
+                descriptors: Vec::new(),
+                material: None,
+                attack: None,
+                defense: None,
+                consumable: None,
+                throwable: Some(Throwable { uses: 1, effect }),
+                light_source: None,
+            },
+            equipped_location: LocationTag::Packed,
+            at_the_ready: false,
+        });
+        (player, item_id)
+    }
+
+    fn effect(name: ThrowableEffectName) -> ThrowableEffect {
+        ThrowableEffect {
+            name,
+            oil_splash_effect: None,
+            smoke_bomb_effect: None,
+            caltrops_effect: None,
+            holy_water_effect: None,
+            acid_flask_effect: None,
+            firebomb_effect: None,
+        }
+    }
+
+    fn damage(amount: i32) -> Attack {
+        Attack {
+            num_rolls: 0,
+            modifier: amount,
+            effects: Vec::new(),
+        }
+    }
+
+    fn throw(
+        state: &GameState,
+        player: &PlayerCharacter,
+        item_id: &Uuid,
+        target: &NonPlayer,
+    ) -> Result<Vec<Event>, Error> {
+        super::handle(
+            &ThrowItemAtNpc {
+                item_id: item_id.to_string(),
+                npc_id: target.id.to_string(),
+            },
+            state,
+            player,
+            &Ruleset::default(),
+        )
+    }
+
+    fn damage_dealt(events: &[Event], npc: &NonPlayer) -> Option<i32> {
+        events.iter().find_map(|event| match event {
+            Event::PlayerHitNpc(hit) if hit.npc_id == npc.id => Some(hit.damage),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn holy_water_only_hurts_undead() {
+        let zombie = npc(true, false);
+        let goblin = npc(false, false);
+        let state = room_with(&[&zombie, &goblin]);
+        let mut holy_water = effect(ThrowableEffectName::HolyWater);
+        holy_water.holy_water_effect = Some(HolyWaterEffect {
+            damage: damage(5),
+            covers_all_enemies: true,
+        });
+        let (player, item_id) = player_with(holy_water);
+
+        let events = throw(&state, &player, &item_id, &goblin).unwrap();
+        assert_eq!(damage_dealt(&events, &zombie), Some(5));
+        assert_eq!(damage_dealt(&events, &goblin), None);
+    }
+
+    #[test]
+    fn firebombs_do_double_damage_to_oiled_npcs() {
+        let oiled = npc(false, true);
+        let dry = npc(false, false);
+        let state = room_with(&[&oiled, &dry]);
+        let mut firebomb = effect(ThrowableEffectName::Firebomb);
+        firebomb.firebomb_effect = Some(FirebombEffect {
+            damage: damage(5),
+            covers_all_enemies: true,
+        });
+        let (player, item_id) = player_with(firebomb);
+
+        let events = throw(&state, &player, &item_id, &dry).unwrap();
+        assert_eq!(damage_dealt(&events, &oiled), Some(10));
+        assert_eq!(damage_dealt(&events, &dry), Some(5));
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::NpcOilIgnited(id) if *id == oiled.id)));
+    }
+
+    #[test]
+    fn single_target_throws_only_hit_the_target() {
+        let target = npc(false, false);
+        let bystander = npc(false, false);
+        let mut firebomb = effect(ThrowableEffectName::Firebomb);
+        firebomb.firebomb_effect = Some(FirebombEffect {
+            damage: damage(5),
+            covers_all_enemies: false,
+        });
+        let (player, item_id) = player_with(firebomb);
+
+        let state = room_with(&[&target, &bystander]);
+        let events = throw(&state, &player, &item_id, &target).unwrap();
+        assert_eq!(damage_dealt(&events, &target), Some(5));
+        assert_eq!(damage_dealt(&events, &bystander), None);
+
+        let mut dead = target.clone();
+        dead.character.stats.health.current = 0;
+        let state = room_with(&[&dead, &bystander]);
+        assert!(matches!(
+            throw(&state, &player, &item_id, &dead),
+            Err(Error::NpcIsDeadError(_))
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    actions::{Action, ExitRoom, TravelToRoom},
+    components::{games::GameState, PlayerCharacter},
+    content::ContentRegistry,
+    errors::Error,
+    events::Event,
+    ruleset::Ruleset,
+    utils::ids::parse_id,
+};
+
+use super::{handle_action, HandledAction};
+
+/// Takes the shortest route through the rooms already seen, one exit at a time, so every hop
+/// plays out just like exiting the room by hand. The journey stops early when something gets
+/// in the way.
+pub fn handle(
+    travel_to_room: &TravelToRoom,
+    state: &GameState,
+    player: &PlayerCharacter,
+    ruleset: &Ruleset,
+    content: &ContentRegistry,
+) -> Result<HandledAction, Error> {
+    let room_id = parse_id(&travel_to_room.room_id)?;
+    if !state.rooms_seen.contains(&room_id) {
+        return Err(Error::RoomNotFoundError(room_id.to_string()));
+    }
+
+    let exit_ids = match state.path_to_room(&room_id) {
+        Some(it) => it,
+        None => return Err(Error::NoPathToRoomError(room_id.to_string())),
+    };
+
+    let mut events: Vec<Event> = Vec::new();
+    let mut new_state = state.clone();
+    let mut new_player = player.clone();
+    for exit_id in exit_ids {
+        let exit_room = Action::ExitRoom(ExitRoom {
+            exit_id: exit_id.to_string(),
+        });
+        let mut handled = handle_action(&exit_room, &new_state, &new_player, ruleset, content)?;
+        let interrupted = is_interrupted(&handled);
+        events.append(&mut handled.events);
+        new_state = handled.new_state;
+        new_player = handled.new_player;
+
+        if interrupted {
+            break;
+        }
+    }
+
+    Ok(HandledAction {
+        events,
+        new_player,
+        new_state,
+    })
+}
+
+/// Being attacked on the way, or walking in on someone hostile, ends the journey.
+fn is_interrupted(handled: &HandledAction) -> bool {
+    handled.new_player.character.is_dead()
+        || handled.new_state.is_completed()
+        || handled
+            .new_state
+            .current_room()
+            .first_alive_hostile_npc()
+            .is_some()
+        || handled.events.iter().any(|event| {
+            matches!(
+                event,
+                Event::PlayerHit(_)
+                    | Event::PlayerMissed(_)
+                    | Event::PlayerBlockedAttack(_)
+                    | Event::PlayerHitWithAcid
+                    | Event::PlayerPoisoned(_)
+            )
+        })
+}
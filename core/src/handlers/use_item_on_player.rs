@@ -3,15 +3,26 @@ use uuid::Uuid;
 
 use crate::{
     actions::UseItemOnPlayer,
-    components::{items::ConsumableEffectName, spells::Spell, PlayerCharacter},
+    components::{
+        games::{GameState, ItemKnowledge},
+        items::{Consumable, ConsumableEffectName},
+        spells::Spell,
+        PlayerCharacter,
+    },
     errors::Error,
-    events::{Event, PlayerHealed, PlayerItemRemoved, PlayerItemUsed, PlayerSpellLearned},
-    utils::ids::parse_id,
+    events::{
+        Event, ItemIdentified, PlayerHealed, PlayerItemFizzled, PlayerItemRemoved, PlayerItemUsed,
+        PlayerSpellLearned,
+    },
+    ruleset::Ruleset,
+    utils::{ids::parse_id, rolls::roll_percent_succeeds},
 };
 
 pub fn handle(
     use_item_on_player: &UseItemOnPlayer,
+    state: &GameState,
     player: &PlayerCharacter,
+    ruleset: &Ruleset,
 ) -> Result<Vec<Event>, Error> {
     let item_id = parse_id(&use_item_on_player.item_id)?;
     let character_item = match player.character.find_item(&item_id) {
@@ -28,9 +39,36 @@ pub fn handle(
         None => return Ok(Vec::new()),
     };
 
-    let mut events = match consumable.effect.name {
+    let is_identified =
+        state.all_knowledge_unlocked || state.item_knowledge(&item_id).knows_consumable;
+    let mut rng = rand::thread_rng();
+
+    // Drinking or reading something unknown is a gamble, and it might just be wasted.
+    let mut events =
+        if !is_identified && roll_percent_succeeds(&mut rng, ruleset.unidentified_fizzle_chance) {
+            vec![Event::PlayerItemFizzled(PlayerItemFizzled { item_id })]
+        } else {
+            consumable_events(&consumable, &item_id, state, player)
+        };
+
+    events.push(Event::PlayerItemUsed(PlayerItemUsed { item_id }));
+
+    if consumable.uses - 1 == 0 {
+        events.push(Event::PlayerItemRemoved(PlayerItemRemoved { item_id }));
+    }
+
+    Ok(events)
+}
+
+fn consumable_events(
+    consumable: &Consumable,
+    item_id: &Uuid,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Vec<Event> {
+    match consumable.effect.name {
         ConsumableEffectName::LearnSpell => {
-            if let Some(learn_spell_effect) = consumable.effect.learn_spell_effect {
+            if let Some(learn_spell_effect) = &consumable.effect.learn_spell_effect {
                 let spell = Spell {
                     name: learn_spell_effect.spell_name,
                     attack: learn_spell_effect.spell_attack.clone(),
@@ -48,9 +86,11 @@ pub fn handle(
             }
         }
         ConsumableEffectName::HealingGrog => {
-            if let Some(heal_effect) = consumable.effect.healing_effect {
+            if let Some(heal_effect) = &consumable.effect.healing_effect {
                 let mut rng = rand::thread_rng();
-                let healing = heal_effect.healing.attack_roll(&mut rng);
+                let healing = state
+                    .difficulty
+                    .scale_healing(heal_effect.healing.attack_roll(&mut rng));
                 let damage_healed = healing
                     .min(player.character.stats.health.max - player.character.stats.health.current);
                 vec![Event::PlayerHealed(PlayerHealed { damage_healed })]
@@ -58,13 +98,20 @@ pub fn handle(
                 Vec::new()
             }
         }
-    };
-
-    events.push(Event::PlayerItemUsed(PlayerItemUsed { item_id }));
-
-    if consumable.uses - 1 == 0 {
-        events.push(Event::PlayerItemRemoved(PlayerItemRemoved { item_id }));
+        ConsumableEffectName::Identify => player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .map(|character_item| &character_item.item)
+            .filter(|item| item.id.ne(item_id))
+            .filter(|item| !state.item_knowledge(&item.id).reveals_all_of(item))
+            .map(|item| {
+                Event::ItemIdentified(ItemIdentified {
+                    item_id: item.id,
+                    knowledge: ItemKnowledge::identified(),
+                })
+            })
+            .collect(),
     }
-
-    Ok(events)
 }
@@ -1,24 +1,33 @@
 use crate::{
     actions::LookAtFixture,
-    components::{fixtures::FixtureViewArgs, games::GameState},
+    components::{fixtures::FixtureViewArgs, games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, FixtureViewed},
     systems::view::fixture,
     utils::ids::parse_id,
 };
 
-pub fn handle(look_at_fixture: &LookAtFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+/// The fixture can't be found in the dark, and in dim light looks no different to how it does
+/// in the room.
+pub fn handle(
+    look_at_fixture: &LookAtFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let fixture_id = parse_id(&look_at_fixture.fixture_id)?;
 
     let fixture_position = match state.current_room().find_fixture(&fixture_id) {
-        Some(it) => it,
-        None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+        Some(it) if state.can_see_current_room(player) => it,
+        _ => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
     };
 
-    let knowledge = state.fixture_knowledge(&fixture_id);
-
-    let args = FixtureViewArgs {
-        knows_has_hidden_compartment: knowledge.knows_has_hidden_compartment,
+    let args = if state.sees_current_room_clearly(player) {
+        let knowledge = state.fixture_knowledge(&fixture_id);
+        FixtureViewArgs {
+            knows_has_hidden_compartment: knowledge.knows_has_hidden_compartment,
+        }
+    } else {
+        FixtureViewArgs::default()
     };
 
     let view = fixture::view(
@@ -0,0 +1,234 @@
+use rand::seq::SliceRandom;
+use uuid::Uuid;
+
+use crate::{
+    components::{games::GameState, rooms::Room, NonPlayer, PlayerCharacter},
+    content::ContentRegistry,
+    events::{Event, NpcMoved, NpcMovementHeard, NpcRegrouped, RoomRepopulated},
+    generators::rooms::npcs::repopulation_npc_positions,
+    ruleset::Ruleset,
+    utils::rolls::roll_percent_succeeds,
+};
+
+/// Time moves on in the rest of the world every turn. Hostile NPCs wander between the rooms
+/// the player has opened up, the badly hurt flee, those who fled look for their allies, and
+/// cleared rooms slowly fill back up. Prisoners stay locked in their cells.
+/// The state and player passed in should already have the events applied.
+pub fn handle(
+    state: &GameState,
+    player: &PlayerCharacter,
+    ruleset: &Ruleset,
+    content: &ContentRegistry,
+) -> Vec<Event> {
+    if player.character.is_dead() {
+        return Vec::new();
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut tick_events: Vec<Event> = Vec::new();
+
+    for room in state.world.rooms.iter() {
+        let adjacent_rooms = state.adjacent_rooms(room);
+
+        for npc in room
+            .npc_positions
+            .iter()
+            .map(|npc_position| &npc_position.npc)
+            .filter(|npc| !npc.character.is_dead() && !npc.is_boss() && !npc.is_prisoner)
+        {
+            if room.id.eq(&state.current_room_id) {
+                if is_badly_hurt(npc, ruleset)
+                    && roll_percent_succeeds(&mut rng, ruleset.flee_chance)
+                {
+                    if let Some((exit_id, to_room_id)) = adjacent_rooms.choose(&mut rng) {
+                        tick_events.push(npc_moved(npc, room, *exit_id, *to_room_id, true));
+                    }
+                }
+            } else if npc.has_fled {
+                if has_allies(state, room, npc) {
+                    tick_events.push(Event::NpcRegrouped(NpcRegrouped {
+                        npc_id: npc.id,
+                        room_id: room.id,
+                    }));
+                } else if let Some((exit_id, to_room_id)) =
+                    adjacent_rooms.iter().find(|(_, room_id)| {
+                        room_id.ne(&state.current_room_id)
+                            && state
+                                .find_room(room_id)
+                                .map(|other| has_allies(state, other, npc))
+                                .unwrap_or(false)
+                    })
+                {
+                    tick_events.push(npc_moved(npc, room, *exit_id, *to_room_id, true));
+                }
+            } else if npc.disposition.is_hostile()
+                && roll_percent_succeeds(&mut rng, ruleset.wander_chance)
+            {
+                if let Some((exit_id, to_room_id)) = adjacent_rooms.choose(&mut rng) {
+                    tick_events.push(npc_moved(npc, room, *exit_id, *to_room_id, false));
+                }
+            }
+        }
+
+        if is_cleared(state, room) && roll_percent_succeeds(&mut rng, ruleset.repopulate_chance) {
+            tick_events.push(Event::RoomRepopulated(RoomRepopulated {
+                room_id: room.id,
+                npc_positions: repopulation_npc_positions(
+                    room,
+                    state.danger_level,
+                    state.difficulty,
+                    ruleset,
+                )
+                .into_iter()
+                .map(|mut npc_position| {
+                    content.apply_to_npc(&mut npc_position.npc);
+                    npc_position
+                })
+                .collect(),
+            }));
+        }
+    }
+
+    let mut heard_events = movement_heard_events(&tick_events, state);
+    tick_events.append(&mut heard_events);
+
+    tick_events
+}
+
+fn npc_moved(npc: &NonPlayer, room: &Room, exit_id: Uuid, to_room_id: Uuid, fled: bool) -> Event {
+    Event::NpcMoved(NpcMoved {
+        npc_id: npc.id,
+        exit_id,
+        from_room_id: room.id,
+        to_room_id,
+        fled,
+    })
+}
+
+fn is_badly_hurt(npc: &NonPlayer, ruleset: &Ruleset) -> bool {
+    let health = &npc.character.stats.health;
+    health.current * 100 <= health.max * ruleset.flee_health_percent
+}
+
+fn has_allies(state: &GameState, room: &Room, npc: &NonPlayer) -> bool {
+    room.id.ne(&state.current_room_id)
+        && room.npc_positions.iter().any(|npc_position| {
+            npc_position.npc.id.ne(&npc.id)
+                && !npc_position.npc.character.is_dead()
+                && !npc_position.npc.has_fled
+                && npc_position.npc.faction() == npc.faction()
+        })
+}
+
+/// Only rooms the player has already been through and emptied can fill back up.
+fn is_cleared(state: &GameState, room: &Room) -> bool {
+    room.id.ne(&state.current_room_id)
+        && state.rooms_seen.contains(&room.id)
+        && room
+            .npc_positions
+            .iter()
+            .all(|npc_position| npc_position.npc.character.is_dead())
+}
+
+/// Anything moving in a room next to the player can be heard through the exit between them.
+fn movement_heard_events(tick_events: &[Event], state: &GameState) -> Vec<Event> {
+    let neighbours = state.adjacent_rooms(state.current_room());
+
+    let mut heard: Vec<(Uuid, bool)> = Vec::new();
+    for npc_moved in tick_events.iter().filter_map(|event| match event {
+        Event::NpcMoved(it) => Some(it),
+        _ => None,
+    }) {
+        // The player sees anything coming or going from their own room.
+        if npc_moved.from_room_id.eq(&state.current_room_id)
+            || npc_moved.to_room_id.eq(&state.current_room_id)
+        {
+            continue;
+        }
+
+        for (exit_id, room_id) in neighbours.iter() {
+            let approaching = room_id.eq(&npc_moved.to_room_id);
+            if (approaching || room_id.eq(&npc_moved.from_room_id))
+                && !heard.iter().any(|(id, _)| id.eq(exit_id))
+            {
+                heard.push((*exit_id, approaching));
+            }
+        }
+    }
+
+    heard
+        .into_iter()
+        .map(|(exit_id, approaching)| {
+            Event::NpcMovementHeard(NpcMovementHeard {
+                exit_id,
+                approaching,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::{rooms::NpcPosition, Disposition, NonPlayer},
+        content::ContentRegistry,
+        events::Event,
+        generators::{
+            floors::FloorGeneratorBuilder, game::floor_game_generator, generator::Generator,
+            non_players::NonPlayerGeneratorBuilder, players::player_generator,
+        },
+        ruleset::Ruleset,
+    };
+
+    fn npc(disposition: Disposition, is_prisoner: bool) -> NonPlayer {
+        let mut npc = NonPlayerGeneratorBuilder::new().build().generate();
+        npc.disposition = disposition;
+        npc.is_prisoner = is_prisoner;
+        npc
+    }
+
+    #[test]
+    fn only_hostile_npcs_wander() {
+        let mut state =
+            floor_game_generator(Default::default(), FloorGeneratorBuilder::new()).generate();
+        let current_room_id = state.current_room_id;
+        let prisoner = npc(Disposition::Wary, true);
+        let regular = npc(Disposition::Friendly, false);
+        let wanderer = npc(Disposition::Hostile, false);
+        let room = state
+            .world
+            .rooms
+            .iter_mut()
+            .find(|room| room.id.ne(&current_room_id))
+            .unwrap();
+        room.npc_positions = [&prisoner, &regular, &wanderer]
+            .into_iter()
+            .map(|npc| NpcPosition {
+                npc: npc.clone(),
+                position_descriptor: None,
+            })
+            .collect();
+        let ruleset = Ruleset {
+            wander_chance: 100,
+            repopulate_chance: 0,
+            ..Ruleset::default()
+        };
+
+        let events = super::handle(
+            &state,
+            &player_generator(None, None, None).generate(),
+            &ruleset,
+            &ContentRegistry::default(),
+        );
+        let moved: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::NpcMoved(moved) => Some(moved.npc_id),
+                _ => None,
+            })
+            .filter(|npc_id| [prisoner.id, regular.id, wanderer.id].contains(npc_id))
+            .collect();
+
+        assert_eq!(vec![wanderer.id], moved);
+    }
+}
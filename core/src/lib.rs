@@ -1,10 +1,12 @@
 pub mod actions;
 pub mod components;
+pub mod content;
 pub mod errors;
 pub mod events;
 pub mod game;
 pub mod generators;
 pub mod handlers;
+pub mod ruleset;
 pub mod systems;
 pub mod utils;
 
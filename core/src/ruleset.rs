@@ -0,0 +1,264 @@
+use std::ops::RangeInclusive;
+
+use crate::errors::Error;
+
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Every tuning number for combat, parley, the world tick and generation in one place.
+/// Any field left out of a loaded file keeps its default, which matches the original balance.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(default)
+)]
+pub struct Ruleset {
+    /// Out of a d6, the roll at or below which the player dodges an NPC attack.
+    pub player_dodge_chance: i32,
+    pub phantom_dodge_chance: i32,
+    pub shadow_dodge_chance: i32,
+    pub shield_block_chance: i32,
+    pub buckler_block_chance: i32,
+    pub toxic_damage_range: RangeInclusive<i32>,
+    pub toxic_duration_range: RangeInclusive<i32>,
+    /// Chance an NPC's acidic attack destroys one of the player's readied weapons.
+    pub acid_destroys_item_chance: i32,
+    /// Chance the player's acidic attack destroys one of the NPC's items.
+    pub npc_acid_destroys_item_chance: i32,
+    /// Chance an acid splash spell destroys one of its target's readied weapons.
+    pub spell_acid_destroys_item_chance: i32,
+    /// Chance a thrown acid flask destroys one of each target's readied weapons.
+    pub thrown_acid_destroys_item_chance: i32,
+    /// Out of a d6, the roll at or above which inspecting reveals something.
+    pub discover_health_chance: i32,
+    pub discover_packed_chance: i32,
+    pub discover_hidden_compartment_chance: i32,
+    pub identify_item_chance: i32,
+    /// Chance an unidentified consumable is used up without doing anything.
+    pub unidentified_fizzle_chance: i32,
+    pub bribe_gold_hostile: u32,
+    pub bribe_gold_wary: u32,
+    pub base_intimidate_chance: i32,
+    pub intimidate_chance_per_size: i32,
+    pub hire_cost: u32,
+    pub wander_chance: i32,
+    pub flee_chance: i32,
+    pub flee_health_percent: i32,
+    pub repopulate_chance: i32,
+    /// Chance a newly generated room leaves the current biome behind for a random one.
+    pub biome_change_chance: i32,
+    pub spawn_from_ghost_chance: i32,
+    pub keep_species_chance: i32,
+    pub undead_chance: i32,
+    pub generate_consumable_chance: i32,
+    pub generate_pot_chance: i32,
+    pub weapon_in_hand_chance: i32,
+    /// Health lost each turn in a freezing room without a cloak on.
+    pub freezing_damage: i32,
+    /// Chance an attack in a dark room misses, whoever is swinging.
+    pub darkness_miss_chance: i32,
+    /// Percent of fire spell damage soaked up in a steamy room.
+    pub steam_fire_resistance: i32,
+    /// Percent of electric spell damage lost in a moist room.
+    pub moist_electric_penalty: i32,
+    /// Reputation lost with a faction for each of its members the player kills.
+    pub kill_reputation_loss: i32,
+    /// Reputation gained with every faction at war with the one that lost a member.
+    pub rival_kill_reputation_gain: i32,
+    /// A boss waits in the first room generated past every multiple of this danger level.
+    pub boss_danger_level_interval: u32,
+    /// The boss at this danger level is the last one, and beating it wins the run.
+    pub final_boss_danger_level: u32,
+    /// Bosses are generated as though they were this much deeper in the dungeon.
+    pub boss_danger_level_bonus: u32,
+    /// Their unique loot is better still.
+    pub unique_loot_level_bonus: u32,
+    pub quest_slay_count_range: RangeInclusive<u32>,
+    /// How many danger levels past the current one a depth quest asks the player to reach.
+    pub quest_depth_range: RangeInclusive<u32>,
+    pub quest_base_gold_reward: u32,
+    /// Extra reward gold for every kill or danger level a quest asks for.
+    pub quest_gold_per_step: u32,
+    pub quest_reward_item_chance: i32,
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Self {
+            player_dodge_chance: 1,
+            phantom_dodge_chance: 15,
+            shadow_dodge_chance: 25,
+            shield_block_chance: 25,
+            buckler_block_chance: 15,
+            toxic_damage_range: 3..=6,
+            toxic_duration_range: 2..=4,
+            acid_destroys_item_chance: 20,
+            npc_acid_destroys_item_chance: 25,
+            spell_acid_destroys_item_chance: 75,
+            thrown_acid_destroys_item_chance: 50,
+            discover_health_chance: 5,
+            discover_packed_chance: 4,
+            discover_hidden_compartment_chance: 2,
+            identify_item_chance: 4,
+            unidentified_fizzle_chance: 25,
+            bribe_gold_hostile: 20,
+            bribe_gold_wary: 10,
+            base_intimidate_chance: 30,
+            intimidate_chance_per_size: 15,
+            hire_cost: 25,
+            wander_chance: 5,
+            flee_chance: 40,
+            flee_health_percent: 25,
+            repopulate_chance: 2,
+            biome_change_chance: 10,
+            spawn_from_ghost_chance: 10,
+            keep_species_chance: 90,
+            undead_chance: 15,
+            generate_consumable_chance: 25,
+            generate_pot_chance: 20,
+            weapon_in_hand_chance: 95,
+            freezing_damage: 1,
+            darkness_miss_chance: 20,
+            steam_fire_resistance: 50,
+            moist_electric_penalty: 50,
+            kill_reputation_loss: 10,
+            rival_kill_reputation_gain: 5,
+            boss_danger_level_interval: 10,
+            final_boss_danger_level: 50,
+            boss_danger_level_bonus: 5,
+            unique_loot_level_bonus: 10,
+            quest_slay_count_range: 2..=5,
+            quest_depth_range: 5..=15,
+            quest_base_gold_reward: 10,
+            quest_gold_per_step: 5,
+            quest_reward_item_chance: 50,
+        }
+    }
+}
+
+impl Ruleset {
+    /// Catches a loaded ruleset that would panic mid-game, such as an empty range to roll
+    /// from or a percent chance outside 0 to 100.
+    pub fn validate(&self) -> Result<(), Error> {
+        check_range("toxic_damage_range", &self.toxic_damage_range)?;
+        check_range("toxic_duration_range", &self.toxic_duration_range)?;
+        check_range("quest_slay_count_range", &self.quest_slay_count_range)?;
+        check_range("quest_depth_range", &self.quest_depth_range)?;
+
+        if self.boss_danger_level_interval == 0 {
+            return Err(Error::InvalidContentError(
+                "ruleset boss_danger_level_interval must be above 0".to_string(),
+            ));
+        }
+
+        for (field, value) in [
+            ("phantom_dodge_chance", self.phantom_dodge_chance),
+            ("shadow_dodge_chance", self.shadow_dodge_chance),
+            ("shield_block_chance", self.shield_block_chance),
+            ("buckler_block_chance", self.buckler_block_chance),
+            ("acid_destroys_item_chance", self.acid_destroys_item_chance),
+            (
+                "npc_acid_destroys_item_chance",
+                self.npc_acid_destroys_item_chance,
+            ),
+            (
+                "spell_acid_destroys_item_chance",
+                self.spell_acid_destroys_item_chance,
+            ),
+            (
+                "thrown_acid_destroys_item_chance",
+                self.thrown_acid_destroys_item_chance,
+            ),
+            (
+                "unidentified_fizzle_chance",
+                self.unidentified_fizzle_chance,
+            ),
+            ("base_intimidate_chance", self.base_intimidate_chance),
+            ("wander_chance", self.wander_chance),
+            ("flee_chance", self.flee_chance),
+            ("flee_health_percent", self.flee_health_percent),
+            ("repopulate_chance", self.repopulate_chance),
+            ("biome_change_chance", self.biome_change_chance),
+            ("spawn_from_ghost_chance", self.spawn_from_ghost_chance),
+            ("keep_species_chance", self.keep_species_chance),
+            ("undead_chance", self.undead_chance),
+            (
+                "generate_consumable_chance",
+                self.generate_consumable_chance,
+            ),
+            ("generate_pot_chance", self.generate_pot_chance),
+            ("weapon_in_hand_chance", self.weapon_in_hand_chance),
+            ("darkness_miss_chance", self.darkness_miss_chance),
+            ("steam_fire_resistance", self.steam_fire_resistance),
+            ("moist_electric_penalty", self.moist_electric_penalty),
+            ("quest_reward_item_chance", self.quest_reward_item_chance),
+        ] {
+            check_percent(field, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn check_range<T: PartialOrd>(field: &str, range: &RangeInclusive<T>) -> Result<(), Error> {
+    if range.is_empty() {
+        Err(Error::InvalidContentError(format!(
+            "ruleset has an empty {}",
+            field
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_percent(field: &str, value: i32) -> Result<(), Error> {
+    if (0..=100).contains(&value) {
+        Ok(())
+    } else {
+        Err(Error::InvalidContentError(format!(
+            "ruleset {} of {} is not between 0 and 100",
+            field, value
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "serialization")]
+    fn missing_fields_keep_their_defaults() {
+        use super::Ruleset;
+
+        let text = r#"{ "hire_cost": 50, "toxic_damage_range": { "start": 1, "end": 2 } }"#;
+        let ruleset: Ruleset = serde_json::from_str(text).unwrap();
+
+        assert_eq!(ruleset.hire_cost, 50);
+        assert_eq!(ruleset.toxic_damage_range, 1..=2);
+        assert_eq!(ruleset.flee_chance, Ruleset::default().flee_chance);
+    }
+
+    #[test]
+    fn validate_rejects_empty_ranges_and_bad_percents() {
+        use std::ops::RangeInclusive;
+
+        use super::Ruleset;
+
+        assert!(Ruleset::default().validate().is_ok());
+
+        let ruleset = Ruleset {
+            toxic_damage_range: RangeInclusive::new(6, 3),
+            ..Ruleset::default()
+        };
+        assert!(ruleset.validate().is_err());
+
+        let ruleset = Ruleset {
+            flee_chance: 140,
+            ..Ruleset::default()
+        };
+        assert!(ruleset.validate().is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    components::{
+        games::GameState, spells::SpellName, Achievement, AchievementView, LifeModifier,
+        PlayerCharacter,
+    },
+    events::Event,
+};
+
+const ROOMS_TO_CLEAR: i64 = 50;
+
+/// Work out which achievements the events from a single action have earned.
+/// The state and player passed in should already have the events applied.
+pub fn unlocked(events: &[Event], state: &GameState, player: &PlayerCharacter) -> Vec<Achievement> {
+    Achievement::iter()
+        .filter(|achievement| is_unlocked(achievement, events, state, player))
+        .collect()
+}
+
+pub fn view(achievement: &Achievement, unlocked: bool) -> AchievementView {
+    AchievementView {
+        achievement: *achievement,
+        description: achievement.description().to_string(),
+        unlocked,
+    }
+}
+
+fn is_unlocked(
+    achievement: &Achievement,
+    events: &[Event],
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> bool {
+    match *achievement {
+        Achievement::ClearFiftyRooms => {
+            state.player_stats(&player.id).rooms_cleared >= ROOMS_TO_CLEAR
+        }
+        Achievement::FirstKill => events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerKilledNpc(_))),
+        Achievement::KillAVampire => events.iter().any(|event| match event {
+            Event::PlayerKilledNpc(killed) => state
+                .find_npc(&killed.npc_id)
+                .map(|npc| npc.character.life_modifier == Some(LifeModifier::Vampire))
+                .unwrap_or(false),
+            _ => false,
+        }),
+        Achievement::LearnEverySpell => {
+            let spells_learned = state.player_stats(&player.id).spells_learned;
+            SpellName::iter().all(|spell_name| spells_learned.contains(&spell_name))
+        }
+        Achievement::SurvivePoisonAtOneHealth => {
+            !player.character.is_dead()
+                && player.character.get_current_health() == 1
+                && events
+                    .iter()
+                    .any(|event| matches!(event, Event::PlayerDamagedByPoison(_)))
+        }
+    }
+}
@@ -0,0 +1,440 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use uuid::Uuid;
+
+use crate::components::{
+    games::GameState,
+    rooms::{Coordinates, Room},
+    worlds::{Map, MapConnection, MapFormat, MapRoom},
+};
+
+const SVG_CELL_SIZE: i32 = 60;
+const SVG_ROOM_SIZE: i32 = 40;
+
+/// Builds the fog of war map from the rooms the player has seen.
+pub fn build_map(state: &GameState) -> Map {
+    let seen: Vec<&Room> = state
+        .rooms_seen
+        .iter()
+        .filter_map(|room_id| state.find_room(room_id))
+        .collect();
+    let seen_ids: HashSet<Uuid> = seen.iter().map(|room| room.id).collect();
+
+    let mut connections: Vec<MapConnection> = Vec::new();
+    let mut neighbours: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    let mut mapped_exits: HashSet<Uuid> = HashSet::new();
+    for room in seen.iter() {
+        for exit in room.exits.iter() {
+            if !mapped_exits.insert(exit.id) {
+                continue;
+            }
+
+            let to_room_id = state
+                .world
+                .exit_graph
+                .iter()
+                .find(|exit_map| exit_map.exit_id.eq(&exit.id))
+                .and_then(|exit_map| exit_map.other_room_id(room.id))
+                .filter(|room_id| seen_ids.contains(room_id));
+            if let Some(other_id) = to_room_id {
+                neighbours.entry(room.id).or_default().push(other_id);
+                neighbours.entry(other_id).or_default().push(room.id);
+            }
+
+            connections.push(MapConnection {
+                exit_id: exit.id.to_string(),
+                exit_type: exit.exit_type,
+                from_room_id: room.id.to_string(),
+                to_room_id: to_room_id.map(|id| id.to_string()),
+            });
+        }
+    }
+
+    let positions = lay_out(&seen, &neighbours);
+    let rooms = seen
+        .iter()
+        .map(|room| {
+            let id = room.id.to_string();
+            MapRoom {
+                num_unexplored_exits: connections
+                    .iter()
+                    .filter(|connection| {
+                        connection.from_room_id.eq(&id) && connection.to_room_id.is_none()
+                    })
+                    .count(),
+                id,
+                name: room.name.clone(),
+                room_type: room.room_type,
+                position: positions[&room.id],
+                is_current: room.id.eq(&state.current_room_id),
+            }
+        })
+        .collect();
+
+    Map {
+        current_room_id: state.current_room_id.to_string(),
+        rooms,
+        connections,
+    }
+}
+
+pub fn render(map: &Map, format: MapFormat) -> String {
+    match format {
+        MapFormat::Ascii => render_ascii(map),
+        MapFormat::Svg => render_svg(map),
+        MapFormat::Dot => render_dot(map),
+    }
+}
+
+/// Each room is drawn as `[ ]`, with `@` marking where the player is and `?` marking rooms
+/// with exits still to explore. Only connections between neighbouring rooms can be drawn.
+pub fn render_ascii(map: &Map) -> String {
+    let (min, max) = match bounds(map) {
+        Some(it) => it,
+        None => return String::new(),
+    };
+
+    let num_rows = ((max.y - min.y) * 2 + 1) as usize;
+    let num_columns = ((max.x - min.x) * 4 + 3) as usize;
+    let mut grid = vec![vec![' '; num_columns]; num_rows];
+    let cell = |position: &Coordinates| {
+        (
+            ((position.y - min.y) * 2) as usize,
+            ((position.x - min.x) * 4) as usize,
+        )
+    };
+
+    for room in map.rooms.iter() {
+        let (row, column) = cell(&room.position);
+        let marker = if room.is_current {
+            '@'
+        } else if room.num_unexplored_exits > 0 {
+            '?'
+        } else {
+            ' '
+        };
+        grid[row][column] = '[';
+        grid[row][column + 1] = marker;
+        grid[row][column + 2] = ']';
+    }
+
+    for (from, to) in connected_positions(map) {
+        let (first, second) = if (from.y, from.x) <= (to.y, to.x) {
+            (from, to)
+        } else {
+            (to, from)
+        };
+        let (row, column) = cell(&first);
+        if first.y == second.y && second.x - first.x == 1 {
+            grid[row][column + 3] = '-';
+        } else if first.x == second.x && second.y - first.y == 1 {
+            grid[row + 1][column + 1] = '|';
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| row.into_iter().collect::<String>().trim_end().to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn render_svg(map: &Map) -> String {
+    let (min, max) =
+        bounds(map).unwrap_or((Coordinates { x: 0, y: 0 }, Coordinates { x: -1, y: -1 }));
+    let width = (max.x - min.x + 1) * SVG_CELL_SIZE;
+    let height = (max.y - min.y + 1) * SVG_CELL_SIZE;
+    let centre = |position: &Coordinates| {
+        (
+            (position.x - min.x) * SVG_CELL_SIZE + SVG_CELL_SIZE / 2,
+            (position.y - min.y) * SVG_CELL_SIZE + SVG_CELL_SIZE / 2,
+        )
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    );
+    for (from, to) in connected_positions(map) {
+        let (x1, y1) = centre(&from);
+        let (x2, y2) = centre(&to);
+        svg.push_str(&format!(
+            "  <line x1=\"{x1}\" y1=\"{y1}\" x2=\"{x2}\" y2=\"{y2}\" stroke=\"#888888\" stroke-width=\"4\"/>\n"
+        ));
+    }
+    for room in map.rooms.iter() {
+        let (x, y) = centre(&room.position);
+        let fill = if room.is_current {
+            "#cc9933"
+        } else {
+            "#333333"
+        };
+        let dashes = if room.num_unexplored_exits > 0 {
+            " stroke-dasharray=\"4 4\""
+        } else {
+            ""
+        };
+        svg.push_str(&format!(
+            "  <rect x=\"{}\" y=\"{}\" width=\"{SVG_ROOM_SIZE}\" height=\"{SVG_ROOM_SIZE}\" rx=\"4\" fill=\"{fill}\" stroke=\"#dddddd\"{dashes}><title>{}</title></rect>\n",
+            x - SVG_ROOM_SIZE / 2,
+            y - SVG_ROOM_SIZE / 2,
+            escape_xml(&label(room)),
+        ));
+    }
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+/// Graphviz DOT, with unexplored exits as dashed edges to points.
+pub fn render_dot(map: &Map) -> String {
+    let mut dot = String::from("graph map {\n");
+    for room in map.rooms.iter() {
+        let style = if room.is_current {
+            ", style=filled"
+        } else {
+            ""
+        };
+        dot.push_str(&format!(
+            "  \"{}\" [label=\"{}\"{style}];\n",
+            room.id,
+            escape_dot(&label(room))
+        ));
+    }
+    for connection in map.connections.iter() {
+        match &connection.to_room_id {
+            Some(to_room_id) => dot.push_str(&format!(
+                "  \"{}\" -- \"{}\" [label=\"{:?}\"];\n",
+                connection.from_room_id, to_room_id, connection.exit_type
+            )),
+            None => dot.push_str(&format!(
+                "  \"{exit_id}\" [shape=point];\n  \"{}\" -- \"{exit_id}\" [style=dashed, label=\"{:?}\"];\n",
+                connection.from_room_id,
+                connection.exit_type,
+                exit_id = connection.exit_id,
+            )),
+        }
+    }
+    dot.push_str("}\n");
+
+    dot
+}
+
+/// Rooms keep their own coordinates. The rest are placed next to a room they connect to,
+/// spreading out from the rooms that already have a place, or from the first room seen
+/// when none of them do.
+fn lay_out(seen: &[&Room], neighbours: &HashMap<Uuid, Vec<Uuid>>) -> HashMap<Uuid, Coordinates> {
+    let mut positions: HashMap<Uuid, Coordinates> = HashMap::new();
+    let mut taken: HashSet<Coordinates> = HashSet::new();
+    for room in seen.iter() {
+        if let Some(coordinates) = room.coordinates {
+            if taken.insert(coordinates) {
+                positions.insert(room.id, coordinates);
+            }
+        }
+    }
+
+    let mut queue: VecDeque<Uuid> = seen
+        .iter()
+        .map(|room| room.id)
+        .filter(|room_id| positions.contains_key(room_id))
+        .collect();
+    spread_out(&mut queue, neighbours, &mut positions, &mut taken);
+
+    for room in seen.iter() {
+        if positions.contains_key(&room.id) {
+            continue;
+        }
+
+        let start = free_cell_near(Coordinates { x: 0, y: 0 }, &taken);
+        taken.insert(start);
+        positions.insert(room.id, start);
+
+        queue.push_back(room.id);
+        spread_out(&mut queue, neighbours, &mut positions, &mut taken);
+    }
+
+    positions
+}
+
+fn spread_out(
+    queue: &mut VecDeque<Uuid>,
+    neighbours: &HashMap<Uuid, Vec<Uuid>>,
+    positions: &mut HashMap<Uuid, Coordinates>,
+    taken: &mut HashSet<Coordinates>,
+) {
+    while let Some(room_id) = queue.pop_front() {
+        let position = positions[&room_id];
+        for neighbour_id in neighbours.get(&room_id).into_iter().flatten() {
+            if positions.contains_key(neighbour_id) {
+                continue;
+            }
+
+            let cell = free_cell_near(position, taken);
+            taken.insert(cell);
+            positions.insert(*neighbour_id, cell);
+            queue.push_back(*neighbour_id);
+        }
+    }
+}
+
+/// The cell itself if it's free, then the cells sharing a side with it so a connection can
+/// be drawn, and only then the diagonals and rings further out.
+fn free_cell_near(position: Coordinates, taken: &HashSet<Coordinates>) -> Coordinates {
+    if !taken.contains(&position) {
+        return position;
+    }
+
+    if let Some(cell) = position
+        .neighbours()
+        .into_iter()
+        .find(|cell| !taken.contains(cell))
+    {
+        return cell;
+    }
+
+    let mut radius = 1;
+    loop {
+        for y in (position.y - radius)..=(position.y + radius) {
+            for x in (position.x - radius)..=(position.x + radius) {
+                let cell = Coordinates { x, y };
+                if (x - position.x).abs().max((y - position.y).abs()) == radius
+                    && !taken.contains(&cell)
+                {
+                    return cell;
+                }
+            }
+        }
+        radius += 1;
+    }
+}
+
+fn bounds(map: &Map) -> Option<(Coordinates, Coordinates)> {
+    let first = map.rooms.first()?.position;
+    Some(map.rooms.iter().fold((first, first), |(min, max), room| {
+        (
+            Coordinates {
+                x: min.x.min(room.position.x),
+                y: min.y.min(room.position.y),
+            },
+            Coordinates {
+                x: max.x.max(room.position.x),
+                y: max.y.max(room.position.y),
+            },
+        )
+    }))
+}
+
+fn connected_positions(map: &Map) -> Vec<(Coordinates, Coordinates)> {
+    let positions: HashMap<&str, Coordinates> = map
+        .rooms
+        .iter()
+        .map(|room| (room.id.as_str(), room.position))
+        .collect();
+
+    map.connections
+        .iter()
+        .filter_map(|connection| {
+            let from = positions.get(connection.from_room_id.as_str())?;
+            let to = positions.get(connection.to_room_id.as_deref()?)?;
+            Some((*from, *to))
+        })
+        .collect()
+}
+
+fn label(room: &MapRoom) -> String {
+    match &room.name {
+        Some(name) => format!("{} ({:?})", name, room.room_type),
+        None => format!("{:?}", room.room_type),
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        components::{rooms::Coordinates, worlds::MapFormat},
+        generators::{
+            floors::FloorGeneratorBuilder,
+            game::{floor_game_generator, game_generator},
+            generator::Generator,
+        },
+        ruleset::Ruleset,
+    };
+
+    use super::{build_map, free_cell_near, render};
+
+    #[test]
+    fn only_seen_rooms_are_mapped() {
+        let state =
+            floor_game_generator(Default::default(), FloorGeneratorBuilder::new()).generate();
+        let map = build_map(&state);
+
+        assert_eq!(1, map.rooms.len());
+        assert!(map.rooms[0].is_current);
+        assert!(map
+            .connections
+            .iter()
+            .all(|connection| connection.to_room_id.is_none()));
+    }
+
+    #[test]
+    fn renders_every_format() {
+        let state = game_generator(Default::default(), Ruleset::default(), Vec::new()).generate();
+        let map = build_map(&state);
+
+        assert_eq!("[@]", render(&map, MapFormat::Ascii));
+        assert!(render(&map, MapFormat::Svg).starts_with("<svg"));
+        assert!(render(&map, MapFormat::Dot).starts_with("graph map {"));
+    }
+
+    #[test]
+    fn renders_every_seen_room_of_a_floor() {
+        let mut state =
+            floor_game_generator(Default::default(), FloorGeneratorBuilder::new()).generate();
+        state.rooms_seen = state.world.rooms.iter().map(|room| room.id).collect();
+        // Rooms without coordinates of their own are fitted in around the ones that have them.
+        let current_room_id = state.current_room_id;
+        let current_position = state.current_room().coordinates;
+        for room in state.world.rooms.iter_mut() {
+            if room.id.ne(&current_room_id) {
+                room.coordinates = None;
+            }
+        }
+        let map = build_map(&state);
+
+        assert_eq!(state.world.rooms.len(), map.rooms.len());
+        let positions: HashSet<Coordinates> = map.rooms.iter().map(|room| room.position).collect();
+        assert_eq!(map.rooms.len(), positions.len());
+        let current = map.rooms.iter().find(|room| room.is_current).unwrap();
+        assert_eq!(current_position, Some(current.position));
+
+        let ascii = render(&map, MapFormat::Ascii);
+        assert_eq!(map.rooms.len(), ascii.matches('[').count());
+        assert_eq!(1, ascii.matches('@').count());
+        assert!(ascii.contains('-') || ascii.contains('|'));
+        let svg = render(&map, MapFormat::Svg);
+        assert_eq!(map.rooms.len(), svg.matches("<rect").count());
+        let dot = render(&map, MapFormat::Dot);
+        assert!(dot.contains(" -- "));
+    }
+
+    #[test]
+    fn free_cells_prefer_sharing_a_side() {
+        let origin = Coordinates { x: 0, y: 0 };
+        let taken = HashSet::from([origin]);
+        let cell = free_cell_near(origin, &taken);
+
+        assert!(origin.neighbours().contains(&cell));
+    }
+}
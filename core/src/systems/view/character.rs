@@ -1,9 +1,19 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    games::ItemKnowledge,
     spells::{LearnedSpellView, SpellMemoryView, SpellView},
     EffectsView, StatsView, {Character, CharacterView, CharacterViewArgs},
 };
 
-pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) -> CharacterView {
+pub fn view(
+    character: &Character,
+    args: &CharacterViewArgs,
+    item_knowledge: Option<&HashMap<Uuid, ItemKnowledge>>,
+    knows_all: bool,
+) -> CharacterView {
     let (health, health_known) = if args.knows_health || knows_all {
         (Some(character.stats.health.clone()), true)
     } else {
@@ -13,8 +23,9 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
     let (inventory, inventory_known) = if args.knows_inventory || knows_all {
         (
             Some(super::inventory::view(
-                &character.inventory,
+                character,
                 args.knows_packed_in_inventory,
+                item_knowledge,
                 knows_all,
             )),
             true,
@@ -23,7 +34,7 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
         (None, false)
     };
 
-    let (spell_memory, spell_memory_known) = if knows_all {
+    let (spell_memory, spell_memory_known) = if args.knows_spell_memory || knows_all {
         (
             Some(SpellMemoryView {
                 spells: character
@@ -60,6 +71,7 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
             height: character.stats.height,
         },
         species: character.species,
+        custom_species: character.custom_species.clone(),
         life_modifier: character.life_modifier,
         inventory,
         inventory_known,
@@ -74,6 +86,7 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
             knows_has_resurrection_aura: true,
             poison: character.current_effects.poison.clone(),
             covered_in_oil: character.current_effects.covered_in_oil,
+            blindness: character.current_effects.blindness.clone(),
         },
     }
 }
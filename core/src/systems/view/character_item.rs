@@ -1,10 +1,22 @@
-use crate::components::items::{CharacterItem, CharacterItemView};
+use crate::components::{
+    games::ItemKnowledge,
+    items::{CharacterItem, CharacterItemView},
+};
 
-pub fn view(character_item: &CharacterItem, knows_all: bool) -> CharacterItemView {
+pub fn view(
+    character_item: &CharacterItem,
+    knowledge: &ItemKnowledge,
+    knows_all: bool,
+) -> CharacterItemView {
     let full_item_hidden = character_item.equipped_location.hides_full_item();
 
     CharacterItemView {
-        item: super::item::view(&character_item.item, !full_item_hidden, knows_all),
+        item: super::item::view(
+            &character_item.item,
+            !full_item_hidden,
+            knowledge,
+            knows_all,
+        ),
         at_the_ready: character_item.at_the_ready,
         equipped_location: character_item.equipped_location,
     }
@@ -1,5 +1,6 @@
 use crate::components::{
     fixtures::{Fixture, FixtureView, FixtureViewArgs},
+    games::ItemKnowledge,
     items::FixtureItemView,
 };
 
@@ -10,7 +11,12 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
         .filter_map(|fixture_item| {
             if fixture_item.is_inside && fixture.open {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(
+                        &fixture_item.item,
+                        true,
+                        &ItemKnowledge::default(),
+                        knows_all,
+                    ),
                     is_in_hidden_compartment: Some(false),
                     is_in_hidden_compartment_known: true,
                     is_inside: true,
@@ -19,7 +25,12 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
                 None
             } else if fixture_item.is_in_hidden_compartment && fixture.hidden_compartment_open {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(
+                        &fixture_item.item,
+                        true,
+                        &ItemKnowledge::default(),
+                        knows_all,
+                    ),
                     is_in_hidden_compartment: Some(true),
                     is_in_hidden_compartment_known: true,
                     is_inside: false,
@@ -28,7 +39,12 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
                 None
             } else {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(
+                        &fixture_item.item,
+                        true,
+                        &ItemKnowledge::default(),
+                        knows_all,
+                    ),
                     is_in_hidden_compartment: Some(false),
                     is_in_hidden_compartment_known: true,
                     is_inside: false,
@@ -10,8 +10,21 @@ use crate::components::{
 pub fn view(
     fixture_position: &FixturePosition,
     fixture_view_args: &HashMap<Uuid, FixtureViewArgs>,
+    is_obscured: bool,
     knows_all: bool,
 ) -> FixturePositionView {
+    if is_obscured {
+        return FixturePositionView {
+            fixture: super::fixture::view(
+                &fixture_position.fixture,
+                &FixtureViewArgs::default(),
+                false,
+            ),
+            position_descriptor: fixture_position.position_descriptor,
+            is_obscured,
+        };
+    }
+
     let args = fixture_view_args
         .get(&fixture_position.fixture.id)
         .cloned()
@@ -27,5 +40,6 @@ pub fn view(
     FixturePositionView {
         fixture,
         position_descriptor: fixture_position.position_descriptor,
+        is_obscured,
     }
 }
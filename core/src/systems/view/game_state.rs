@@ -1,19 +1,36 @@
 use std::collections::HashMap;
 
+use uuid::Uuid;
+
 use crate::components::{
     games::{game_state::GameStateView, GameState},
     worlds::{ExitMapView, WorldView},
+    PlayerCharacter,
 };
 
-pub fn view(game_state: &GameState) -> GameStateView {
+/// Floors are generated up front, so only rooms the player has seen make it into the view.
+/// Every room is seen by its own light, apart from the one the player holds their light up in.
+pub fn view(game_state: &GameState, player: &PlayerCharacter) -> GameStateView {
+    let is_seen = |room_id: &Uuid| {
+        game_state.all_knowledge_unlocked || game_state.rooms_seen.contains(room_id)
+    };
+    let seen_room_id = |room_id: Option<Uuid>| {
+        room_id
+            .filter(|room_id| is_seen(room_id))
+            .map(|room_id| room_id.to_string())
+    };
+
     let exit_graph = game_state
         .world
         .exit_graph
         .iter()
+        .filter(|exit_map| {
+            exit_map.left_room_id.iter().any(is_seen) || exit_map.right_room_id.iter().any(is_seen)
+        })
         .map(|exit_map| ExitMapView {
             exit_id: exit_map.exit_id.to_string(),
-            left_room_id: exit_map.left_room_id.map(|id| id.to_string()),
-            right_room_id: exit_map.right_room_id.map(|id| id.to_string()),
+            left_room_id: seen_room_id(exit_map.left_room_id),
+            right_room_id: seen_room_id(exit_map.right_room_id),
         })
         .collect();
 
@@ -21,7 +38,14 @@ pub fn view(game_state: &GameState) -> GameStateView {
         .world
         .rooms
         .iter()
-        .map(|room| game_state.view_room(room))
+        .filter(|room| is_seen(&room.id))
+        .map(|room| {
+            if room.id.eq(&game_state.current_room_id) {
+                game_state.view_current_room(player)
+            } else {
+                game_state.view_room(room)
+            }
+        })
         .collect();
 
     let rooms_seen = game_state
@@ -40,11 +64,21 @@ pub fn view(game_state: &GameState) -> GameStateView {
         .iter()
         .map(|(fixture_id, knowledge)| (fixture_id.to_string(), knowledge.clone()));
 
+    let player_item_knowledge = game_state
+        .player_item_knowledge
+        .iter()
+        .map(|(item_id, knowledge)| (item_id.to_string(), knowledge.clone()));
+
     let player_statistics = game_state
         .player_statistics
         .iter()
         .map(|(id, stats)| (id.to_string(), stats.clone()));
 
+    let reputation = game_state
+        .reputation
+        .iter()
+        .map(|(faction, reputation)| (faction.to_string(), *reputation));
+
     GameStateView {
         id: game_state.id.to_string(),
         name: game_state.name.clone(),
@@ -54,7 +88,13 @@ pub fn view(game_state: &GameState) -> GameStateView {
         all_knowledge_unlocked: game_state.all_knowledge_unlocked,
         player_npc_knowledge: HashMap::from_iter(player_npc_knowledge),
         player_fixture_knowledge: HashMap::from_iter(player_fixture_knowledge),
+        player_item_knowledge: HashMap::from_iter(player_item_knowledge),
         player_statistics: HashMap::from_iter(player_statistics),
         danger_level: game_state.danger_level,
+        run_summary: game_state.run_summary.clone(),
+        reputation: HashMap::from_iter(reputation),
+        party: game_state.view_party(),
+        turn: game_state.turn,
+        difficulty: game_state.difficulty,
     }
 }
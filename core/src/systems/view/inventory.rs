@@ -1,11 +1,40 @@
-use crate::components::{Inventory, InventoryView};
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::components::{
+    games::ItemKnowledge,
+    items::{CharacterItem, CharacterItemView, EquipmentSlotView},
+    Character, InventoryView,
+};
+
+/// Items only show what the item knowledge reveals, and without any to go on they are shown in full.
+pub fn view(
+    character: &Character,
+    knows_packed: bool,
+    item_knowledge: Option<&HashMap<Uuid, ItemKnowledge>>,
+    knows_all: bool,
+) -> InventoryView {
+    let inventory = &character.inventory;
+    let view_item = |character_item: &CharacterItem| -> CharacterItemView {
+        match item_knowledge {
+            Some(item_knowledge) => super::character_item::view(
+                character_item,
+                &item_knowledge
+                    .get(&character_item.item.id)
+                    .cloned()
+                    .unwrap_or_default(),
+                knows_all,
+            ),
+            None => super::character_item::view(character_item, &ItemKnowledge::default(), true),
+        }
+    };
 
-pub fn view(inventory: &Inventory, knows_packed: bool, knows_all: bool) -> InventoryView {
     let equipped_items = inventory
         .equipment
         .iter()
         .filter(|character_item| character_item.is_at_the_ready())
-        .map(|character_item| super::character_item::view(character_item, true));
+        .map(view_item);
 
     let packed_items = inventory
         .equipment
@@ -13,13 +42,26 @@ pub fn view(inventory: &Inventory, knows_packed: bool, knows_all: bool) -> Inven
         .filter(|character_item| character_item.is_packed())
         .filter_map(|character_item| {
             if knows_packed || knows_all {
-                Some(super::character_item::view(character_item, true))
+                Some(view_item(character_item))
             } else {
                 None
             }
         });
 
+    let paper_doll = inventory
+        .paper_doll()
+        .into_iter()
+        .map(|(slot, character_item)| EquipmentSlotView {
+            slot,
+            item_id: character_item.item.id.to_string(),
+        })
+        .collect();
+
     InventoryView {
         equipment: equipped_items.chain(packed_items).collect(),
+        paper_doll,
+        total_weight: inventory.total_weight(),
+        carry_capacity: character.carry_capacity(),
+        max_carry_weight: character.max_carry_weight(),
     }
 }
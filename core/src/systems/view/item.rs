@@ -1,8 +1,16 @@
-use crate::components::items::{
-    ConsumableView, ThrowableView, {Item, ItemView},
+use crate::components::{
+    games::ItemKnowledge,
+    items::{
+        ConsumableView, LightSourceView, ThrowableView, {Item, ItemView},
+    },
 };
 
-pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
+pub fn view(
+    item: &Item,
+    sees_full_item: bool,
+    knowledge: &ItemKnowledge,
+    knows_all: bool,
+) -> ItemView {
     let (descriptors, descriptors_known) = if sees_full_item || knows_all {
         (item.descriptors.clone(), true)
     } else {
@@ -15,19 +23,19 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         (None, false)
     };
 
-    let (attack, attack_known) = if knows_all {
+    let (attack, attack_known) = if knowledge.knows_attack || knows_all {
         (item.attack.clone(), true)
     } else {
         (None, false)
     };
 
-    let (defense, defense_known) = if knows_all {
+    let (defense, defense_known) = if knowledge.knows_defense || knows_all {
         (item.defense.clone(), true)
     } else {
         (None, false)
     };
 
-    let (consumable, knows_consumable) = if knows_all {
+    let (consumable, knows_consumable) = if knowledge.knows_consumable || knows_all {
         if let Some(c) = &item.consumable {
             (
                 Some(ConsumableView {
@@ -50,6 +58,14 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         effect: throwable.effect.clone(),
     });
 
+    let light_source = item
+        .light_source
+        .as_ref()
+        .map(|light_source| LightSourceView {
+            burn_time: light_source.burn_time,
+            is_lit: light_source.is_lit,
+        });
+
     ItemView {
         id: item.id.to_string(),
         name: item.name.clone(),
@@ -67,5 +83,6 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         consumable,
         knows_consumable,
         throwable,
+        light_source,
     }
 }
@@ -9,4 +9,5 @@ pub mod item;
 pub mod non_player;
 pub mod npc_position;
 pub mod player;
+pub mod quest;
 pub mod room;
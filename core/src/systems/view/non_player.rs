@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    games::ItemKnowledge,
     CharacterViewArgs, {NonPlayer, NonPlayerView},
 };
 
 pub fn view(
     non_player: &NonPlayer,
     character_args: &CharacterViewArgs,
+    item_knowledge: Option<&HashMap<Uuid, ItemKnowledge>>,
     knows_all: bool,
 ) -> NonPlayerView {
     let can_be_looted =
@@ -14,6 +20,15 @@ pub fn view(
         id: non_player.id.to_string(),
         can_be_looted,
         name: non_player.name.clone(),
-        character: super::character::view(&non_player.character, character_args, knows_all),
+        is_boss: non_player.is_boss(),
+        title: non_player.boss.as_ref().map(|boss| boss.title.clone()),
+        disposition: non_player.disposition,
+        faction: non_player.faction(),
+        character: super::character::view(
+            &non_player.character,
+            character_args,
+            item_knowledge,
+            knows_all,
+        ),
     }
 }
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::components::{
+    games::ItemKnowledge,
     rooms::{NpcPosition, NpcPositionView},
     NonPlayerViewArgs,
 };
@@ -10,8 +11,23 @@ use crate::components::{
 pub fn view(
     npc_position: &NpcPosition,
     non_player_args: &HashMap<Uuid, NonPlayerViewArgs>,
+    item_knowledge: &HashMap<Uuid, ItemKnowledge>,
+    is_obscured: bool,
     knows_all: bool,
 ) -> NpcPositionView {
+    if is_obscured {
+        return NpcPositionView {
+            npc: super::non_player::view(
+                &npc_position.npc,
+                &NonPlayerViewArgs::default().character_args,
+                Some(item_knowledge),
+                false,
+            ),
+            position_descriptor: npc_position.position_descriptor,
+            is_obscured,
+        };
+    }
+
     let args = non_player_args
         .get(&npc_position.npc.id)
         .cloned()
@@ -22,9 +38,15 @@ pub fn view(
                 NonPlayerViewArgs::default()
             }
         });
-    let npc = super::non_player::view(&npc_position.npc, &args.character_args, knows_all);
+    let npc = super::non_player::view(
+        &npc_position.npc,
+        &args.character_args,
+        Some(item_knowledge),
+        knows_all,
+    );
     NpcPositionView {
         npc,
         position_descriptor: npc_position.position_descriptor,
+        is_obscured,
     }
 }
@@ -1,14 +1,37 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    games::{GameState, ItemKnowledge},
     CharacterViewArgs, {PlayerCharacter, PlayerCharacterView},
 };
 
+/// Checks the player outside of any game, where nothing about their items has been identified.
 pub fn check(player_character: &PlayerCharacter) -> PlayerCharacterView {
-    let args = CharacterViewArgs {
-        knows_health: true,
-        knows_inventory: true,
-        knows_packed_in_inventory: true,
-    };
-    let character = super::character::view(&player_character.character, &args, true);
+    view(player_character, &HashMap::new(), false)
+}
+
+/// Checks the player from inside a game, where items only show what the player has identified.
+pub fn check_in_game(player_character: &PlayerCharacter, state: &GameState) -> PlayerCharacterView {
+    view(
+        player_character,
+        &state.player_item_knowledge,
+        state.all_knowledge_unlocked,
+    )
+}
+
+fn view(
+    player_character: &PlayerCharacter,
+    item_knowledge: &HashMap<Uuid, ItemKnowledge>,
+    knows_all: bool,
+) -> PlayerCharacterView {
+    let character = super::character::view(
+        &player_character.character,
+        &CharacterViewArgs::knows_all_args(),
+        Some(item_knowledge),
+        knows_all,
+    );
 
     PlayerCharacterView {
         character,
@@ -0,0 +1,20 @@
+use crate::components::{
+    games::ItemKnowledge,
+    quests::{Quest, QuestView},
+};
+
+pub fn view(quest: &Quest) -> QuestView {
+    QuestView {
+        id: quest.id.to_string(),
+        name: quest.name.clone(),
+        objective: quest.objective.clone(),
+        progress: quest.progress,
+        completed: quest.completed,
+        reward_gold: quest.reward.gold,
+        reward_item: quest
+            .reward
+            .item
+            .as_ref()
+            .map(|item| super::item::view(item, true, &ItemKnowledge::default(), false)),
+    }
+}
@@ -4,7 +4,8 @@ use uuid::Uuid;
 
 use crate::components::{
     fixtures::FixtureViewArgs,
-    rooms::{ExitView, FixturePositionView, NpcPositionView, Room, RoomView},
+    games::ItemKnowledge,
+    rooms::{ExitView, FixturePositionView, LightLevel, NpcPositionView, Room, RoomView},
     NonPlayerViewArgs,
 };
 
@@ -13,19 +14,35 @@ pub fn view(
     non_player_args: HashMap<Uuid, NonPlayerViewArgs>,
     fixture_args: HashMap<Uuid, FixtureViewArgs>,
     exit_visitations: HashMap<Uuid, bool>,
+    item_knowledge: &HashMap<Uuid, ItemKnowledge>,
+    light_level: LightLevel,
     knows_all: bool,
 ) -> RoomView {
+    // In the dark only the exits can be felt out, and in dim light everything is a vague shape.
+    let is_hidden = !knows_all && light_level == LightLevel::Dark;
+    let is_obscured = !knows_all && light_level == LightLevel::Dim;
+
     let fixture_positions: Vec<FixturePositionView> = room
         .fixture_positions
         .iter()
+        .filter(|_| !is_hidden)
         .map(|fixture_position| {
-            super::fixture_position::view(fixture_position, &fixture_args, knows_all)
+            super::fixture_position::view(fixture_position, &fixture_args, is_obscured, knows_all)
         })
         .collect();
     let npc_positions: Vec<NpcPositionView> = room
         .npc_positions
         .iter()
-        .map(|npc_position| super::npc_position::view(npc_position, &non_player_args, knows_all))
+        .filter(|_| !is_hidden)
+        .map(|npc_position| {
+            super::npc_position::view(
+                npc_position,
+                &non_player_args,
+                item_knowledge,
+                is_obscured,
+                knows_all,
+            )
+        })
         .collect();
 
     let exits: Vec<ExitView> = room
@@ -42,16 +59,26 @@ pub fn view(
         id: room.id.to_string(),
         name: room.name.clone(),
         descriptors: room.descriptors.clone(),
+        conditions: room.conditions(),
+        light_level,
         room_type: room.room_type,
         fixture_positions,
         dimensions: room.dimensions.clone(),
         npc_positions,
         flavour: room.flavour,
+        flavour_text: room.flavour_text.clone(),
         exits,
         loose_items: room
             .loose_items
             .iter()
-            .map(|item| super::item::view(item, true, knows_all))
+            .filter(|_| !is_hidden)
+            .map(|item| {
+                let knowledge = item_knowledge.get(&item.id).cloned().unwrap_or_default();
+                super::item::view(item, !is_obscured, &knowledge, knows_all)
+            })
             .collect(),
+        coordinates: room.coordinates,
+        biome: room.biome,
+        area_name: room.biome.map(|biome| biome.area_name().to_string()),
     }
 }
@@ -0,0 +1,20 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS runs (
+  id SERIAL PRIMARY KEY,
+  username TEXT NOT NULL,
+  game_state_id TEXT NOT NULL,
+  player_character_id TEXT NOT NULL,
+  player_name TEXT,
+  species TEXT NOT NULL,
+  danger_level INTEGER NOT NULL,
+  kills BIGINT NOT NULL,
+  victorious BOOLEAN NOT NULL,
+  killer_npc_id TEXT,
+  killer_name TEXT,
+  killer_species TEXT,
+  finished_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  UNIQUE (game_state_id, player_character_id)
+);
+
+CREATE INDEX IF NOT EXISTS runs_species_idx ON runs (species);
+CREATE INDEX IF NOT EXISTS runs_finished_at_idx ON runs (finished_at);
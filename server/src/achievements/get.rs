@@ -0,0 +1,19 @@
+use sqlx::{Postgres, Transaction};
+use strum::IntoEnumIterator;
+use underworld_core::{
+    components::{Achievement, AchievementView},
+    systems::achievements::view,
+};
+
+use crate::error::GameError;
+
+pub async fn achievements(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+) -> Result<Vec<AchievementView>, GameError> {
+    let unlocked = super::repository::unlocked(transaction, username).await?;
+
+    Ok(Achievement::iter()
+        .map(|achievement| view(&achievement, unlocked.contains(&achievement)))
+        .collect())
+}
@@ -0,0 +1,18 @@
+use sqlx::{Postgres, Transaction};
+use underworld_core::{events::Event, systems::achievements::unlocked, Game};
+
+use crate::error::GameError;
+
+/// Persist any achievements the events from an action earned for the user.
+pub async fn record_achievements(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    events: &[Event],
+    game: &Game,
+) -> Result<(), GameError> {
+    for achievement in unlocked(events, &game.state, &game.player).iter() {
+        super::repository::unlock(transaction, username, achievement).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use serde_json::Value;
+use sqlx::{postgres::PgRow, Postgres, Row, Transaction};
+use underworld_core::components::Achievement;
+
+use crate::error::GameError;
+
+pub async fn unlocked(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+) -> Result<Vec<Achievement>, GameError> {
+    let rows: Vec<String> = sqlx::query("select achievement from achievements where username = $1")
+        .bind(username)
+        .map(|row: PgRow| row.try_get("achievement").unwrap())
+        .fetch_all(&mut **transaction)
+        .await
+        .unwrap();
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|name| serde_json::from_value(Value::String(name)).ok())
+        .collect())
+}
+
+pub async fn unlock(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    achievement: &Achievement,
+) -> Result<(), GameError> {
+    let query = r"
+        insert into achievements (username, achievement) values ($1, $2)
+        on conflict (username, achievement)
+        do nothing
+    ";
+
+    let name = match serde_json::to_value(achievement).unwrap() {
+        Value::String(name) => name,
+        other => other.to_string(),
+    };
+
+    sqlx::query(query)
+        .bind(username)
+        .bind(&name)
+        .execute(&mut **transaction)
+        .await
+        .unwrap();
+
+    Ok(())
+}
@@ -11,8 +11,12 @@ pub enum ActionName {
     CastSpellOnPlayer,
     CheckPlayerCharacter,
     ExitRoom,
+    ExtinguishItem,
+    GiveItemToCompanion,
     InspectFixture,
+    InspectItem,
     InspectNpc,
+    LightItem,
     LookAtFixture,
     LookAtNpc,
     LookAtRoom,
@@ -21,11 +25,15 @@ pub enum ActionName {
     MovePlayerItem,
     OpenFixture,
     OpenFixtureHiddenCompartment,
+    ParleyWithNpc,
     PickUpItem,
     QuickLookRoom,
+    RecruitNpc,
     SellPlayerItem,
     SetCurrentPlayerCharacter,
+    TakeItemFromCompanion,
     ThrowItemAtNpc,
+    TravelToRoom,
     UseItemOnPlayer,
 }
 
@@ -96,6 +104,13 @@ pub fn game_actions(game: &Game, _username: &str) -> Vec<PerformAction> {
                 http_action: "POST".to_string(),
                 args: Some(serde_json::to_value(it).unwrap()),
             },
+            Action::TravelToRoom(it) => PerformAction {
+                name: ActionName::TravelToRoom,
+                description: "Travel back to a room already seen, one exit at a time.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/travel_to_room")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(it).unwrap()),
+            },
             Action::LookAtNpc(it) => PerformAction {
                 name: ActionName::LookAtNpc,
                 description: "Look at an NPC".to_string(),
@@ -132,6 +147,13 @@ pub fn game_actions(game: &Game, _username: &str) -> Vec<PerformAction> {
                 http_action: "POST".to_string(),
                 args: Some(serde_json::to_value(inspect).unwrap()),
             },
+            Action::InspectItem(inspect) => PerformAction {
+                name: ActionName::InspectItem,
+                description: "Inspect an item to try and identify it.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/inspect_item")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(inspect).unwrap()),
+            },
             Action::LookAtFixture(look_at) => PerformAction {
                 name: ActionName::LookAtFixture,
                 description: "Look at a fixture.".to_string(),
@@ -167,6 +189,20 @@ pub fn game_actions(game: &Game, _username: &str) -> Vec<PerformAction> {
                 http_action: "POST".to_string(),
                 args: Some(serde_json::to_value(use_item_on_player).unwrap()),
             },
+            Action::LightItem(light_item) => PerformAction {
+                name: ActionName::LightItem,
+                description: "Light a light source to see by.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/light_item")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(light_item).unwrap()),
+            },
+            Action::ExtinguishItem(extinguish_item) => PerformAction {
+                name: ActionName::ExtinguishItem,
+                description: "Put out a lit light source.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/extinguish_item")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(extinguish_item).unwrap()),
+            },
             Action::OpenFixture(open_fixture) => PerformAction {
                 name: ActionName::OpenFixture,
                 description: "Open a fixture.".to_string(),
@@ -192,6 +228,13 @@ pub fn game_actions(game: &Game, _username: &str) -> Vec<PerformAction> {
                 http_action: "POST".to_string(),
                 args: Some(serde_json::to_value(sell).unwrap()),
             },
+            Action::ParleyWithNpc(parley) => PerformAction {
+                name: ActionName::ParleyWithNpc,
+                description: "Talk, bribe or intimidate an NPC.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/parley_with_npc")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(parley).unwrap()),
+            },
             Action::ThrowItemAtNpc(throw) => PerformAction {
                 name: ActionName::ThrowItemAtNpc,
                 description: "Toss a throwable at an NPC".to_string(),
@@ -199,6 +242,27 @@ pub fn game_actions(game: &Game, _username: &str) -> Vec<PerformAction> {
                 http_action: "POST".to_string(),
                 args: Some(serde_json::to_value(throw).unwrap()),
             },
+            Action::RecruitNpc(recruit) => PerformAction {
+                name: ActionName::RecruitNpc,
+                description: "Recruit an NPC to join your party.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/recruit_npc")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(recruit).unwrap()),
+            },
+            Action::GiveItemToCompanion(give) => PerformAction {
+                name: ActionName::GiveItemToCompanion,
+                description: "Give an item to a companion to carry.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/give_item_to_companion")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(give).unwrap()),
+            },
+            Action::TakeItemFromCompanion(take) => PerformAction {
+                name: ActionName::TakeItemFromCompanion,
+                description: "Take an item back from a companion.".to_string(),
+                link: get_api_link(&format!("game/{game_id}/take_item_from_companion")),
+                http_action: "POST".to_string(),
+                args: Some(serde_json::to_value(take).unwrap()),
+            },
             Action::PickUpItem(pick_up) => PerformAction {
                 name: ActionName::PickUpItem,
                 description: "Pick up an item in the room".to_string(),
@@ -0,0 +1,32 @@
+use poem::{web::Data, Result};
+use poem_openapi::{payload::Json, ApiResponse, OpenApi};
+use sqlx::PgPool;
+use underworld_core::components::AchievementView;
+
+use crate::achievements::get::achievements;
+use crate::tags::UnderworldApiTags;
+
+use super::security::UnderworldApiKeyAuthorization;
+
+#[derive(ApiResponse)]
+enum AchievementsResponse {
+    #[oai(status = 200)]
+    Achievements(Json<Vec<AchievementView>>),
+}
+
+pub struct UnderworldAchievementApi;
+
+#[OpenApi(tag = "UnderworldApiTags::Achievements", prefix_path = "/achievements")]
+impl UnderworldAchievementApi {
+    /// List every achievement, and whether the user has unlocked it in any of their games.
+    #[oai(path = "/", method = "get", operation_id = "list_achievements")]
+    async fn list_achievements(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+    ) -> Result<AchievementsResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = achievements(&mut transaction, &auth.0.email).await?;
+        Ok(AchievementsResponse::Achievements(Json(result)))
+    }
+}
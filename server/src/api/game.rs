@@ -1,12 +1,21 @@
 use poem::{web::Data, Result};
-use poem_openapi::{param::Path, payload::Json, ApiResponse, OpenApi};
+use poem_openapi::{
+    param::{Path, Query},
+    payload::{Json, PlainText},
+    ApiResponse, OpenApi,
+};
 use serde_json::Value;
 use sqlx::PgPool;
-use underworld_core::components::games::game_state::GameStateView;
+use underworld_core::components::{
+    games::{game_state::GameStateView, Difficulty},
+    quests::QuestView,
+    worlds::{Layout, Map, MapFormat},
+    NonPlayerView,
+};
 
 use crate::game::{
     generate::{generate_game, GeneratedGame},
-    get::{game_ids, game_state},
+    get::{game_ids, game_state, map, party, quests, rendered_map},
 };
 use crate::tags::UnderworldApiTags;
 
@@ -30,6 +39,30 @@ enum GameStateResponse {
     GameState(Json<GameStateView>),
 }
 
+#[derive(ApiResponse)]
+enum QuestLogResponse {
+    #[oai(status = 200)]
+    Quests(Json<Vec<QuestView>>),
+}
+
+#[derive(ApiResponse)]
+enum MapResponse {
+    #[oai(status = 200)]
+    Map(Json<Map>),
+}
+
+#[derive(ApiResponse)]
+enum RenderedMapResponse {
+    #[oai(status = 200)]
+    Map(PlainText<String>),
+}
+
+#[derive(ApiResponse)]
+enum PartyResponse {
+    #[oai(status = 200)]
+    Party(Json<Vec<NonPlayerView>>),
+}
+
 #[derive(ApiResponse)]
 enum RawGameStateResponse {
     #[oai(status = 200)]
@@ -50,15 +83,24 @@ impl UnderworldGameApi {
     ///
     /// # Example
     ///
-    /// POST `/games/generate` to generate and save a new game
+    /// POST `/games/generate?difficulty=hardcore&layout=floor` to generate and save a new game.
+    /// Games are normal difficulty, with rooms generated as they're explored, unless told otherwise.
     #[oai(path = "/generate", method = "post", operation_id = "generate_game")]
     async fn generate_game(
         &self,
         pool: Data<&PgPool>,
         auth: UnderworldApiKeyAuthorization,
+        difficulty: Query<Option<Difficulty>>,
+        layout: Query<Option<Layout>>,
     ) -> Result<GenerateGameResponse> {
         let mut transaction = pool.0.begin().await.unwrap();
-        let generated_result = generate_game(&mut transaction, &auth.0.email).await?;
+        let generated_result = generate_game(
+            &mut transaction,
+            &auth.0.email,
+            difficulty.0.unwrap_or_default(),
+            layout.0.unwrap_or_default(),
+        )
+        .await?;
         transaction.commit().await.unwrap();
 
         Ok(GenerateGameResponse::GameGenerated(Json(generated_result)))
@@ -95,6 +137,73 @@ impl UnderworldGameApi {
         Ok(GameStateResponse::GameState(Json(view)))
     }
 
+    /// Get the quest log for the game, with the progress made on each quest.
+    #[oai(path = "/:game_id/quests", method = "get", operation_id = "quest_log")]
+    async fn quest_log(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+    ) -> Result<QuestLogResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = quests(&mut transaction, &auth.0.email, &game_id).await?;
+        Ok(QuestLogResponse::Quests(Json(result)))
+    }
+
+    /// Get the map of the rooms explored so far in the game.
+    #[oai(path = "/:game_id/map", method = "get", operation_id = "map")]
+    async fn map(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+    ) -> Result<MapResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = map(&mut transaction, &auth.0.email, &game_id).await?;
+        Ok(MapResponse::Map(Json(result)))
+    }
+
+    /// Export the map of the explored rooms as ASCII, SVG or Graphviz DOT.
+    ///
+    /// # Example
+    ///
+    /// Call `/games/{game_id}/map/export?format=svg` for an SVG image of the map.
+    #[oai(
+        path = "/:game_id/map/export",
+        method = "get",
+        operation_id = "export_map"
+    )]
+    async fn export_map(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        format: Query<Option<MapFormat>>,
+    ) -> Result<RenderedMapResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = rendered_map(
+            &mut transaction,
+            &auth.0.email,
+            &game_id,
+            format.0.unwrap_or_default(),
+        )
+        .await?;
+        Ok(RenderedMapResponse::Map(PlainText(result)))
+    }
+
+    /// Get the companions currently travelling with the player.
+    #[oai(path = "/:game_id/party", method = "get", operation_id = "party")]
+    async fn party(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+    ) -> Result<PartyResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = party(&mut transaction, &auth.0.email, &game_id).await?;
+        Ok(PartyResponse::Party(Json(result)))
+    }
+
     /// Unlock all of the knowledge in the game for all player characters.
     #[oai(
         path = "/:game_id/unlock_knowledge",
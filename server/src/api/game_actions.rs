@@ -3,9 +3,11 @@ use poem_openapi::{param::Path, payload::Json, ApiResponse, OpenApi};
 use sqlx::PgPool;
 use underworld_core::{
     actions::{
-        AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, ExitRoom, InspectFixture, InspectNpc,
-        LookAtFixture, LookAtNpc, LootFixture, LootNpc, MovePlayerItem, OpenFixture,
-        OpenFixtureHiddenCompartment, SellPlayerItem, ThrowItemAtNpc, UseItemOnPlayer,
+        AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, ExitRoom, ExtinguishItem,
+        GiveItemToCompanion, InspectFixture, InspectItem, InspectNpc, LightItem, LookAtFixture,
+        LookAtNpc, LootFixture, LootNpc, MovePlayerItem, OpenFixture, OpenFixtureHiddenCompartment,
+        ParleyWithNpc, RecruitNpc, SellPlayerItem, TakeItemFromCompanion, ThrowItemAtNpc,
+        TravelToRoom, UseItemOnPlayer,
     },
     components::{fixtures::FixtureView, rooms::RoomView, NonPlayerView},
 };
@@ -14,16 +16,21 @@ use crate::{
     actions::PerformAction,
     game::{
         attack::{attack_npc, NpcAttacked},
-        exit::{exit_room, RoomExited},
+        companions::{give_item_to_companion, recruit_npc, take_item_from_companion, PartyChanged},
+        exit::{exit_room, travel_to_room, RoomExited},
         get::game_actions,
-        inspect::{inspect_fixture, inspect_npc, FixtureInspected, NpcInspected},
+        inspect::{
+            inspect_fixture, inspect_item, inspect_npc, FixtureInspected, ItemInspected,
+            NpcInspected,
+        },
         items::{
-            move_player_item, sell_player_item, throw_item_at_npc, use_item_on_player, ItemMoved,
-            ItemSold, ItemUsed,
+            extinguish_item, light_item, move_player_item, sell_player_item, throw_item_at_npc,
+            use_item_on_player, ItemMoved, ItemSold, ItemUsed,
         },
         look::{look_at_fixture, look_at_npc, look_at_room},
         loot::{loot_fixture, loot_npc, FixtureLooted, NpcLooted},
         open::{open_fixture, open_fixture_hidden_compartment, FixtureOpened},
+        parley::{parley_with_npc, NpcParleyed},
         spells::{cast_spell_on_npc, cast_spell_on_player, SpellCast},
     },
 };
@@ -62,6 +69,12 @@ enum InspectFixtureResponse {
     FixtureInspected(Json<FixtureInspected>),
 }
 
+#[derive(ApiResponse)]
+enum InspectItemResponse {
+    #[oai(status = 200)]
+    ItemInspected(Json<ItemInspected>),
+}
+
 #[derive(ApiResponse)]
 enum LootNpcResponse {
     #[oai(status = 200)]
@@ -86,6 +99,18 @@ enum AttackNpcResponse {
     NpcAttacked(Json<NpcAttacked>),
 }
 
+#[derive(ApiResponse)]
+enum ParleyWithNpcResponse {
+    #[oai(status = 200)]
+    NpcParleyed(Json<NpcParleyed>),
+}
+
+#[derive(ApiResponse)]
+enum PartyChangedResponse {
+    #[oai(status = 200)]
+    PartyChanged(Json<PartyChanged>),
+}
+
 #[derive(ApiResponse)]
 enum CastSpellResponse {
     #[oai(status = 200)]
@@ -144,6 +169,26 @@ impl UnderworldGameActionApi {
         Ok(ExitRoomResponse::RoomExited(Json(exit_result)))
     }
 
+    /// Travel to a room that has already been seen, taking each exit along the shortest route.
+    /// The journey stops early if the player is attacked or runs into anything hostile.
+    #[oai(
+        path = "/travel_to_room",
+        method = "post",
+        operation_id = "travel_to_room"
+    )]
+    async fn travel_to_room(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<TravelToRoom>,
+    ) -> Result<ExitRoomResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let exit_result = travel_to_room(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+        Ok(ExitRoomResponse::RoomExited(Json(exit_result)))
+    }
+
     /// Attack a specific NPC inside the current room of the specified game.
     #[oai(path = "/attack_npc", method = "post", operation_id = "attack_npc")]
     async fn attack_npc(
@@ -160,6 +205,85 @@ impl UnderworldGameActionApi {
         Ok(AttackNpcResponse::NpcAttacked(Json(attack_result)))
     }
 
+    /// Talk to, bribe or intimidate a specific NPC inside the current room of the specified game.
+    #[oai(
+        path = "/parley_with_npc",
+        method = "post",
+        operation_id = "parley_with_npc"
+    )]
+    async fn parley_with_npc(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<ParleyWithNpc>,
+    ) -> Result<ParleyWithNpcResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let parley_result =
+            parley_with_npc(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+
+        Ok(ParleyWithNpcResponse::NpcParleyed(Json(parley_result)))
+    }
+
+    /// Recruit a prisoner or tavern regular in the current room to join the party.
+    #[oai(path = "/recruit_npc", method = "post", operation_id = "recruit_npc")]
+    async fn recruit_npc(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<RecruitNpc>,
+    ) -> Result<PartyChangedResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = recruit_npc(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+
+        Ok(PartyChangedResponse::PartyChanged(Json(result)))
+    }
+
+    /// Give one of the player's items to a companion to carry.
+    #[oai(
+        path = "/give_item_to_companion",
+        method = "post",
+        operation_id = "give_item_to_companion"
+    )]
+    async fn give_item_to_companion(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<GiveItemToCompanion>,
+    ) -> Result<PartyChangedResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result =
+            give_item_to_companion(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+
+        Ok(PartyChangedResponse::PartyChanged(Json(result)))
+    }
+
+    /// Take an item back from a companion.
+    #[oai(
+        path = "/take_item_from_companion",
+        method = "post",
+        operation_id = "take_item_from_companion"
+    )]
+    async fn take_item_from_companion(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<TakeItemFromCompanion>,
+    ) -> Result<PartyChangedResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result =
+            take_item_from_companion(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+
+        Ok(PartyChangedResponse::PartyChanged(Json(result)))
+    }
+
     /// Cast a spell on your player character.
     #[oai(
         path = "/cast_spell_on_player",
@@ -223,6 +347,42 @@ impl UnderworldGameActionApi {
         Ok(UseItemResponse::ItemUsed(Json(use_item_result)))
     }
 
+    /// Light a torch, lantern or other light source to see by.
+    #[oai(path = "/light_item", method = "post", operation_id = "light_item")]
+    async fn light_item(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<LightItem>,
+    ) -> Result<UseItemResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = light_item(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+
+        Ok(UseItemResponse::ItemUsed(Json(result)))
+    }
+
+    /// Put out a lit light source to save what is left of it.
+    #[oai(
+        path = "/extinguish_item",
+        method = "post",
+        operation_id = "extinguish_item"
+    )]
+    async fn extinguish_item(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<ExtinguishItem>,
+    ) -> Result<UseItemResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = extinguish_item(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+
+        Ok(UseItemResponse::ItemUsed(Json(result)))
+    }
+
     /// Use an item on your player character.
     #[oai(
         path = "/throw_item_at_npc",
@@ -429,6 +589,22 @@ impl UnderworldGameActionApi {
         Ok(InspectFixtureResponse::FixtureInspected(Json(inspection)))
     }
 
+    /// Inspect an item that is carried or lying in the room, with a chance to identify it.
+    /// Identified items show their attack, defense and consumable effects.
+    #[oai(path = "/inspect_item", method = "post", operation_id = "inspect_item")]
+    async fn inspect_item(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        game_id: Path<String>,
+        args: Json<InspectItem>,
+    ) -> Result<InspectItemResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let inspection = inspect_item(&mut transaction, &auth.0.email, &game_id, &args).await?;
+        transaction.commit().await.unwrap();
+        Ok(InspectItemResponse::ItemInspected(Json(inspection)))
+    }
+
     /// Inspect an NPC to find out more information about them when looking at them next.
     /// After completing an inspect, look at the NPC to see new information.
     #[oai(path = "/inspect_npc", method = "post", operation_id = "inspect_npc")]
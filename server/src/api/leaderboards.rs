@@ -0,0 +1,57 @@
+use poem::{web::Data, Result};
+use poem_openapi::{param::Query, payload::Json, ApiResponse, OpenApi};
+use sqlx::PgPool;
+use underworld_core::components::Species;
+
+use crate::runs::{
+    leaderboard::{leaderboard, TimeWindow},
+    repository::LeaderboardEntry,
+};
+use crate::tags::UnderworldApiTags;
+
+use super::security::UnderworldApiKeyAuthorization;
+
+#[derive(ApiResponse)]
+enum LeaderboardResponse {
+    #[oai(status = 200)]
+    Leaderboard(Json<Vec<LeaderboardEntry>>),
+}
+
+pub struct UnderworldLeaderboardApi;
+
+#[OpenApi(tag = "UnderworldApiTags::Leaderboards", prefix_path = "/leaderboards")]
+impl UnderworldLeaderboardApi {
+    /// The best finished runs of every user.
+    #[oai(path = "/", method = "get", operation_id = "global_leaderboard")]
+    async fn global_leaderboard(
+        &self,
+        pool: Data<&PgPool>,
+        _auth: UnderworldApiKeyAuthorization,
+        species: Query<Option<Species>>,
+        time_window: Query<Option<TimeWindow>>,
+    ) -> Result<LeaderboardResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = leaderboard(&mut transaction, None, species.0, time_window.0).await?;
+        Ok(LeaderboardResponse::Leaderboard(Json(result)))
+    }
+
+    /// The best finished runs of the current user.
+    #[oai(path = "/mine", method = "get", operation_id = "my_leaderboard")]
+    async fn my_leaderboard(
+        &self,
+        pool: Data<&PgPool>,
+        auth: UnderworldApiKeyAuthorization,
+        species: Query<Option<Species>>,
+        time_window: Query<Option<TimeWindow>>,
+    ) -> Result<LeaderboardResponse> {
+        let mut transaction = pool.0.begin().await.unwrap();
+        let result = leaderboard(
+            &mut transaction,
+            Some(&auth.0.email),
+            species.0,
+            time_window.0,
+        )
+        .await?;
+        Ok(LeaderboardResponse::Leaderboard(Json(result)))
+    }
+}
@@ -1,6 +1,8 @@
+pub mod achievements;
 pub mod auth;
 pub mod game;
 pub mod game_actions;
+pub mod leaderboards;
 pub mod middleware;
 pub mod player;
 pub mod randomizers;
@@ -19,7 +19,7 @@ use super::security::UnderworldApiKeyAuthorization;
 #[derive(ApiResponse)]
 enum PlayerCharacterResponse {
     #[oai(status = 200)]
-    PlayerCharacter(Json<PlayerCharacterView>),
+    PlayerCharacter(Json<Box<PlayerCharacterView>>),
 
     #[oai(status = 404)]
     NotFound(PlainText<String>),
@@ -95,9 +95,9 @@ impl UnderworldPlayerApi {
         transaction.commit().await.unwrap();
 
         match result {
-            Some(it) => Ok(PlayerCharacterResponse::PlayerCharacter(Json(
+            Some(it) => Ok(PlayerCharacterResponse::PlayerCharacter(Json(Box::new(
                 player::check(&it),
-            ))),
+            )))),
             None => Ok(PlayerCharacterResponse::NotFound(PlainText(format!(
                 "No player character found for user {} id {}",
                 &auth.0.email, &id.0
@@ -116,9 +116,9 @@ impl UnderworldPlayerApi {
         let player_character_result =
             get_current_player_character(&mut transaction, &auth.0.email).await?;
         transaction.commit().await.unwrap();
-        Ok(PlayerCharacterResponse::PlayerCharacter(Json(
+        Ok(PlayerCharacterResponse::PlayerCharacter(Json(Box::new(
             player::check(&player_character_result),
-        )))
+        ))))
     }
 
     /// Set the specified player character as the current one for any actions in a game.
@@ -88,6 +88,9 @@ pub struct RoomNpcGenerationArgs {
     /// If you'd like NPCs to not spawn already killed, set this to false.
     /// Defaults to true.
     pub allow_npcs_to_spawn_dead: Option<bool>,
+    /// Set to true to add a boss to the room along with the other NPCs.
+    /// Defaults to false.
+    pub include_boss: Option<bool>,
 }
 
 /// Args to tweak the room generation.
@@ -142,7 +145,7 @@ impl UnderworldRandomizerApi {
         let non_player = generator.generate();
 
         let character_args = CharacterViewArgs::knows_all_args();
-        let view = non_player::view(&non_player, &character_args, true);
+        let view = non_player::view(&non_player, &character_args, None, true);
 
         let generated = GeneratedNpc { non_player: view };
 
@@ -207,6 +210,7 @@ impl UnderworldRandomizerApi {
                         possible_life_modifiers: room_npc_args.possible_life_modifiers.clone(),
                         allow_npcs_to_spawn_dead: room_npc_args.allow_npcs_to_spawn_dead,
                         ghosts: Some(Vec::new()),
+                        include_boss: room_npc_args.include_boss,
                     };
 
                     builder.room_npc_generation_args(core_room_npc_args);
@@ -248,6 +252,8 @@ impl UnderworldRandomizerApi {
                     HashMap::new(),
                     HashMap::new(),
                     HashMap::new(),
+                    &HashMap::new(),
+                    room.light_level(),
                     true,
                 )
             })
@@ -1,4 +1,11 @@
-use std::env;
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use serde::de::DeserializeOwned;
+use underworld_core::{content::ContentRegistry, ruleset::Ruleset};
 
 pub fn get_psql_url() -> String {
     env::var("DATABASE_URL").unwrap()
@@ -24,3 +31,76 @@ pub fn get_server_url() -> String {
 pub fn get_server_auth_url() -> String {
     format!("{}/auth", get_server_url())
 }
+
+static RULESET: OnceLock<Ruleset> = OnceLock::new();
+static CONTENT: OnceLock<ContentRegistry> = OnceLock::new();
+
+/// Reads the ruleset and content files once at boot, so a bad file stops the server from
+/// starting instead of failing every game request.
+pub fn load_game_data() -> io::Result<()> {
+    let ruleset = match env::var("RULESET_PATH") {
+        Ok(path) => load_data_file(Path::new(&path))?,
+        Err(_) => Ruleset::default(),
+    };
+    let content = match env::var("CONTENT_PATH") {
+        Ok(path) => load_content(Path::new(&path))?,
+        Err(_) => ContentRegistry::default(),
+    };
+    ruleset
+        .validate()
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", error)))?;
+    content
+        .validate()
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, format!("{:?}", error)))?;
+
+    let _ = RULESET.set(ruleset);
+    let _ = CONTENT.set(content);
+    Ok(())
+}
+
+/// The balance rules every game on this server is played by, read from the TOML or JSON
+/// file at `RULESET_PATH` at boot, or the built in defaults when it isn't set.
+pub fn ruleset() -> &'static Ruleset {
+    RULESET.get_or_init(Ruleset::default)
+}
+
+/// Extra species, items and fixtures for generated rooms. Every TOML or JSON file in the
+/// `CONTENT_PATH` directory is merged at boot, and nothing is added when it isn't set.
+pub fn content() -> &'static ContentRegistry {
+    CONTENT.get_or_init(ContentRegistry::default)
+}
+
+fn load_content(directory: &Path) -> io::Result<ContentRegistry> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| matches!(extension(path), Some("json") | Some("toml")))
+        .collect();
+    paths.sort();
+
+    let mut content = ContentRegistry::default();
+    for path in paths {
+        content.merge(load_data_file(&path)?);
+    }
+    Ok(content)
+}
+
+fn load_data_file<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
+    let invalid_data = |error: String| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), error),
+        )
+    };
+
+    let contents = fs::read_to_string(path)?;
+    match extension(path) {
+        Some("json") => {
+            serde_json::from_str(&contents).map_err(|error| invalid_data(error.to_string()))
+        }
+        _ => toml::from_str(&contents).map_err(|error| invalid_data(error.to_string())),
+    }
+}
+
+fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|extension| extension.to_str())
+}
@@ -2,6 +2,9 @@ use poem::error::ResponseError;
 
 #[derive(Debug, thiserror::Error, strum_macros::Display)]
 pub enum GameError {
+    GameCompletedError,
+    CarryCapacityExceededError(String),
+    CompanionNotFoundError(String),
     ExitNotFoundError(String),
     FixtureCannotBeFound(String),
     FixtureCannotBeOpened(String),
@@ -9,13 +12,25 @@ pub enum GameError {
     FixtureHasHiddenCompartmentUnknown(String),
     FixtureNotFoundError(String),
     InvalidIdError(String),
+    InvalidItemLocationError(String),
     ItemCannotBeTakenFromFixture(String),
     ItemNotDirectlyUsableError(String),
     ItemNotFoundError(String),
+    ItemNotLightSourceError(String),
     ItemNotThrowableError(String),
     JsonProcessingError(String),
+    LightSourceBurnedOutError(String),
+    NotEnoughFreeHandsError(String),
+    NotEnoughGoldError,
+    NpcCannotBeRecruitedError(String),
+    NpcCannotParleyError(String),
+    NpcIsDeadError(String),
     NpcNotFoundError(String),
+    OffHandWeaponRequiredError(String),
+    PartyFullError,
+    NoPathToRoomError(String),
     PlayerIsDeadError,
+    RoomNotFoundError(String),
     SpellNotFoundError(String),
     TooManyWeaponsEquippedError,
     TooManyWearablesEquippedError,
@@ -28,21 +43,47 @@ pub enum GameError {
 impl From<underworld_core::errors::Error> for GameError {
     fn from(error: underworld_core::errors::Error) -> Self {
         match error {
+            underworld_core::errors::Error::CarryCapacityExceededError(it) => {
+                GameError::CarryCapacityExceededError(it)
+            }
+            underworld_core::errors::Error::GameCompletedError => GameError::GameCompletedError,
+            underworld_core::errors::Error::NotEnoughGoldError => GameError::NotEnoughGoldError,
+            underworld_core::errors::Error::CompanionNotFoundError(it) => {
+                GameError::CompanionNotFoundError(it)
+            }
+            underworld_core::errors::Error::NpcCannotBeRecruitedError(it) => {
+                GameError::NpcCannotBeRecruitedError(it)
+            }
+            underworld_core::errors::Error::PartyFullError => GameError::PartyFullError,
+            underworld_core::errors::Error::NpcCannotParleyError(it) => {
+                GameError::NpcCannotParleyError(it)
+            }
             underworld_core::errors::Error::ExitNotFoundError(it) => {
                 GameError::ExitNotFoundError(it)
             }
             underworld_core::errors::Error::FixtureNotFoundError(it) => {
                 GameError::FixtureNotFoundError(it)
             }
+            underworld_core::errors::Error::InvalidContentError(it) => GameError::GeneralError(it),
             underworld_core::errors::Error::InvalidIdError(it) => GameError::InvalidIdError(it),
+            underworld_core::errors::Error::InvalidItemLocationError(it) => {
+                GameError::InvalidItemLocationError(it)
+            }
             underworld_core::errors::Error::ItemNotDirectlyUsableError(it) => {
                 GameError::ItemNotDirectlyUsableError(it)
             }
             underworld_core::errors::Error::ItemNotFoundError(it) => {
                 GameError::ItemNotFoundError(it)
             }
+            underworld_core::errors::Error::NpcIsDeadError(it) => GameError::NpcIsDeadError(it),
             underworld_core::errors::Error::NpcNotFoundError(it) => GameError::NpcNotFoundError(it),
             underworld_core::errors::Error::PlayerIsDeadError => GameError::PlayerIsDeadError,
+            underworld_core::errors::Error::NoPathToRoomError(it) => {
+                GameError::NoPathToRoomError(it)
+            }
+            underworld_core::errors::Error::RoomNotFoundError(it) => {
+                GameError::RoomNotFoundError(it)
+            }
             underworld_core::errors::Error::SpellNotFoundError(it) => {
                 GameError::SpellNotFoundError(it)
             }
@@ -67,9 +108,21 @@ impl From<underworld_core::errors::Error> for GameError {
             underworld_core::errors::Error::ItemNotThrowableError(it) => {
                 GameError::ItemNotThrowableError(it)
             }
+            underworld_core::errors::Error::ItemNotLightSourceError(it) => {
+                GameError::ItemNotLightSourceError(it)
+            }
+            underworld_core::errors::Error::LightSourceBurnedOutError(it) => {
+                GameError::LightSourceBurnedOutError(it)
+            }
             underworld_core::errors::Error::ItemCannotBeTakenFromFixture(it) => {
                 GameError::ItemCannotBeTakenFromFixture(it)
             }
+            underworld_core::errors::Error::NotEnoughFreeHandsError(it) => {
+                GameError::NotEnoughFreeHandsError(it)
+            }
+            underworld_core::errors::Error::OffHandWeaponRequiredError(it) => {
+                GameError::OffHandWeaponRequiredError(it)
+            }
         }
     }
 }
@@ -77,13 +130,24 @@ impl From<underworld_core::errors::Error> for GameError {
 impl ResponseError for GameError {
     fn status(&self) -> poem::http::StatusCode {
         match self {
+            GameError::CarryCapacityExceededError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::GameCompletedError => poem::http::StatusCode::BAD_REQUEST,
+            GameError::NotEnoughGoldError => poem::http::StatusCode::BAD_REQUEST,
+            GameError::NpcCannotParleyError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::CompanionNotFoundError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::NpcCannotBeRecruitedError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::PartyFullError => poem::http::StatusCode::BAD_REQUEST,
             GameError::ExitNotFoundError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::FixtureNotFoundError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::InvalidIdError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::InvalidItemLocationError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::ItemNotDirectlyUsableError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::ItemNotFoundError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::NpcIsDeadError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::NpcNotFoundError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::PlayerIsDeadError => poem::http::StatusCode::BAD_REQUEST,
+            GameError::NoPathToRoomError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::RoomNotFoundError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::SpellNotFoundError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::TooManyWeaponsEquippedError => poem::http::StatusCode::BAD_REQUEST,
             GameError::TooManyWearablesEquippedError => poem::http::StatusCode::BAD_REQUEST,
@@ -96,8 +160,12 @@ impl ResponseError for GameError {
             GameError::FixtureHasNoHiddenCompartment(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::FixtureHasHiddenCompartmentUnknown(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::ItemNotThrowableError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::ItemNotLightSourceError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::LightSourceBurnedOutError(_) => poem::http::StatusCode::BAD_REQUEST,
             GameError::JsonProcessingError(_) => poem::http::StatusCode::INTERNAL_SERVER_ERROR,
             GameError::ItemCannotBeTakenFromFixture(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::NotEnoughFreeHandsError(_) => poem::http::StatusCode::BAD_REQUEST,
+            GameError::OffHandWeaponRequiredError(_) => poem::http::StatusCode::BAD_REQUEST,
         }
     }
 }
@@ -1,40 +1,81 @@
 use poem_openapi::{Enum, Object};
 use serde::Serialize;
 use serde_json::Value;
-use underworld_core::events::Event;
+use underworld_core::events::{stamp_turns, Event};
 
 #[derive(Object, Serialize)]
 pub struct GameEvent {
     pub name: EventName,
 
     pub data: Option<Value>,
+
+    /// The game turn the event happened on.
+    pub turn: u32,
+}
+
+impl GameEvent {
+    pub fn new(event: Event, turn: u32) -> Self {
+        let (name, data) = name_and_data(event);
+        GameEvent { name, data, turn }
+    }
+
+    /// The events from an action that started on `start_turn`, each stamped with the turn it
+    /// happened on.
+    pub fn from_action(events: Vec<Event>, start_turn: u32) -> Vec<GameEvent> {
+        stamp_turns(start_turn, events)
+            .into_iter()
+            .map(|(turn, event)| GameEvent::new(event, turn))
+            .collect()
+    }
 }
 
 #[derive(Serialize, Enum)]
 #[oai(rename_all = "snake_case")]
 pub enum EventName {
+    CompanionHit,
+    CompanionKilled,
+    CompanionReceivedItem,
     DeadNpcBeaten,
+    FactionReputationChanged,
     FixtureHasHiddenCompartmentDiscovered,
     FixtureHiddenCompartmentOpened,
     FixtureOpened,
     FixtureViewed,
     GameDangerLevelIncreased,
     GhostEscapesToTheVoid,
+    ItemIdentified,
+    ItemTakenFromCompanion,
     ItemTakenFromFixture,
     ItemTakenFromNpc,
+    NpcBlinded,
+    NpcBlindnessDissipated,
+    NpcBlindnessDurationChanged,
+    NpcBribed,
     NpcCoveredInOil,
     NpcDamagedByPoison,
+    NpcDispositionChanged,
     NpcHealthDiscovered,
+    NpcHitNpc,
     NpcItemDestroyed,
     NpcHitWithAcid,
+    NpcKilledNpc,
     NpcMissed,
+    NpcMissedNpc,
+    NpcMoved,
+    NpcMovementHeard,
+    NpcOilIgnited,
     NpcPoisoned,
     NpcPackedDiscovered,
     NpcPoisonDurationChanged,
     NpcPoisonEffectDissipated,
     NpcPoisonLevelChanged,
+    NpcRecruited,
+    NpcRegrouped,
+    NpcSpoke,
     NpcViewed,
     NpcWeaponReadied,
+    PlayerBlockedAttack,
+    PlayerDamagedByCold,
     PlayerDamagedByPoison,
     PlayerDropsAllItems,
     PlayerGainedGold,
@@ -53,6 +94,10 @@ pub enum EventName {
     PlayerShieldAuraDamaged,
     PlayerShieldAuraDissipated,
     PlayerHealthFullyRestored,
+    PlayerItemBurnTimeChanged,
+    PlayerItemExtinguished,
+    PlayerItemFizzled,
+    PlayerItemLit,
     PlayerItemMoved,
     PlayerItemDestroyed,
     PlayerItemRemoved,
@@ -66,238 +111,334 @@ pub enum EventName {
     PlayerSpellForgotten,
     PlayerSpellLearned,
     PlayerSpellUsed,
+    PlayerVictorious,
+    QuestCompleted,
+    QuestProgressed,
     RoomExited,
     RoomFirstSeen,
     RoomGenerated,
+    RoomRepopulated,
+    TurnsPassed,
 }
 
-impl From<Event> for GameEvent {
-    fn from(event: Event) -> Self {
-        match event {
-            Event::PlayerHitNpc(it) => GameEvent {
-                name: EventName::PlayerHitNpc,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerKilledNpc(it) => GameEvent {
-                name: EventName::PlayerKilledNpc,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcMissed(it) => GameEvent {
-                name: EventName::NpcMissed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerHit(it) => GameEvent {
-                name: EventName::PlayerHit,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerKilled(it) => GameEvent {
-                name: EventName::PlayerKilled,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerMissed(it) => GameEvent {
-                name: EventName::PlayerMissed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::RoomExited(it) => GameEvent {
-                name: EventName::RoomExited,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::RoomGenerated(it) => GameEvent {
-                name: EventName::RoomGenerated,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::ItemTakenFromNpc(it) => GameEvent {
-                name: EventName::ItemTakenFromNpc,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcViewed(it) => GameEvent {
-                name: EventName::NpcViewed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::DeadNpcBeaten(it) => GameEvent {
-                name: EventName::DeadNpcBeaten,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcWeaponReadied(it) => GameEvent {
-                name: EventName::NpcWeaponReadied,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerItemMoved(it) => GameEvent {
-                name: EventName::PlayerItemMoved,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcHealthDiscovered(it) => GameEvent {
-                name: EventName::NpcHealthDiscovered,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcPackedDiscovered(it) => GameEvent {
-                name: EventName::NpcPackedDiscovered,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::FixtureHasHiddenCompartmentDiscovered(it) => GameEvent {
-                name: EventName::FixtureHasHiddenCompartmentDiscovered,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::FixtureViewed(it) => GameEvent {
-                name: EventName::FixtureViewed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::RoomFirstSeen(it) => GameEvent {
-                name: EventName::RoomFirstSeen,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::ItemTakenFromFixture(it) => GameEvent {
-                name: EventName::ItemTakenFromFixture,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerGainsResurrectionAura => GameEvent {
-                name: EventName::PlayerGainsResurrectionAura,
-                data: None,
-            },
-            Event::PlayerGainsRetributionAura(it) => GameEvent {
-                name: EventName::PlayerGainsRetributionAura,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerGainsShieldAura(it) => GameEvent {
-                name: EventName::PlayerGainsShieldAura,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerHealed(it) => GameEvent {
-                name: EventName::PlayerHealed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerItemRemoved(it) => GameEvent {
-                name: EventName::PlayerItemRemoved,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerItemUsed(it) => GameEvent {
-                name: EventName::PlayerItemUsed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerResurrected => GameEvent {
-                name: EventName::PlayerResurrected,
-                data: None,
-            },
-            Event::PlayerRetributionAuraDissipated => GameEvent {
-                name: EventName::PlayerRetributionAuraDissipated,
-                data: None,
-            },
-            Event::PlayerSpellForgotten(it) => GameEvent {
-                name: EventName::PlayerSpellForgotten,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerSpellLearned(it) => GameEvent {
-                name: EventName::PlayerSpellLearned,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerSpellUsed(it) => GameEvent {
-                name: EventName::PlayerSpellUsed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::GameDangerLevelIncreased(_) => GameEvent {
-                name: EventName::GameDangerLevelIncreased,
-                data: None,
-            },
-            Event::FixtureHiddenCompartmentOpened(it) => GameEvent {
-                name: EventName::FixtureHiddenCompartmentOpened,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::FixtureOpened(it) => GameEvent {
-                name: EventName::FixtureOpened,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcDamagedByPoison(it) => GameEvent {
-                name: EventName::NpcDamagedByPoison,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcPoisonDurationChanged(it) => GameEvent {
-                name: EventName::NpcPoisonDurationChanged,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcPoisonLevelChanged(it) => GameEvent {
-                name: EventName::NpcPoisonLevelChanged,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcPoisoned(it) => GameEvent {
-                name: EventName::NpcPoisoned,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerDamagedByPoison(it) => GameEvent {
-                name: EventName::PlayerDamagedByPoison,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerMaxHealthChanged(it) => GameEvent {
-                name: EventName::PlayerMaxHealthChanged,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerPoisonLevelChanged(it) => GameEvent {
-                name: EventName::PlayerPoisonLevelChanged,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerPoisoned(it) => GameEvent {
-                name: EventName::PlayerPoisoned,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerPoisonDurationChanged(it) => GameEvent {
-                name: EventName::PlayerPoisonDurationChanged,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerHealthFullyRestored => GameEvent {
-                name: EventName::PlayerHealthFullyRestored,
-                data: None,
-            },
-            Event::PlayerGainedGold(gold) => GameEvent {
-                name: EventName::PlayerGainedGold,
-                data: Some(serde_json::to_value(gold).unwrap()),
-            },
-            Event::PlayerShieldAuraDamaged(damage) => GameEvent {
-                name: EventName::PlayerShieldAuraDamaged,
-                data: Some(serde_json::to_value(damage).unwrap()),
-            },
-            Event::PlayerShieldAuraDissipated => GameEvent {
-                name: EventName::PlayerShieldAuraDissipated,
-                data: None,
-            },
-            Event::NpcPoisonEffectDissipated(dissipated) => GameEvent {
-                name: EventName::NpcPoisonEffectDissipated,
-                data: Some(serde_json::to_value(dissipated).unwrap()),
-            },
-            Event::NpcHitWithAcid(it) => GameEvent {
-                name: EventName::NpcHitWithAcid,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::NpcItemDestroyed(it) => GameEvent {
-                name: EventName::NpcItemDestroyed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerHitWithAcid => GameEvent {
-                name: EventName::PlayerHitWithAcid,
-                data: None,
-            },
-            Event::PlayerItemDestroyed(it) => GameEvent {
-                name: EventName::PlayerItemDestroyed,
-                data: Some(serde_json::to_value(it).unwrap()),
-            },
-            Event::PlayerPoisonDissipated => GameEvent {
-                name: EventName::PlayerPoisonDissipated,
-                data: None,
-            },
-            Event::NpcCoveredInOil(id) => GameEvent {
-                name: EventName::NpcCoveredInOil,
-                data: Some(serde_json::to_value(id).unwrap()),
-            },
-            Event::GhostEscapesToTheVoid(escapes) => GameEvent {
-                name: EventName::GhostEscapesToTheVoid,
-                data: Some(serde_json::to_value(escapes).unwrap()),
-            },
-            Event::PlayerDropsAllItems => GameEvent {
-                name: EventName::PlayerDropsAllItems,
-                data: None,
-            },
-            Event::PlayerPicksUpItem(id) => GameEvent {
-                name: EventName::PlayerPicksUpItem,
-                data: Some(serde_json::to_value(id).unwrap()),
-            },
+fn name_and_data(event: Event) -> (EventName, Option<Value>) {
+    match event {
+        Event::PlayerHitNpc(it) => (
+            EventName::PlayerHitNpc,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerKilledNpc(it) => (
+            EventName::PlayerKilledNpc,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcMissed(it) => (
+            EventName::NpcMissed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerHit(it) => (
+            EventName::PlayerHit,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerKilled(it) => (
+            EventName::PlayerKilled,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerMissed(it) => (
+            EventName::PlayerMissed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::RoomExited(it) => (
+            EventName::RoomExited,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::RoomGenerated(it) => (
+            EventName::RoomGenerated,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::ItemTakenFromNpc(it) => (
+            EventName::ItemTakenFromNpc,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcViewed(it) => (
+            EventName::NpcViewed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::DeadNpcBeaten(it) => (
+            EventName::DeadNpcBeaten,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcWeaponReadied(it) => (
+            EventName::NpcWeaponReadied,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerItemBurnTimeChanged(it) => (
+            EventName::PlayerItemBurnTimeChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerItemExtinguished(it) => (
+            EventName::PlayerItemExtinguished,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerItemFizzled(it) => (
+            EventName::PlayerItemFizzled,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerItemLit(it) => (
+            EventName::PlayerItemLit,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerItemMoved(it) => (
+            EventName::PlayerItemMoved,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcHealthDiscovered(it) => (
+            EventName::NpcHealthDiscovered,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcPackedDiscovered(it) => (
+            EventName::NpcPackedDiscovered,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::FixtureHasHiddenCompartmentDiscovered(it) => (
+            EventName::FixtureHasHiddenCompartmentDiscovered,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::FixtureViewed(it) => (
+            EventName::FixtureViewed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::RoomFirstSeen(it) => (
+            EventName::RoomFirstSeen,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::ItemTakenFromFixture(it) => (
+            EventName::ItemTakenFromFixture,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerGainsResurrectionAura => (EventName::PlayerGainsResurrectionAura, None),
+        Event::PlayerGainsRetributionAura(it) => (
+            EventName::PlayerGainsRetributionAura,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerGainsShieldAura(it) => (
+            EventName::PlayerGainsShieldAura,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerHealed(it) => (
+            EventName::PlayerHealed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerItemRemoved(it) => (
+            EventName::PlayerItemRemoved,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerItemUsed(it) => (
+            EventName::PlayerItemUsed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerResurrected => (EventName::PlayerResurrected, None),
+        Event::PlayerRetributionAuraDissipated => {
+            (EventName::PlayerRetributionAuraDissipated, None)
         }
+        Event::PlayerSpellForgotten(it) => (
+            EventName::PlayerSpellForgotten,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerSpellLearned(it) => (
+            EventName::PlayerSpellLearned,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerSpellUsed(it) => (
+            EventName::PlayerSpellUsed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::GameDangerLevelIncreased(_) => (EventName::GameDangerLevelIncreased, None),
+        Event::FixtureHiddenCompartmentOpened(it) => (
+            EventName::FixtureHiddenCompartmentOpened,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::FixtureOpened(it) => (
+            EventName::FixtureOpened,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcDamagedByPoison(it) => (
+            EventName::NpcDamagedByPoison,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcPoisonDurationChanged(it) => (
+            EventName::NpcPoisonDurationChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcPoisonLevelChanged(it) => (
+            EventName::NpcPoisonLevelChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcPoisoned(it) => (
+            EventName::NpcPoisoned,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerDamagedByCold(it) => (
+            EventName::PlayerDamagedByCold,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerDamagedByPoison(it) => (
+            EventName::PlayerDamagedByPoison,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerMaxHealthChanged(it) => (
+            EventName::PlayerMaxHealthChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerPoisonLevelChanged(it) => (
+            EventName::PlayerPoisonLevelChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerPoisoned(it) => (
+            EventName::PlayerPoisoned,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerPoisonDurationChanged(it) => (
+            EventName::PlayerPoisonDurationChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerHealthFullyRestored => (EventName::PlayerHealthFullyRestored, None),
+        Event::PlayerGainedGold(gold) => (
+            EventName::PlayerGainedGold,
+            Some(serde_json::to_value(gold).unwrap()),
+        ),
+        Event::PlayerShieldAuraDamaged(damage) => (
+            EventName::PlayerShieldAuraDamaged,
+            Some(serde_json::to_value(damage).unwrap()),
+        ),
+        Event::PlayerShieldAuraDissipated => (EventName::PlayerShieldAuraDissipated, None),
+        Event::NpcPoisonEffectDissipated(dissipated) => (
+            EventName::NpcPoisonEffectDissipated,
+            Some(serde_json::to_value(dissipated).unwrap()),
+        ),
+        Event::NpcHitWithAcid(it) => (
+            EventName::NpcHitWithAcid,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcItemDestroyed(it) => (
+            EventName::NpcItemDestroyed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerHitWithAcid => (EventName::PlayerHitWithAcid, None),
+        Event::PlayerItemDestroyed(it) => (
+            EventName::PlayerItemDestroyed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerPoisonDissipated => (EventName::PlayerPoisonDissipated, None),
+        Event::NpcCoveredInOil(id) => (
+            EventName::NpcCoveredInOil,
+            Some(serde_json::to_value(id).unwrap()),
+        ),
+        Event::NpcOilIgnited(id) => (
+            EventName::NpcOilIgnited,
+            Some(serde_json::to_value(id).unwrap()),
+        ),
+        Event::NpcBlinded(it) => (
+            EventName::NpcBlinded,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcBlindnessDurationChanged(it) => (
+            EventName::NpcBlindnessDurationChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcBlindnessDissipated(it) => (
+            EventName::NpcBlindnessDissipated,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerBlockedAttack(it) => (
+            EventName::PlayerBlockedAttack,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::GhostEscapesToTheVoid(escapes) => (
+            EventName::GhostEscapesToTheVoid,
+            Some(serde_json::to_value(escapes).unwrap()),
+        ),
+        Event::PlayerDropsAllItems => (EventName::PlayerDropsAllItems, None),
+        Event::PlayerPicksUpItem(id) => (
+            EventName::PlayerPicksUpItem,
+            Some(serde_json::to_value(id).unwrap()),
+        ),
+        Event::QuestCompleted(it) => (
+            EventName::QuestCompleted,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::QuestProgressed(it) => (
+            EventName::QuestProgressed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::PlayerVictorious(it) => (
+            EventName::PlayerVictorious,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcSpoke(it) => (EventName::NpcSpoke, Some(serde_json::to_value(it).unwrap())),
+        Event::NpcBribed(it) => (
+            EventName::NpcBribed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcDispositionChanged(it) => (
+            EventName::NpcDispositionChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcHitNpc(it) => (
+            EventName::NpcHitNpc,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcKilledNpc(it) => (
+            EventName::NpcKilledNpc,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcMissedNpc(it) => (
+            EventName::NpcMissedNpc,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::FactionReputationChanged(it) => (
+            EventName::FactionReputationChanged,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcRecruited(it) => (
+            EventName::NpcRecruited,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::CompanionReceivedItem(it) => (
+            EventName::CompanionReceivedItem,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::ItemIdentified(it) => (
+            EventName::ItemIdentified,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::ItemTakenFromCompanion(it) => (
+            EventName::ItemTakenFromCompanion,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::CompanionHit(it) => (
+            EventName::CompanionHit,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::CompanionKilled(it) => (
+            EventName::CompanionKilled,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcMoved(it) => (EventName::NpcMoved, Some(serde_json::to_value(it).unwrap())),
+        Event::NpcRegrouped(it) => (
+            EventName::NpcRegrouped,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::NpcMovementHeard(it) => (
+            EventName::NpcMovementHeard,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::RoomRepopulated(it) => (
+            EventName::RoomRepopulated,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
+        Event::TurnsPassed(it) => (
+            EventName::TurnsPassed,
+            Some(serde_json::to_value(it).unwrap()),
+        ),
     }
 }
@@ -0,0 +1,114 @@
+use poem_openapi::Object;
+use serde::Serialize;
+use sqlx::{Postgres, Transaction};
+use underworld_core::{
+    actions::{Action, GiveItemToCompanion, RecruitNpc, TakeItemFromCompanion},
+    components::{rooms::RoomView, NonPlayerView, PlayerCharacterView},
+    Game,
+};
+
+use crate::{
+    actions::{game_actions, PerformAction},
+    config::{content, ruleset},
+    error::GameError,
+    event::GameEvent,
+};
+
+#[derive(Serialize, Object)]
+/// Results from recruiting an NPC or trading items with a companion.
+pub struct PartyChanged {
+    /// Events that happened due to the action.
+    events: Vec<GameEvent>,
+    /// Actions that can now be performed.
+    actions: Vec<PerformAction>,
+    current_room: RoomView,
+    current_player: PlayerCharacterView,
+    /// The companions now travelling with the player.
+    party: Vec<NonPlayerView>,
+}
+
+pub async fn recruit_npc(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &RecruitNpc,
+) -> Result<PartyChanged, GameError> {
+    handle_party_action(
+        transaction,
+        username,
+        game_id,
+        &Action::RecruitNpc(args.to_owned()),
+    )
+    .await
+}
+
+pub async fn give_item_to_companion(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &GiveItemToCompanion,
+) -> Result<PartyChanged, GameError> {
+    handle_party_action(
+        transaction,
+        username,
+        game_id,
+        &Action::GiveItemToCompanion(args.to_owned()),
+    )
+    .await
+}
+
+pub async fn take_item_from_companion(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &TakeItemFromCompanion,
+) -> Result<PartyChanged, GameError> {
+    handle_party_action(
+        transaction,
+        username,
+        game_id,
+        &Action::TakeItemFromCompanion(args.to_owned()),
+    )
+    .await
+}
+
+async fn handle_party_action(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    action: &Action,
+) -> Result<PartyChanged, GameError> {
+    let player_character =
+        match crate::player_characters::repository::current(transaction, username).await? {
+            Some(it) => it,
+            None => return Err(GameError::NoPlayerCharacterSetError),
+        };
+
+    let state = match super::repository::by_id(transaction, username, game_id).await? {
+        Some(it) => it,
+        None => return Err(GameError::GameNotFoundError),
+    };
+
+    let mut game = Game {
+        player: player_character,
+        state,
+    };
+
+    let start_turn = game.state.turn;
+    let events = game.handle_action(action, ruleset(), content())?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
+
+    let game_events = GameEvent::from_action(events, start_turn);
+
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
+
+    Ok(PartyChanged {
+        events: game_events,
+        actions: game_actions(&game, username),
+        current_room,
+        current_player,
+        party: game.state.view_party(),
+    })
+}
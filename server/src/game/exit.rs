@@ -2,13 +2,14 @@ use poem_openapi::Object;
 use serde::Serialize;
 use sqlx::{Postgres, Transaction};
 use underworld_core::{
-    actions::{Action, ExitRoom},
+    actions::{Action, ExitRoom, TravelToRoom},
     components::{rooms::RoomView, PlayerCharacterView},
     Game,
 };
 
 use crate::{
     actions::{game_actions, PerformAction},
+    config::{content, ruleset},
     error::GameError,
     event::GameEvent,
 };
@@ -27,6 +28,37 @@ pub async fn exit_room(
     username: &str,
     game_id: &str,
     args: &ExitRoom,
+) -> Result<RoomExited, GameError> {
+    take_exits(
+        transaction,
+        username,
+        game_id,
+        &Action::ExitRoom(args.to_owned()),
+    )
+    .await
+}
+
+/// Every exit taken along the way shows up in the events, up to wherever the journey stopped.
+pub async fn travel_to_room(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &TravelToRoom,
+) -> Result<RoomExited, GameError> {
+    take_exits(
+        transaction,
+        username,
+        game_id,
+        &Action::TravelToRoom(args.to_owned()),
+    )
+    .await
+}
+
+async fn take_exits(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    action: &Action,
 ) -> Result<RoomExited, GameError> {
     let player_character =
         match crate::player_characters::repository::current(transaction, username).await? {
@@ -44,13 +76,14 @@ pub async fn exit_room(
         state,
     };
 
-    let events = game.handle_action(&Action::ExitRoom(args.to_owned()))?;
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let start_turn = game.state.turn;
+    let events = game.handle_action(action, ruleset(), content())?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(RoomExited {
         events: game_events,
@@ -2,12 +2,21 @@ use poem_openapi::Object;
 use serde::Serialize;
 use sqlx::{Postgres, Transaction};
 use underworld_core::{
-    generators::{game::game_generator, generator::Generator},
+    components::{
+        games::{Difficulty, GameState},
+        worlds::Layout,
+    },
+    generators::{
+        floors::FloorGeneratorBuilder,
+        game::{floor_game_generator, game_generator},
+        generator::Generator,
+    },
     Game,
 };
 
 use crate::{
     actions::{game_actions, PerformAction},
+    config::{content, ruleset},
     error::GameError,
 };
 
@@ -20,9 +29,15 @@ pub struct GeneratedGame {
 pub async fn generate_game(
     transaction: &mut Transaction<'_, Postgres>,
     username: &str,
+    difficulty: Difficulty,
+    layout: Layout,
 ) -> Result<GeneratedGame, GameError> {
-    let game_generator = game_generator();
-    let game_state = game_generator.generate();
+    let mut game_state = generate_game_state(difficulty, layout);
+    game_state
+        .world
+        .rooms
+        .iter_mut()
+        .for_each(|room| content().apply_to_room(room));
 
     super::repository::save(transaction, username, &game_state).await?;
 
@@ -39,3 +54,22 @@ pub async fn generate_game(
     let actions = game_actions(&game, username);
     Ok(GeneratedGame { actions, game_id })
 }
+
+fn generate_game_state(difficulty: Difficulty, layout: Layout) -> GameState {
+    match layout {
+        Layout::Lazy => game_generator(
+            difficulty,
+            ruleset().clone(),
+            content().room_templates.clone(),
+        )
+        .generate(),
+        Layout::Floor => floor_game_generator(
+            difficulty,
+            FloorGeneratorBuilder::new()
+                .ruleset(ruleset().clone())
+                .room_templates(content().room_templates.clone())
+                .to_owned(),
+        )
+        .generate(),
+    }
+}
@@ -1,6 +1,14 @@
 use serde_json::Value;
 use sqlx::{Postgres, Transaction};
-use underworld_core::{components::games::game_state::GameStateView, Game};
+use underworld_core::{
+    components::{
+        games::game_state::GameStateView,
+        quests::QuestView,
+        worlds::{Map, MapFormat},
+        NonPlayerView,
+    },
+    Game,
+};
 
 use crate::{actions::PerformAction, error::GameError};
 
@@ -37,10 +45,68 @@ pub async fn game_state(
         None => return Err(GameError::GameNotFoundError),
     };
 
-    let view = underworld_core::systems::view::game_state::view(&state);
+    let player = match crate::player_characters::repository::current(transaction, username).await? {
+        Some(player) => player,
+        None => return Err(GameError::NoPlayerCharacterSetError),
+    };
+
+    let view = underworld_core::systems::view::game_state::view(&state, &player);
     Ok(view)
 }
 
+pub async fn quests(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+) -> Result<Vec<QuestView>, GameError> {
+    let state = match super::repository::by_id(transaction, username, game_id).await? {
+        Some(game_state) => game_state,
+        None => return Err(GameError::GameNotFoundError),
+    };
+
+    Ok(state
+        .quests
+        .iter()
+        .map(underworld_core::systems::view::quest::view)
+        .collect())
+}
+
+pub async fn map(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+) -> Result<Map, GameError> {
+    let state = match super::repository::by_id(transaction, username, game_id).await? {
+        Some(game_state) => game_state,
+        None => return Err(GameError::GameNotFoundError),
+    };
+
+    Ok(underworld_core::systems::map::build_map(&state))
+}
+
+pub async fn rendered_map(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    format: MapFormat,
+) -> Result<String, GameError> {
+    let map = map(transaction, username, game_id).await?;
+    Ok(underworld_core::systems::map::render(&map, format))
+}
+
+pub async fn party(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+) -> Result<Vec<NonPlayerView>, GameError> {
+    let state = match super::repository::by_id(transaction, username, game_id).await? {
+        Some(game_state) => game_state,
+        None => return Err(GameError::GameNotFoundError),
+    };
+
+    Ok(state.view_party())
+}
+
 pub async fn raw_export(
     transaction: &mut Transaction<'_, Postgres>,
     username: &str,
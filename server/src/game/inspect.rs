@@ -2,7 +2,7 @@ use poem_openapi::Object;
 use serde::Serialize;
 use sqlx::{Postgres, Transaction};
 use underworld_core::{
-    actions::{Action, InspectFixture, InspectNpc},
+    actions::{Action, InspectFixture, InspectItem, InspectNpc},
     components::{rooms::RoomView, PlayerCharacterView},
     events::Event,
     Game,
@@ -10,6 +10,7 @@ use underworld_core::{
 
 use crate::{
     actions::{game_actions, PerformAction},
+    config::{content, ruleset},
     error::GameError,
     event::GameEvent,
 };
@@ -41,19 +42,16 @@ pub async fn inspect_npc(
     let mut game = Game { state, player };
 
     let action = Action::InspectNpc(args.to_owned());
-    let events = game.handle_action(&action)?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(&action, ruleset(), content())?;
 
-    super::repository::save(transaction, username, &game.state)
-        .await
-        .unwrap();
-    crate::player_characters::repository::save(transaction, username, &game.player)
-        .await
-        .unwrap();
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.iter().cloned().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events.clone(), start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     let mut npc_inspected = NpcInspected {
         health_discovered: false,
@@ -111,14 +109,15 @@ pub async fn inspect_fixture(
     let mut game = Game { state, player };
 
     let action = Action::InspectFixture(args.to_owned());
-    let events = game.handle_action(&action)?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(&action, ruleset(), content())?;
 
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
-    let game_events: Vec<GameEvent> = events.iter().cloned().map(GameEvent::from).collect();
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
+    let game_events = GameEvent::from_action(events.clone(), start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     let mut fixture_inspected = FixtureInspected {
         actions: game_actions(&game, username),
@@ -134,3 +133,50 @@ pub async fn inspect_fixture(
 
     Ok(fixture_inspected)
 }
+
+#[derive(Object, Serialize)]
+pub struct ItemInspected {
+    pub item_identified: bool,
+    pub actions: Vec<PerformAction>,
+    pub events: Vec<GameEvent>,
+    pub current_room: RoomView,
+    pub current_player: PlayerCharacterView,
+}
+
+pub async fn inspect_item(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &InspectItem,
+) -> Result<ItemInspected, GameError> {
+    let state = match super::repository::by_id(transaction, username, game_id).await? {
+        Some(it) => it,
+        None => return Err(GameError::GameNotFoundError),
+    };
+    let player = match crate::player_characters::repository::current(transaction, username).await? {
+        Some(it) => it,
+        None => return Err(GameError::NoPlayerCharacterSetError),
+    };
+    let mut game = Game { state, player };
+
+    let action = Action::InspectItem(args.to_owned());
+    let start_turn = game.state.turn;
+    let events = game.handle_action(&action, ruleset(), content())?;
+
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
+    let game_events = GameEvent::from_action(events.clone(), start_turn);
+
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
+
+    Ok(ItemInspected {
+        item_identified: events
+            .iter()
+            .any(|event| matches!(event, Event::ItemIdentified(_))),
+        actions: game_actions(&game, username),
+        events: game_events,
+        current_player,
+        current_room,
+    })
+}
@@ -2,13 +2,17 @@ use poem_openapi::Object;
 use serde::Serialize;
 use sqlx::{Postgres, Transaction};
 use underworld_core::{
-    actions::{Action, MovePlayerItem, SellPlayerItem, ThrowItemAtNpc, UseItemOnPlayer},
+    actions::{
+        Action, ExtinguishItem, LightItem, MovePlayerItem, SellPlayerItem, ThrowItemAtNpc,
+        UseItemOnPlayer,
+    },
     components::{rooms::RoomView, PlayerCharacterView},
     Game,
 };
 
 use crate::{
     actions::{game_actions, PerformAction},
+    config::{content, ruleset},
     error::GameError,
     event::GameEvent,
 };
@@ -46,14 +50,89 @@ pub async fn use_item_on_player(
         state,
     };
 
-    let events = game.handle_action(&Action::UseItemOnPlayer(args.to_owned()))?;
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(
+        &Action::UseItemOnPlayer(args.to_owned()),
+        ruleset(),
+        content(),
+    )?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
+
+    Ok(ItemUsed {
+        events: game_events,
+        actions: game_actions(&game, username),
+        current_player,
+        current_room,
+    })
+}
+
+pub async fn light_item(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &LightItem,
+) -> Result<ItemUsed, GameError> {
+    change_light_source(
+        transaction,
+        username,
+        game_id,
+        &Action::LightItem(args.to_owned()),
+    )
+    .await
+}
+
+pub async fn extinguish_item(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &ExtinguishItem,
+) -> Result<ItemUsed, GameError> {
+    change_light_source(
+        transaction,
+        username,
+        game_id,
+        &Action::ExtinguishItem(args.to_owned()),
+    )
+    .await
+}
+
+async fn change_light_source(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    action: &Action,
+) -> Result<ItemUsed, GameError> {
+    let player_character =
+        match crate::player_characters::repository::current(transaction, username).await? {
+            Some(it) => it,
+            None => return Err(GameError::NoPlayerCharacterSetError),
+        };
+
+    let state = match super::repository::by_id(transaction, username, game_id).await? {
+        Some(it) => it,
+        None => return Err(GameError::GameNotFoundError),
+    };
+
+    let mut game = Game {
+        player: player_character,
+        state,
+    };
+
+    let start_turn = game.state.turn;
+    let events = game.handle_action(action, ruleset(), content())?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
+
+    let game_events = GameEvent::from_action(events, start_turn);
+
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(ItemUsed {
         events: game_events,
@@ -96,14 +175,19 @@ pub async fn move_player_item(
         state,
     };
 
-    let events = game.handle_action(&Action::MovePlayerItem(args.to_owned()))?;
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(
+        &Action::MovePlayerItem(args.to_owned()),
+        ruleset(),
+        content(),
+    )?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(ItemMoved {
         events: game_events,
@@ -146,14 +230,19 @@ pub async fn sell_player_item(
         state,
     };
 
-    let events = game.handle_action(&Action::SellPlayerItem(args.to_owned()))?;
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(
+        &Action::SellPlayerItem(args.to_owned()),
+        ruleset(),
+        content(),
+    )?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(ItemSold {
         events: game_events,
@@ -185,14 +274,19 @@ pub async fn throw_item_at_npc(
         state,
     };
 
-    let events = game.handle_action(&Action::ThrowItemAtNpc(args.to_owned()))?;
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(
+        &Action::ThrowItemAtNpc(args.to_owned()),
+        ruleset(),
+        content(),
+    )?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(ItemUsed {
         events: game_events,
@@ -6,7 +6,10 @@ use underworld_core::{
     Game,
 };
 
-use crate::error::GameError;
+use crate::{
+    config::{content, ruleset},
+    error::GameError,
+};
 
 pub async fn look_at_room(
     transaction: &mut Transaction<'_, Postgres>,
@@ -18,7 +21,12 @@ pub async fn look_at_room(
         None => return Err(GameError::GameNotFoundError),
     };
 
-    Ok(state.view_current_room())
+    let player = match crate::player_characters::repository::current(transaction, username).await? {
+        Some(it) => it,
+        None => return Err(GameError::NoPlayerCharacterSetError),
+    };
+
+    Ok(state.view_current_room(&player))
 }
 
 pub async fn look_at_npc(
@@ -45,7 +53,8 @@ pub async fn look_at_npc(
 
     let mut game = Game { state, player };
     let action = Action::LookAtNpc(args.to_owned());
-    let events = game.handle_action(&action)?;
+    let events = game.handle_action(&action, ruleset(), content())?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
     match events.iter().find_map(|event| match event {
         Event::NpcViewed(it) => Some(it),
@@ -80,7 +89,8 @@ pub async fn look_at_fixture(
 
     let mut game = Game { state, player };
     let action = Action::LookAtFixture(args.to_owned());
-    let events = game.handle_action(&action)?;
+    let events = game.handle_action(&action, ruleset(), content())?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
     match events.iter().find_map(|event| match event {
         Event::FixtureViewed(it) => Some(it),
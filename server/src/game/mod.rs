@@ -1,4 +1,5 @@
 pub mod attack;
+pub mod companions;
 pub mod exit;
 pub mod generate;
 pub mod get;
@@ -7,6 +8,8 @@ pub mod items;
 pub mod look;
 pub mod loot;
 pub mod open;
+pub mod parley;
+pub mod persist;
 pub mod repository;
 pub mod spells;
 pub mod unlock;
@@ -9,6 +9,7 @@ use underworld_core::{
 
 use crate::{
     actions::{game_actions, PerformAction},
+    config::{content, ruleset},
     error::GameError,
     event::GameEvent,
 };
@@ -39,15 +40,16 @@ pub async fn open_fixture(
 
     let mut game = Game { state, player };
     let action = Action::OpenFixture(args.to_owned());
-    let events = game.handle_action(&action)?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(&action, ruleset(), content())?;
 
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(FixtureOpened {
         events: game_events,
@@ -75,15 +77,16 @@ pub async fn open_fixture_hidden_compartment(
 
     let mut game = Game { state, player };
     let action = Action::OpenFixtureHiddenCompartment(args.to_owned());
-    let events = game.handle_action(&action)?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(&action, ruleset(), content())?;
 
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(FixtureOpened {
         events: game_events,
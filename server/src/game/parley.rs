@@ -0,0 +1,70 @@
+use poem_openapi::Object;
+use serde::Serialize;
+use sqlx::{Postgres, Transaction};
+use underworld_core::{
+    actions::{Action, ParleyWithNpc},
+    components::{rooms::RoomView, PlayerCharacterView},
+    Game,
+};
+
+use crate::{
+    actions::{game_actions, PerformAction},
+    config::{content, ruleset},
+    error::GameError,
+    event::GameEvent,
+};
+
+#[derive(Serialize, Object)]
+/// Results from parleying with the NPC.
+pub struct NpcParleyed {
+    /// Events that happened due to the parley, including anything the NPC said.
+    events: Vec<GameEvent>,
+    /// Actions that can now be performed after the parley.
+    actions: Vec<PerformAction>,
+    current_room: RoomView,
+    current_player: PlayerCharacterView,
+}
+
+pub async fn parley_with_npc(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game_id: &str,
+    args: &ParleyWithNpc,
+) -> Result<NpcParleyed, GameError> {
+    let player_character =
+        match crate::player_characters::repository::current(transaction, username).await? {
+            Some(it) => it,
+            None => return Err(GameError::NoPlayerCharacterSetError),
+        };
+
+    let state = match super::repository::by_id(transaction, username, game_id).await? {
+        Some(it) => it,
+        None => return Err(GameError::GameNotFoundError),
+    };
+
+    let mut game = Game {
+        player: player_character,
+        state,
+    };
+
+    let start_turn = game.state.turn;
+    let events = game.handle_action(
+        &Action::ParleyWithNpc(args.to_owned()),
+        ruleset(),
+        content(),
+    )?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
+
+    let game_events = GameEvent::from_action(events, start_turn);
+
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
+
+    Ok(NpcParleyed {
+        events: game_events,
+        actions: game_actions(&game, username),
+        current_room,
+        current_player,
+    })
+}
@@ -0,0 +1,21 @@
+use sqlx::{Postgres, Transaction};
+use underworld_core::{events::Event, Game};
+
+use crate::error::GameError;
+
+/// Everything that has to happen after an action is handled, in one place so no action
+/// can skip recording a death or a finished run.
+pub async fn persist_action_result(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    events: &[Event],
+    game: &Game,
+) -> Result<(), GameError> {
+    super::repository::save(transaction, username, &game.state).await?;
+    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    crate::achievements::record::record_achievements(transaction, username, events, game).await?;
+    crate::runs::record::record_finished_run(transaction, username, events, game).await?;
+    crate::player_characters::permadeath::bury_if_permadead(transaction, username, game).await?;
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use underworld_core::{
 
 use crate::{
     actions::{game_actions, PerformAction},
+    config::{content, ruleset},
     error::GameError,
     event::GameEvent,
 };
@@ -46,14 +47,19 @@ pub async fn cast_spell_on_player(
         state,
     };
 
-    let events = game.handle_action(&Action::CastSpellOnPlayer(args.to_owned()))?;
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(
+        &Action::CastSpellOnPlayer(args.to_owned()),
+        ruleset(),
+        content(),
+    )?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(SpellCast {
         events: game_events,
@@ -85,14 +91,19 @@ pub async fn cast_spell_on_npc(
         state,
     };
 
-    let events = game.handle_action(&Action::CastSpellOnNpc(args.to_owned()))?;
-    super::repository::save(transaction, username, &game.state).await?;
-    crate::player_characters::repository::save(transaction, username, &game.player).await?;
+    let start_turn = game.state.turn;
+    let events = game.handle_action(
+        &Action::CastSpellOnNpc(args.to_owned()),
+        ruleset(),
+        content(),
+    )?;
+    super::persist::persist_action_result(transaction, username, &events, &game).await?;
 
-    let game_events: Vec<GameEvent> = events.into_iter().map(GameEvent::from).collect();
+    let game_events = GameEvent::from_action(events, start_turn);
 
-    let current_room = game.state.view_current_room();
-    let current_player = underworld_core::systems::view::player::check(&game.player);
+    let current_room = game.state.view_current_room(&game.player);
+    let current_player =
+        underworld_core::systems::view::player::check_in_game(&game.player, &game.state);
 
     Ok(SpellCast {
         events: game_events,
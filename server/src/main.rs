@@ -1,3 +1,4 @@
+mod achievements;
 mod actions;
 mod api;
 mod auth;
@@ -7,14 +8,16 @@ mod event;
 mod game;
 mod mail;
 mod player_characters;
+mod runs;
 pub mod tags;
 
 use api::{
-    auth::UnderworldAuthApi, game::UnderworldGameApi, game_actions::UnderworldGameActionApi,
+    achievements::UnderworldAchievementApi, auth::UnderworldAuthApi, game::UnderworldGameApi,
+    game_actions::UnderworldGameActionApi, leaderboards::UnderworldLeaderboardApi,
     middleware::CookieToTokenMiddleware, player::UnderworldPlayerApi,
     randomizers::UnderworldRandomizerApi,
 };
-use config::{get_port, get_psql_url, get_server_api_url, get_server_auth_url};
+use config::{get_port, get_psql_url, get_server_api_url, get_server_auth_url, load_game_data};
 use poem::{
     endpoint::StaticFilesEndpoint,
     http::StatusCode,
@@ -42,6 +45,8 @@ impl IntoResponse for UnauthResponse {
 
 #[tokio::main]
 async fn main() -> Result<(), std::io::Error> {
+    load_game_data()?;
+
     let db_url = get_psql_url();
     let pool = sqlx::PgPool::connect(&db_url).await.unwrap();
     MIGRATOR.run(&pool).await.unwrap();
@@ -52,6 +57,8 @@ async fn main() -> Result<(), std::io::Error> {
             UnderworldPlayerApi,
             UnderworldGameApi,
             UnderworldGameActionApi,
+            UnderworldAchievementApi,
+            UnderworldLeaderboardApi,
         ),
         "Underworld",
         "0.6.0",
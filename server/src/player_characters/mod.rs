@@ -1,4 +1,5 @@
 pub mod current;
 pub mod generate;
 pub mod get;
+pub mod permadeath;
 pub mod repository;
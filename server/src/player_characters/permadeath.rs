@@ -0,0 +1,17 @@
+use sqlx::{Postgres, Transaction};
+use underworld_core::Game;
+
+use crate::error::GameError;
+
+/// A player character that dies in a game with permadeath is gone for good.
+pub async fn bury_if_permadead(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    game: &Game,
+) -> Result<(), GameError> {
+    if game.state.difficulty.has_permadeath() && game.player.character.is_dead() {
+        super::repository::delete(transaction, username, &game.player.id.to_string()).await?;
+    }
+
+    Ok(())
+}
@@ -100,3 +100,25 @@ pub async fn set_current(
 
     Ok(())
 }
+
+pub async fn delete(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    pc_id: &str,
+) -> Result<(), GameError> {
+    sqlx::query("delete from current_player_characters where username = $1 and pc_id = $2")
+        .bind(username)
+        .bind(pc_id)
+        .execute(&mut **transaction)
+        .await
+        .unwrap();
+
+    sqlx::query("delete from player_characters where username = $1 and pc_id = $2")
+        .bind(username)
+        .bind(pc_id)
+        .execute(&mut **transaction)
+        .await
+        .unwrap();
+
+    Ok(())
+}
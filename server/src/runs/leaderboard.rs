@@ -0,0 +1,41 @@
+use chrono::{DateTime, Duration, Utc};
+use poem_openapi::Enum;
+use sqlx::{Postgres, Transaction};
+use underworld_core::components::Species;
+
+use crate::error::GameError;
+
+use super::repository::LeaderboardEntry;
+
+const LEADERBOARD_SIZE: i64 = 25;
+
+#[derive(Clone, Copy, Debug, Enum)]
+#[oai(rename_all = "snake_case")]
+/// How far back to look for finished runs.
+pub enum TimeWindow {
+    Day,
+    Week,
+    Month,
+    AllTime,
+}
+
+impl TimeWindow {
+    fn since(&self) -> Option<DateTime<Utc>> {
+        match self {
+            TimeWindow::Day => Some(Utc::now() - Duration::days(1)),
+            TimeWindow::Week => Some(Utc::now() - Duration::weeks(1)),
+            TimeWindow::Month => Some(Utc::now() - Duration::days(30)),
+            TimeWindow::AllTime => None,
+        }
+    }
+}
+
+pub async fn leaderboard(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: Option<&str>,
+    species: Option<Species>,
+    time_window: Option<TimeWindow>,
+) -> Result<Vec<LeaderboardEntry>, GameError> {
+    let since = time_window.and_then(|time_window| time_window.since());
+    super::repository::leaderboard(transaction, username, species, since, LEADERBOARD_SIZE).await
+}
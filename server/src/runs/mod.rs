@@ -0,0 +1,3 @@
+pub mod leaderboard;
+pub mod record;
+pub mod repository;
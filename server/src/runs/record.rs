@@ -0,0 +1,47 @@
+use sqlx::{Postgres, Transaction};
+use underworld_core::{events::Event, Game};
+
+use crate::error::GameError;
+
+use super::repository::FinishedRun;
+
+/// Persist the run if the events from an action ended it, either by victory or death.
+pub async fn record_finished_run(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    events: &[Event],
+    game: &Game,
+) -> Result<(), GameError> {
+    let victorious = events
+        .iter()
+        .any(|event| matches!(event, Event::PlayerVictorious(_)));
+    let killer_id = events.iter().find_map(|event| match event {
+        Event::PlayerKilled(player_killed) => Some(player_killed.killer_id),
+        _ => None,
+    });
+
+    // A resurrection aura can bring the player back after being killed.
+    let died = killer_id.is_some() && game.player.character.is_dead();
+    if !victorious && !died {
+        return Ok(());
+    }
+
+    let killer = killer_id
+        .flatten()
+        .and_then(|killer_id| game.state.find_npc(&killer_id));
+
+    let run = FinishedRun {
+        game_state_id: game.state.id.to_string(),
+        player_character_id: game.player.id.to_string(),
+        player_name: game.player.name.clone(),
+        species: game.player.character.species,
+        danger_level: game.state.danger_level,
+        kills: game.state.player_stats(&game.player.id).num_killed,
+        victorious,
+        killer_npc_id: killer.map(|npc| npc.id.to_string()),
+        killer_name: killer.and_then(|npc| npc.name.clone()),
+        killer_species: killer.map(|npc| npc.character.species),
+    };
+
+    super::repository::save(transaction, username, &run).await
+}
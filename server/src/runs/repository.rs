@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::Object;
+use serde::Serialize;
+use sqlx::{postgres::PgRow, Postgres, Row, Transaction};
+use underworld_core::components::Species;
+
+use crate::error::GameError;
+
+/// A run that ended, by the player character dying or winning.
+pub struct FinishedRun {
+    pub game_state_id: String,
+    pub player_character_id: String,
+    pub player_name: Option<String>,
+    pub species: Species,
+    pub danger_level: u32,
+    pub kills: i64,
+    pub victorious: bool,
+    pub killer_npc_id: Option<String>,
+    pub killer_name: Option<String>,
+    pub killer_species: Option<Species>,
+}
+
+#[derive(Serialize, Object)]
+/// A finished run as it shows up on a leaderboard.
+pub struct LeaderboardEntry {
+    pub username: String,
+    pub player_character_id: String,
+    pub player_name: Option<String>,
+    pub species: Species,
+    pub danger_level: u32,
+    pub kills: i64,
+    pub victorious: bool,
+    /// Name of the NPC that killed the player character, if it had one.
+    pub killer_name: Option<String>,
+    /// Species of the NPC that killed the player character, if one did.
+    pub killer_species: Option<Species>,
+    pub finished_at: String,
+}
+
+pub async fn save(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: &str,
+    run: &FinishedRun,
+) -> Result<(), GameError> {
+    let query = r"
+        insert into runs (
+            username, game_state_id, player_character_id, player_name, species,
+            danger_level, kills, victorious, killer_npc_id, killer_name, killer_species
+        )
+        values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        on conflict (game_state_id, player_character_id)
+        do nothing
+    ";
+
+    sqlx::query(query)
+        .bind(username)
+        .bind(&run.game_state_id)
+        .bind(&run.player_character_id)
+        .bind(&run.player_name)
+        .bind(run.species.to_string())
+        .bind(run.danger_level as i32)
+        .bind(run.kills)
+        .bind(run.victorious)
+        .bind(&run.killer_npc_id)
+        .bind(&run.killer_name)
+        .bind(run.killer_species.map(|species| species.to_string()))
+        .execute(&mut **transaction)
+        .await
+        .unwrap();
+
+    Ok(())
+}
+
+pub async fn leaderboard(
+    transaction: &mut Transaction<'_, Postgres>,
+    username: Option<&str>,
+    species: Option<Species>,
+    since: Option<DateTime<Utc>>,
+    limit: i64,
+) -> Result<Vec<LeaderboardEntry>, GameError> {
+    let query = r"
+        select username, player_character_id, player_name, species, danger_level, kills,
+            victorious, killer_name, killer_species, finished_at
+        from runs
+        where ($1::text is null or username = $1)
+            and ($2::text is null or species = $2)
+            and ($3::timestamptz is null or finished_at >= $3)
+        order by victorious desc, danger_level desc, kills desc, finished_at asc
+        limit $4
+    ";
+
+    let entries = sqlx::query(query)
+        .bind(username)
+        .bind(species.map(|species| species.to_string()))
+        .bind(since)
+        .bind(limit)
+        .map(|row: PgRow| LeaderboardEntry {
+            username: row.try_get("username").unwrap(),
+            player_character_id: row.try_get("player_character_id").unwrap(),
+            player_name: row.try_get("player_name").unwrap(),
+            species: row
+                .try_get::<String, _>("species")
+                .unwrap()
+                .parse()
+                .unwrap(),
+            danger_level: row.try_get::<i32, _>("danger_level").unwrap() as u32,
+            kills: row.try_get("kills").unwrap(),
+            victorious: row.try_get("victorious").unwrap(),
+            killer_name: row.try_get("killer_name").unwrap(),
+            killer_species: row
+                .try_get::<Option<String>, _>("killer_species")
+                .unwrap()
+                .and_then(|species| species.parse().ok()),
+            finished_at: row
+                .try_get::<DateTime<Utc>, _>("finished_at")
+                .unwrap()
+                .to_rfc3339(),
+        })
+        .fetch_all(&mut **transaction)
+        .await
+        .unwrap();
+
+    Ok(entries)
+}
@@ -2,9 +2,11 @@ use poem_openapi::Tags;
 
 #[derive(Tags)]
 pub enum UnderworldApiTags {
+    Achievements,
     Auth,
     PlayerCharacters,
     Games,
     GameActions,
+    Leaderboards,
     Randomizers,
 }